@@ -1,7 +1,19 @@
 // File: src/constants.rs
+use anchor_lang::prelude::*;
+
 pub const CAMPAIGN_SEED: &[u8] = b"campaign";
+pub const CAMPAIGN_ROLE_SEED: &[u8] = b"campaign_role";
+pub const VAULT_SEED: &[u8] = b"vault";
 pub const DONATION_SEED: &[u8] = b"donation";
+pub const DONOR_POSITION_SEED: &[u8] = b"donor_position";
 pub const USER_SEED: &[u8] = b"user";
+pub const WITHDRAWAL_SEED: &[u8] = b"withdrawal";
+pub const WITHDRAWAL_PROPOSAL_SEED: &[u8] = b"withdrawal_proposal";
+pub const SUBSCRIPTION_SEED: &[u8] = b"subscription";
+pub const MATCHING_POOL_SEED: &[u8] = b"matching_pool";
+pub const FUNDING_ROUND_SEED: &[u8] = b"funding_round";
+pub const ROUND_PARTICIPANT_SEED: &[u8] = b"round_participant";
+pub const ROUND_CONTRIBUTION_SEED: &[u8] = b"round_contribution";
 
 // String length constraints
 pub const MAX_TITLE_LENGTH: usize = 100;
@@ -12,18 +24,60 @@ pub const MAX_NAME_LENGTH: usize = 50;
 pub const MAX_EMAIL_LENGTH: usize = 100;
 pub const MAX_TRANSACTION_HASH_LENGTH: usize = 100;
 pub const MAX_IMPACT_DESCRIPTION_LENGTH: usize = 500;
+pub const MAX_WITHDRAWAL_PURPOSE_LENGTH: usize = 200;
+pub const MAX_DEDICATION_LENGTH: usize = 200;
+pub const MAX_PAUSE_REASON_LENGTH: usize = 200;
 
 // Campaign constraints
 pub const MIN_CAMPAIGN_DURATION: i64 = 24 * 60 * 60; // 1 day in seconds
 pub const MAX_CAMPAIGN_DURATION: i64 = 90 * 24 * 60 * 60; // 90 days in seconds
+pub const MAX_START_DATE_BACKDATE: i64 = 24 * 60 * 60; // 1 day in seconds
+pub const MAX_START_DATE_DELAY: i64 = 180 * 24 * 60 * 60; // 180 days in seconds
+pub const MAX_BENEFICIARIES: usize = 3;
+pub const BENEFICIARY_CHANGE_DELAY: i64 = 7 * 24 * 60 * 60; // 7 days in seconds
 pub const MIN_CAMPAIGN_TARGET: u64 = 100_000_000; // 0.1 SOL (lamports)
+pub const MAX_EXPIRY_BOUNTY: u64 = 10_000_000; // 0.01 SOL (lamports)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// Milestone constraints
+pub const MAX_MILESTONES: usize = 5;
+pub const MAX_MILESTONE_DESCRIPTION_LENGTH: usize = 100;
+pub const MAX_EVIDENCE_URI_LENGTH: usize = 200;
+pub const MILESTONE_VOTE_SEED: &[u8] = b"milestone_vote";
+pub const MIN_VOTING_PERIOD: i64 = 60 * 60; // 1 hour in seconds
+pub const MAX_VOTING_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days in seconds
+
+// Withdrawal approval constraints
+pub const MAX_APPROVAL_SIGNERS: usize = 5;
+pub const WITHDRAWAL_PROPOSAL_LIFETIME: i64 = 7 * 24 * 60 * 60; // 7 days in seconds
 
 // Donation constraints
 pub const MIN_DONATION_AMOUNT: u64 = 1_000_000; // 0.001 SOL (lamports)
 
+// Subscription constraints
+pub const MIN_SUBSCRIPTION_INTERVAL: i64 = 24 * 60 * 60; // 1 day in seconds
+pub const MAX_SUBSCRIPTION_INTERVAL: i64 = 365 * 24 * 60 * 60; // 1 year in seconds
+
+// Matching pool constraints
+pub const MAX_MATCHING_CATEGORIES: usize = 6;
+pub const MAX_MATCHING_CAMPAIGNS: usize = 10;
+
+// Funding round constraints
+// Every campaign is passed to `finalize_round` in one transaction, which bounds the round size
+pub const MAX_ROUND_CAMPAIGNS: u32 = 8;
+
 // Badge thresholds
 pub const BRONZE_THRESHOLD: u64 = 1_000_000_000; // 1 SOL
 pub const SILVER_THRESHOLD: u64 = 5_000_000_000; // 5 SOL
 pub const GOLD_THRESHOLD: u64 = 10_000_000_000; // 10 SOL
 pub const CHAMPION_THRESHOLD: u64 = 50_000_000_000; // 50 SOL
 pub const SUSTAINED_SUPPORTER_MIN_DONATIONS: u32 = 10;
+
+// Price feed constraints
+pub const MOCK_PRICE_FEED_SEED: &[u8] = b"mock_price_feed";
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+pub const USD_DECIMALS: u32 = 6;
+pub const SOL_DECIMALS: u8 = 9;
+pub const MIN_CAMPAIGN_TARGET_USD: u64 = 10_000_000; // $10 (micro-USD)
+pub const MAX_PRICE_AGE: i64 = 60; // seconds
+pub const MAX_PRICE_CONFIDENCE_BPS: u64 = 200; // 2% of price
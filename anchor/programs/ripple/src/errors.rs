@@ -59,4 +59,259 @@ pub enum RipplError {
 
     #[msg("Maximum number of badges reached")]
     MaxBadgesReached,
+
+    #[msg("Token mint does not match the campaign's accepted mint")]
+    InvalidMint,
+
+    #[msg("Token accounts are required for campaigns that accept an SPL mint")]
+    MissingTokenAccounts,
+
+    #[msg("Invalid token account")]
+    InvalidTokenAccount,
+
+    #[msg("Token mint uses an extension that is not supported")]
+    UnsupportedMintExtension,
+
+    #[msg("Token mint uses an extension that makes vault custody unsafe")]
+    UnsafeMintExtension,
+
+    #[msg("Price feed account does not match the campaign's price feed")]
+    InvalidPriceFeed,
+
+    #[msg("A price feed account is required for campaigns with a USD target")]
+    MissingPriceFeed,
+
+    #[msg("Price is invalid")]
+    InvalidPrice,
+
+    #[msg("Price is too old")]
+    StalePrice,
+
+    #[msg("Price confidence interval is too wide")]
+    PriceConfidenceTooWide,
+
+    #[msg("Campaign has not reached its funding goal")]
+    FundingGoalNotReached,
+
+    #[msg("Refunds are not available for this campaign")]
+    RefundNotAvailable,
+
+    #[msg("Donation cannot be refunded")]
+    DonationNotRefundable,
+
+    #[msg("Campaign has been cancelled")]
+    CampaignCancelled,
+
+    #[msg("Campaign has not ended yet")]
+    CampaignNotEnded,
+
+    #[msg("Expiry bounty exceeds the maximum allowed")]
+    ExpiryBountyTooHigh,
+
+    #[msg("Too many milestones")]
+    TooManyMilestones,
+
+    #[msg("Milestone is invalid")]
+    InvalidMilestone,
+
+    #[msg("Milestone not found")]
+    MilestoneNotFound,
+
+    #[msg("Milestone has already been completed")]
+    MilestoneAlreadyCompleted,
+
+    #[msg("Milestone deadline has passed")]
+    MilestoneDeadlinePassed,
+
+    #[msg("Evidence URI is too long")]
+    EvidenceUriTooLong,
+
+    #[msg("Withdrawal exceeds the funds released by completed milestones")]
+    MilestoneNotReached,
+
+    #[msg("Voting configuration is invalid")]
+    InvalidVotingConfig,
+
+    #[msg("Milestone is not open for voting")]
+    MilestoneNotVoting,
+
+    #[msg("Voting on this milestone has closed")]
+    VotingClosed,
+
+    #[msg("Voting on this milestone is still open")]
+    VotingStillOpen,
+
+    #[msg("Donation accounts do not give the voter any weight")]
+    InvalidVoteWeight,
+
+    #[msg("Withdrawal purpose is too long")]
+    PurposeTooLong,
+
+    #[msg("Subscription configuration is invalid")]
+    InvalidSubscription,
+
+    #[msg("Subscription is not active")]
+    SubscriptionNotActive,
+
+    #[msg("Subscription is not due yet")]
+    SubscriptionNotDue,
+
+    #[msg("Subscription has reached its maximum number of donations")]
+    SubscriptionExhausted,
+
+    #[msg("Campaign is not a target of this subscription")]
+    InvalidSubscriptionTarget,
+
+    #[msg("Subscription escrow cannot cover the donation")]
+    InsufficientEscrow,
+
+    #[msg("Matching pool configuration is invalid")]
+    InvalidMatchingPool,
+
+    #[msg("Campaign is not eligible for this matching pool")]
+    MatchingPoolNotEligible,
+
+    #[msg("Matching pool window has not ended")]
+    MatchingPoolActive,
+
+    #[msg("Matching pool account is required to return the matched funds")]
+    MissingMatchingPool,
+
+    #[msg("Funding round configuration is invalid")]
+    InvalidFundingRound,
+
+    #[msg("Funding round is not open")]
+    RoundNotOpen,
+
+    #[msg("Funding round has not ended")]
+    RoundNotEnded,
+
+    #[msg("Funding round has already been finalized")]
+    RoundFinalized,
+
+    #[msg("Funding round has no room for more campaigns")]
+    RoundFull,
+
+    #[msg("Donation has already been tallied in a funding round")]
+    DonationAlreadyTallied,
+
+    #[msg("Round participants do not match the funding round")]
+    InvalidRoundParticipants,
+
+    #[msg("Donation is not completed")]
+    DonationNotCompleted,
+
+    #[msg("Dedication message is too long")]
+    DedicationTooLong,
+
+    #[msg("Honoree is invalid")]
+    InvalidHonoree,
+
+    #[msg("Donation is anonymous")]
+    AnonymousDonation,
+
+    #[msg("Salt does not match the donation's commitment")]
+    InvalidCommitment,
+
+    #[msg("Campaign has already been migrated")]
+    CampaignAlreadyMigrated,
+
+    #[msg("Donor position is invalid")]
+    InvalidDonorPosition,
+
+    #[msg("Campaign has not started yet")]
+    CampaignNotStarted,
+
+    #[msg("Start date is too far in the past or future")]
+    InvalidStartDate,
+
+    #[msg("Campaign has already started")]
+    CampaignAlreadyStarted,
+
+    #[msg("Campaign is paused")]
+    CampaignPaused,
+
+    #[msg("Campaign is not paused")]
+    CampaignNotPaused,
+
+    #[msg("Pause reason exceeds maximum length")]
+    PauseReasonTooLong,
+
+    #[msg("Signer is not the pending campaign authority")]
+    NotPendingAuthority,
+
+    #[msg("Beneficiaries must be between one and three distinct addresses")]
+    InvalidBeneficiaries,
+
+    #[msg("Recipient is not one of the campaign's beneficiaries")]
+    RecipientNotBeneficiary,
+
+    #[msg("No beneficiary change is pending")]
+    NoPendingBeneficiaryChange,
+
+    #[msg("Beneficiary change is still in its waiting period")]
+    BeneficiaryChangeNotReady,
+
+    #[msg("Withdrawal approval signers or threshold are invalid")]
+    InvalidApprovalConfig,
+
+    #[msg("Withdrawals from this campaign need approval from its signers")]
+    ApprovalRequired,
+
+    #[msg("Campaign does not use withdrawal approvals")]
+    ApprovalsNotEnabled,
+
+    #[msg("Signer is not one of the campaign's withdrawal approvers")]
+    NotApprover,
+
+    #[msg("Withdrawal has already been approved by this signer")]
+    AlreadyApproved,
+
+    #[msg("Withdrawal proposal does not have enough approvals")]
+    ApprovalThresholdNotMet,
+
+    #[msg("Withdrawal proposal has expired")]
+    WithdrawalProposalExpired,
+
+    #[msg("Withdrawal proposal has not expired yet")]
+    WithdrawalProposalNotExpired,
+
+    #[msg("Withdrawal proposal does not match the accounts provided")]
+    InvalidWithdrawalProposal,
+
+    #[msg("Hard cap must cover the target and the per-donor limit, and per-donor limits must be positive")]
+    InvalidDonationLimits,
+
+    #[msg("Campaign has reached its hard cap")]
+    HardCapReached,
+
+    #[msg("Donation would exceed the campaign's hard cap")]
+    HardCapExceeded,
+
+    #[msg("Donation would exceed the campaign's per-donor limit")]
+    DonorLimitExceeded,
+
+    #[msg("Campaigns with a per-donor limit do not accept anonymous donations")]
+    AnonymousDonationNotAllowed,
+
+    #[msg("Mock price feeds are only available in builds with the mock-oracle feature")]
+    MockOracleDisabled,
+
+    #[msg("Account is not a campaign in its original title-derived layout")]
+    InvalidLegacyCampaign,
+
+    #[msg("User profile must be migrated to the current layout first")]
+    UserNotMigrated,
+
+    #[msg("User profile has already been migrated")]
+    UserAlreadyMigrated,
+
+    #[msg("Round match can only be returned while the campaign is refunding donors")]
+    RoundMatchNotReturnable,
+
+    #[msg("Campaign has no round match to return")]
+    NoRoundMatch,
+
+    #[msg("Campaign has been removed from the funding round")]
+    RoundCampaignRemoved,
 }
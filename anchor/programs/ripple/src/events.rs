@@ -2,66 +2,441 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 
-// Re-export events from lib.rs
-pub use crate::{ CampaignCreated, DonationReceived, CampaignUpdated, FundsWithdrawn, BadgeAwarded };
-
-// Helper functions to emit events
-pub fn emit_campaign_created(
-    campaign: Pubkey,
-    authority: Pubkey,
-    title: String,
-    category: CampaignCategory,
-    target_amount: u64
-) {
-    emit!(CampaignCreated {
-        campaign,
-        authority,
-        title,
-        category,
-        target_amount,
-    });
-}
-
-pub fn emit_donation_received(
-    donation: Pubkey,
-    campaign: Pubkey,
-    donor: Pubkey,
-    amount: u64,
-    payment_method: PaymentMethod
-) {
-    emit!(DonationReceived {
-        donation,
-        campaign,
-        donor,
-        amount,
-        payment_method,
-    });
-}
-
-pub fn emit_campaign_updated(
-    campaign: Pubkey,
-    authority: Pubkey,
-    new_status: Option<CampaignStatus>
-) {
-    emit!(CampaignUpdated {
-        campaign,
-        authority,
-        new_status,
-    });
-}
-
-pub fn emit_funds_withdrawn(campaign: Pubkey, recipient: Pubkey, amount: u64) {
-    emit!(FundsWithdrawn {
-        campaign,
-        recipient,
-        amount,
-    });
-}
-
-pub fn emit_badge_awarded(user: Pubkey, badge_type: BadgeType, timestamp: i64) {
-    emit!(BadgeAwarded {
-        user,
-        badge_type,
-        timestamp,
-    });
+// Events are emitted with `emit_cpi!` so they are recorded as inner instruction
+// data rather than in program logs, which RPC nodes may truncate.
+
+#[event]
+pub struct UserInitialized {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserMigrated {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignCreated {
+    pub campaign: Pubkey,
+    pub authority: Pubkey,
+    pub title: String,
+    pub category: CampaignCategory,
+    pub target_amount: u64,
+    pub accepted_mint: Option<Pubkey>,
+    pub usd_target: Option<u64>,
+    pub funding_mode: FundingMode,
+    /// `Scheduled` when the campaign starts in the future
+    pub status: CampaignStatus,
+    pub start_date: i64,
+    pub end_date: i64,
+    pub beneficiaries: Vec<Pubkey>,
+    pub donation_limits: DonationLimits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DonationReceived {
+    pub donation: Pubkey,
+    pub campaign: Pubkey,
+    /// `Pubkey::default()` for anonymous donations
+    pub donor: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub usd_value: Option<u64>,
+    pub payment_method: PaymentMethod,
+    /// Campaign totals after the donation
+    pub raised_amount: u64,
+    pub raised_usd: u64,
+    pub donors_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TributeDonation {
+    pub donation: Pubkey,
+    pub campaign: Pubkey,
+    /// `Pubkey::default()` for anonymous donations
+    pub donor: Pubkey,
+    pub honoree: Pubkey,
+    pub dedication: String,
+    pub amount: u64,
+    /// User profile the donation was credited to, or `Pubkey::default()` for an
+    /// anonymous donation that credited nobody
+    pub credited_user: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DonationRevealed {
+    pub donation: Pubkey,
+    pub campaign: Pubkey,
+    pub donor: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignUpdated {
+    pub campaign: Pubkey,
+    pub authority: Pubkey,
+    pub title: String,
+    pub new_status: Option<CampaignStatus>,
+    pub start_date: i64,
+    pub end_date: i64,
+    pub is_urgent: bool,
+    pub donation_limits: DonationLimits,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignMigrated {
+    pub campaign: Pubkey,
+    pub authority: Pubkey,
+    pub legacy_vault: Pubkey,
+    pub vault: Pubkey,
+    /// Lamports moved out of the legacy vault
+    pub lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundsWithdrawn {
+    pub campaign: Pubkey,
+    pub withdrawal: Pubkey,
+    pub sequence: u64,
+    pub recipient: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    /// Campaign total withdrawn after this withdrawal
+    pub withdrawn_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BadgeAwarded {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+    pub badge_type: BadgeType,
+    pub total_donations: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RefundClaimed {
+    pub campaign: Pubkey,
+    pub donation: Pubkey,
+    pub donor: Pubkey,
+    pub amount: u64,
+    /// Share of the refund returned to the donation's matching pool
+    pub matched_refund: u64,
+    /// Campaign total after the refund
+    pub raised_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignCancelled {
+    pub campaign: Pubkey,
+    pub authority: Pubkey,
+    pub refund_pool: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignAuthorityProposed {
+    pub campaign: Pubkey,
+    pub authority: Pubkey,
+    /// `None` when a previous proposal is withdrawn
+    pub pending_authority: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignAuthorityTransferred {
+    pub campaign: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignRoleGranted {
+    pub campaign: Pubkey,
+    pub member: Pubkey,
+    pub role: CampaignRoleKind,
+    pub granted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignRoleRevoked {
+    pub campaign: Pubkey,
+    pub member: Pubkey,
+    pub role: CampaignRoleKind,
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryChangeProposed {
+    pub campaign: Pubkey,
+    /// `None` when a previous proposal is withdrawn
+    pub beneficiaries: Option<Vec<Pubkey>>,
+    /// When the change can be applied
+    pub effective_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BeneficiaryChangeApplied {
+    pub campaign: Pubkey,
+    pub previous_beneficiaries: Vec<Pubkey>,
+    pub beneficiaries: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignPaused {
+    pub campaign: Pubkey,
+    pub authority: Pubkey,
+    pub reason: String,
+    pub pause_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignResumed {
+    pub campaign: Pubkey,
+    pub authority: Pubkey,
+    pub paused_duration: i64,
+    pub total_paused_duration: i64,
+    pub end_date: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CampaignSettled {
+    pub campaign: Pubkey,
+    pub caller: Pubkey,
+    pub status: CampaignStatus,
+    pub raised_amount: u64,
+    pub bounty_paid: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneCompleted {
+    pub campaign: Pubkey,
+    pub index: u8,
+    pub status: MilestoneStatus,
+    pub evidence_uri: String,
+    pub evidence_hash: [u8; 32],
+    pub voting_ends_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneVoteCast {
+    pub campaign: Pubkey,
+    pub index: u8,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub weight: u64,
+    /// Milestone tallies after the vote
+    pub approve_weight: u64,
+    pub reject_weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneVoteFinalized {
+    pub campaign: Pubkey,
+    pub index: u8,
+    pub status: MilestoneStatus,
+    pub approve_weight: u64,
+    pub reject_weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionCreated {
+    pub subscription: Pubkey,
+    pub donor: Pubkey,
+    pub target: SubscriptionTarget,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub interval: i64,
+    pub next_due: i64,
+    pub max_count: Option<u32>,
+    pub funding: SubscriptionFunding,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionExecuted {
+    pub subscription: Pubkey,
+    pub donation: Pubkey,
+    pub campaign: Pubkey,
+    pub keeper: Pubkey,
+    pub amount: u64,
+    pub executed_count: u32,
+    pub next_due: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionUpdated {
+    pub subscription: Pubkey,
+    pub donor: Pubkey,
+    pub status: SubscriptionStatus,
+    pub escrow_balance: u64,
+    pub next_due: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SubscriptionCancelled {
+    pub subscription: Pubkey,
+    pub donor: Pubkey,
+    pub executed_count: u32,
+    /// Unspent escrow returned to the donor
+    pub refunded_escrow: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MatchingPoolCreated {
+    pub matching_pool: Pubkey,
+    pub sponsor: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub categories: Vec<CampaignCategory>,
+    pub campaigns: Vec<Pubkey>,
+    pub match_ratio_bps: u16,
+    pub per_donation_cap: u64,
+    pub total_cap: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DonationMatched {
+    pub matching_pool: Pubkey,
+    pub donation: Pubkey,
+    pub campaign: Pubkey,
+    pub amount: u64,
+    /// Pool total matched after this donation
+    pub matched_total: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MatchingPoolReclaimed {
+    pub matching_pool: Pubkey,
+    pub sponsor: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundingRoundCreated {
+    pub funding_round: Pubkey,
+    pub admin: Pubkey,
+    pub pot: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub max_contribution_per_donor: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundCampaignRegistered {
+    pub funding_round: Pubkey,
+    pub campaign: Pubkey,
+    pub campaign_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundCampaignRemoved {
+    pub funding_round: Pubkey,
+    pub campaign: Pubkey,
+    pub campaign_count: u32,
+    pub total_score: u128,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundContributionTallied {
+    pub funding_round: Pubkey,
+    pub campaign: Pubkey,
+    pub donor: Pubkey,
+    pub donation: Pubkey,
+    /// Donor's counted contributions to the campaign after the per-donor cap
+    pub counted_amount: u64,
+    /// Campaign score after the contribution
+    pub score: u128,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundMatchDistributed {
+    pub funding_round: Pubkey,
+    pub campaign: Pubkey,
+    pub score: u128,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FundingRoundFinalized {
+    pub funding_round: Pubkey,
+    pub total_score: u128,
+    pub distributed: u64,
+    /// Unallocated pot returned to the admin
+    pub returned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoundMatchReturned {
+    pub funding_round: Pubkey,
+    pub campaign: Pubkey,
+    pub admin: Pubkey,
+    /// Lamports paid back, the match's share of the vault for cancelled campaigns
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalProposed {
+    pub campaign: Pubkey,
+    pub withdrawal_proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalApproved {
+    pub campaign: Pubkey,
+    pub withdrawal_proposal: Pubkey,
+    pub approver: Pubkey,
+    /// Approvals the proposal has after this one
+    pub approval_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WithdrawalProposalExpired {
+    pub campaign: Pubkey,
+    pub withdrawal_proposal: Pubkey,
+    pub timestamp: i64,
 }
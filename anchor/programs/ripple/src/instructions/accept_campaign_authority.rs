@@ -0,0 +1,39 @@
+// File: src/instructions/accept_campaign_authority.rs
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct AcceptCampaignAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = campaign.pending_authority == Some(new_authority.key()) @ RipplError::NotPendingAuthority
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Completes a handover proposed with `propose_campaign_authority`. The
+/// campaign and its vault are not derived from the authority, so they keep
+/// their addresses and funds. Roles granted under the previous authority
+/// lapse and have to be granted again.
+pub fn handler(ctx: Context<AcceptCampaignAuthority>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let previous_authority = campaign.authority;
+
+    campaign.authority = ctx.accounts.new_authority.key();
+    campaign.pending_authority = None;
+    campaign.role_epoch = campaign.role_epoch.saturating_add(1);
+
+    emit_cpi!(CampaignAuthorityTransferred {
+        campaign: campaign.key(),
+        previous_authority,
+        new_authority: campaign.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+// File: src/instructions/apply_beneficiary_change.rs
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ApplyBeneficiaryChange<'info> {
+    /// Anyone can apply a change once its waiting period is over
+    pub caller: Signer<'info>,
+
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+}
+
+pub fn handler(ctx: Context<ApplyBeneficiaryChange>) -> Result<()> {
+    let clock = Clock::get()?;
+    let campaign = &mut ctx.accounts.campaign;
+
+    let change = campaign.pending_beneficiaries
+        .take()
+        .ok_or(error!(RipplError::NoPendingBeneficiaryChange))?;
+    require!(
+        clock.unix_timestamp >= change.effective_at,
+        RipplError::BeneficiaryChangeNotReady
+    );
+
+    let previous_beneficiaries = std::mem::replace(
+        &mut campaign.beneficiaries,
+        change.beneficiaries
+    );
+
+    emit_cpi!(BeneficiaryChangeApplied {
+        campaign: campaign.key(),
+        previous_beneficiaries,
+        beneficiaries: campaign.beneficiaries.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
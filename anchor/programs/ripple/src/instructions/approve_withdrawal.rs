@@ -0,0 +1,48 @@
+// File: src/instructions/approve_withdrawal.rs
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ApproveWithdrawal<'info> {
+    pub approver: Signer<'info>,
+
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        has_one = campaign @ RipplError::InvalidWithdrawalProposal
+    )]
+    pub withdrawal_proposal: Account<'info, WithdrawalProposal>,
+}
+
+pub fn handler(ctx: Context<ApproveWithdrawal>) -> Result<()> {
+    let clock = Clock::get()?;
+    let approver = ctx.accounts.approver.key();
+
+    let approvals = ctx.accounts.campaign.approvals
+        .as_ref()
+        .ok_or(error!(RipplError::ApprovalsNotEnabled))?;
+    require!(approvals.signers.contains(&approver), RipplError::NotApprover);
+
+    let withdrawal_proposal = &mut ctx.accounts.withdrawal_proposal;
+    require!(
+        clock.unix_timestamp < withdrawal_proposal.expires_at,
+        RipplError::WithdrawalProposalExpired
+    );
+    require!(!withdrawal_proposal.approvals.contains(&approver), RipplError::AlreadyApproved);
+
+    withdrawal_proposal.approvals.push(approver);
+
+    emit_cpi!(WithdrawalApproved {
+        campaign: withdrawal_proposal.campaign,
+        withdrawal_proposal: withdrawal_proposal.key(),
+        approver,
+        approval_count: withdrawal_proposal.approvals.len() as u8,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+// File: src/instructions/cancel_campaign.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+use crate::constants::VAULT_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{ open_refund_pool, release_expiry_bounty };
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CancelCampaign<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = campaign.authority == authority.key() @ RipplError::InvalidAuthority,
+        constraint = campaign.status != CampaignStatus::Cancelled @ RipplError::CampaignCancelled
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: This is a PDA owned by the program that serves as a vault for campaign donations.
+    /// Its balance is snapshotted as the pool donors are refunded from.
+    #[account(
+        seeds = [VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: SystemAccount<'info>,
+
+    #[account(
+        constraint = Some(mint.key()) == campaign.accepted_mint @ RipplError::InvalidMint
+    )]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = campaign_vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<CancelCampaign>) -> Result<()> {
+    // Freeze the campaign and snapshot what is left for donors to reclaim
+    let campaign = &mut ctx.accounts.campaign;
+    open_refund_pool(campaign, &ctx.accounts.campaign_vault, &ctx.accounts.vault_token_account)?;
+
+    // No one needs to settle a cancelled campaign, so the bounty goes back to its creator
+    release_expiry_bounty(campaign, &ctx.accounts.authority.to_account_info())?;
+
+    emit_cpi!(CampaignCancelled {
+        campaign: campaign.key(),
+        authority: campaign.authority,
+        refund_pool: campaign.refund_pool,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
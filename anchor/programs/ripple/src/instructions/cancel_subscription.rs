@@ -0,0 +1,61 @@
+// File: src/instructions/cancel_subscription.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ self, Revoke, TokenAccount, TokenInterface };
+use crate::constants::SUBSCRIPTION_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CancelSubscription<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    /// Closing the account returns its rent and any unspent escrow to the donor
+    #[account(
+        mut,
+        close = donor,
+        seeds = [SUBSCRIPTION_SEED, donor.key().as_ref(), &subscription.id.to_le_bytes()],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    /// Token account the subscription was approved on. Pass it to revoke the approval.
+    #[account(
+        mut,
+        constraint = donor_token_account.owner == donor.key() @ RipplError::InvalidTokenAccount
+    )]
+    pub donor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<CancelSubscription>) -> Result<()> {
+    // Only revoke an approval that still belongs to this subscription, so a newer
+    // delegate on the same token account is left alone
+    if let (Some(donor_token_account), Some(token_program)) = (
+        &ctx.accounts.donor_token_account,
+        &ctx.accounts.token_program,
+    ) {
+        if donor_token_account.delegate == Some(ctx.accounts.subscription.key()).into() {
+            token_interface::revoke(
+                CpiContext::new(token_program.to_account_info(), Revoke {
+                    source: donor_token_account.to_account_info(),
+                    authority: ctx.accounts.donor.to_account_info(),
+                })
+            )?;
+        }
+    }
+
+    let subscription = &ctx.accounts.subscription;
+    emit_cpi!(SubscriptionCancelled {
+        subscription: subscription.key(),
+        donor: subscription.donor,
+        executed_count: subscription.executed_count,
+        refunded_escrow: subscription.escrow_balance,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,236 @@
+// File: src/instructions/claim_refund.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+use crate::constants::DONOR_POSITION_SEED;
+use crate::constants::USER_SEED;
+use crate::constants::VAULT_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{ pay_from_vault, VaultTokenAccounts };
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ClaimRefund<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    /// The donor's profile, which stops counting the campaign as supported once
+    /// every gift to it is refunded
+    #[account(
+        mut,
+        seeds = [USER_SEED, donor.key().as_ref()],
+        bump = user.bump
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        has_one = donor @ RipplError::InvalidAuthority,
+        has_one = campaign @ RipplError::DonationNotRefundable,
+        constraint = donation.status == DonationStatus::Completed @ RipplError::DonationNotRefundable
+    )]
+    pub donation: Account<'info, Donation>,
+
+    /// Created here for donations made before positions were tracked
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = 8 + DonorPosition::INIT_SPACE,
+        seeds = [DONOR_POSITION_SEED, campaign.key().as_ref(), donor.key().as_ref()],
+        bump
+    )]
+    pub donor_position: Account<'info, DonorPosition>,
+
+    /// CHECK: This is a PDA owned by the program that serves as a vault for campaign donations.
+    /// The seeds ensure it is the correct vault for this campaign.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: SystemAccount<'info>,
+
+    #[account(
+        constraint = Some(mint.key()) == campaign.accepted_mint @ RipplError::InvalidMint
+    )]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = campaign_vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token account of the donor that receives the refunded tokens
+    #[account(
+        mut,
+        constraint = Some(donor_token_account.mint) == campaign.accepted_mint @ RipplError::InvalidMint,
+        constraint = donor_token_account.owner == donor.key() @ RipplError::InvalidTokenAccount
+    )]
+    pub donor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// Pool that matched the donation. Required when it did, as the pool gets back
+    /// its share of the refund.
+    #[account(
+        mut,
+        constraint = Some(matching_pool.key()) == donation.matching_pool @ RipplError::MissingMatchingPool
+    )]
+    pub matching_pool: Option<Account<'info, MatchingPool>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = matching_pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    // Any extra accounts required by the mint's transfer hook are passed as
+    // remaining accounts.
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimRefund<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let campaign = &ctx.accounts.campaign;
+    let donation = &ctx.accounts.donation;
+    let matched_amount = donation.matched_amount;
+    let gift_amount = donation.amount
+        .checked_add(matched_amount)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+
+    // Cancelled campaigns refund a pro-rata share of what is left in the vault;
+    // all-or-nothing campaigns that missed their goal refund in full
+    let refund_amount = if campaign.status == CampaignStatus::Cancelled {
+        campaign
+            .cancellation_refund(gift_amount)
+            .ok_or(error!(RipplError::InsufficientFunds))?
+    } else {
+        require!(campaign.refunds_open(clock.unix_timestamp), RipplError::RefundNotAvailable);
+        gift_amount
+    };
+
+    // A matched donation's refund is split with the matching pool in proportion
+    // to what each put in
+    let matched_refund = if matched_amount == 0 {
+        0
+    } else {
+        u64::try_from(
+            (refund_amount as u128)
+                .checked_mul(matched_amount as u128)
+                .and_then(|share| share.checked_div(gift_amount as u128))
+                .ok_or(error!(RipplError::InsufficientFunds))?
+        ).map_err(|_| error!(RipplError::InsufficientFunds))?
+    };
+    let donor_refund = refund_amount - matched_refund;
+
+    let token_accounts = match campaign.accepted_mint {
+        Some(_) =>
+            Some(
+                VaultTokenAccounts::try_from_options(
+                    &ctx.accounts.mint,
+                    &ctx.accounts.vault_token_account,
+                    &ctx.accounts.donor_token_account,
+                    &ctx.accounts.token_program
+                )?
+            ),
+        None => None,
+    };
+
+    // Pay the donation back out of the vault, signed by the vault PDA
+    pay_from_vault(
+        campaign,
+        &ctx.accounts.campaign_vault,
+        ctx.bumps.campaign_vault,
+        &ctx.accounts.donor.to_account_info(),
+        token_accounts.as_ref(),
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        donor_refund
+    )?;
+
+    if matched_refund > 0 {
+        let matching_pool = ctx.accounts.matching_pool
+            .as_mut()
+            .ok_or(error!(RipplError::MissingMatchingPool))?;
+        let pool_token_accounts = match campaign.accepted_mint {
+            Some(_) =>
+                Some(
+                    VaultTokenAccounts::try_from_options(
+                        &ctx.accounts.mint,
+                        &ctx.accounts.vault_token_account,
+                        &ctx.accounts.pool_token_account,
+                        &ctx.accounts.token_program
+                    )?
+                ),
+            None => None,
+        };
+        pay_from_vault(
+            campaign,
+            &ctx.accounts.campaign_vault,
+            ctx.bumps.campaign_vault,
+            &matching_pool.to_account_info(),
+            pool_token_accounts.as_ref(),
+            &ctx.accounts.system_program,
+            ctx.remaining_accounts,
+            matched_refund
+        )?;
+
+        // Returned funds can be matched again, or reclaimed by the sponsor
+        matching_pool.matched_total = matching_pool.matched_total.saturating_sub(matched_refund);
+    }
+
+    // Take the donation back out of the campaign totals
+    let campaign = &mut ctx.accounts.campaign;
+    if campaign.status == CampaignStatus::Cancelled {
+        campaign.refund_pool = campaign.refund_pool
+            .checked_sub(refund_amount)
+            .ok_or(error!(RipplError::InsufficientFunds))?;
+    }
+    campaign.raised_amount = campaign.raised_amount
+        .checked_sub(gift_amount)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+    // Only revealed donations can be refunded, so the gift was voteable
+    campaign.voteable_amount = campaign.voteable_amount.saturating_sub(ctx.accounts.donation.amount);
+    campaign.raised_usd = campaign.raised_usd.saturating_sub(
+        ctx.accounts.donation.usd_value.unwrap_or(0)
+    );
+    // The donor only stops counting towards the campaign's donors, and the
+    // campaign towards the donor's supported ones, once all of their gifts are
+    // refunded. Untracked donations from before positions existed always
+    // counted as a donor and a supported campaign of their own.
+    let donor_position = &mut ctx.accounts.donor_position;
+    let position_closed = if donor_position.is_active() {
+        donor_position.remove(ctx.accounts.donation.amount);
+        !donor_position.is_active()
+    } else {
+        true
+    };
+    if position_closed {
+        campaign.donors_count = campaign.donors_count.saturating_sub(1);
+        let user = &mut ctx.accounts.user;
+        user.campaigns_supported = user.campaigns_supported.saturating_sub(1);
+    }
+
+    ctx.accounts.donation.status = DonationStatus::Refunded;
+
+    emit_cpi!(RefundClaimed {
+        campaign: ctx.accounts.campaign.key(),
+        donation: ctx.accounts.donation.key(),
+        donor: ctx.accounts.donor.key(),
+        amount: donor_refund,
+        matched_refund,
+        raised_amount: ctx.accounts.campaign.raised_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+// File: src/instructions/complete_milestone.rs
+use anchor_lang::prelude::*;
+use crate::constants::MAX_EVIDENCE_URI_LENGTH;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct CompleteMilestone<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = campaign.authority == authority.key() @ RipplError::InvalidAuthority,
+        constraint = campaign.status != CampaignStatus::Cancelled @ RipplError::CampaignCancelled
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+pub fn handler(
+    ctx: Context<CompleteMilestone>,
+    index: u8,
+    evidence_uri: String,
+    evidence_hash: [u8; 32]
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(evidence_uri.len() <= MAX_EVIDENCE_URI_LENGTH, RipplError::EvidenceUriTooLong);
+
+    let campaign_key = ctx.accounts.campaign.key();
+    let campaign = &mut ctx.accounts.campaign;
+    let voting_period = campaign.voting.as_ref().map(|voting| voting.voting_period);
+    let milestone = campaign.milestones
+        .get_mut(index as usize)
+        .ok_or(error!(RipplError::MilestoneNotFound))?;
+    require!(
+        milestone.status == MilestoneStatus::Pending,
+        RipplError::MilestoneAlreadyCompleted
+    );
+    require!(clock.unix_timestamp <= milestone.deadline, RipplError::MilestoneDeadlinePassed);
+
+    // Record the evidence. Without donor voting the milestone's funds become
+    // withdrawable straight away; otherwise donors get to vote on it first.
+    milestone.completed_at = Some(clock.unix_timestamp);
+    milestone.evidence_uri = evidence_uri;
+    milestone.evidence_hash = evidence_hash;
+    match voting_period {
+        Some(voting_period) => {
+            milestone.status = MilestoneStatus::Voting;
+            milestone.voting_ends_at = Some(
+                clock.unix_timestamp
+                    .checked_add(voting_period)
+                    .ok_or(error!(RipplError::InvalidVotingConfig))?
+            );
+        }
+        None => {
+            milestone.status = MilestoneStatus::Approved;
+        }
+    }
+
+    let event = MilestoneCompleted {
+        campaign: campaign_key,
+        index,
+        status: milestone.status.clone(),
+        evidence_uri: milestone.evidence_uri.clone(),
+        evidence_hash: milestone.evidence_hash,
+        voting_ends_at: milestone.voting_ends_at,
+        timestamp: clock.unix_timestamp,
+    };
+    emit_cpi!(event);
+
+    Ok(())
+}
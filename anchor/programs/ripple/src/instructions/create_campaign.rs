@@ -1,19 +1,37 @@
 // File: src/instructions/create_campaign.rs
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+use crate::constants::BPS_DENOMINATOR;
 use crate::constants::CAMPAIGN_SEED;
+use crate::constants::MAX_APPROVAL_SIGNERS;
+use crate::constants::MAX_BENEFICIARIES;
 use crate::constants::MAX_CAMPAIGN_DURATION;
 use crate::constants::MAX_DESCRIPTION_LENGTH;
+use crate::constants::MAX_EXPIRY_BOUNTY;
 use crate::constants::MAX_IMAGE_URL_LENGTH;
+use crate::constants::MAX_MILESTONE_DESCRIPTION_LENGTH;
+use crate::constants::MAX_MILESTONES;
 use crate::constants::MAX_ORGANIZATION_NAME_LENGTH;
+use crate::constants::MAX_START_DATE_BACKDATE;
+use crate::constants::MAX_START_DATE_DELAY;
 use crate::constants::MAX_TITLE_LENGTH;
+use crate::constants::MAX_VOTING_PERIOD;
 use crate::constants::MIN_CAMPAIGN_DURATION;
 use crate::constants::MIN_CAMPAIGN_TARGET;
+use crate::constants::MIN_CAMPAIGN_TARGET_USD;
+use crate::constants::MIN_VOTING_PERIOD;
 use crate::constants::USER_SEED;
+use crate::constants::VAULT_SEED;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
+use crate::utils::{ check_mint_extensions, load_price };
 
 #[derive(Accounts)]
-#[instruction(title: String)]
+#[instruction(params: CreateCampaignParams)]
+#[event_cpi]
 pub struct CreateCampaign<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -21,7 +39,8 @@ pub struct CreateCampaign<'info> {
     #[account(
         mut,
         seeds = [USER_SEED, authority.key().as_ref()],
-        bump
+        bump,
+        constraint = user.to_account_info().data_len() >= 8 + User::INIT_SPACE @ RipplError::UserNotMigrated
     )]
     pub user: Account<'info, User>,
 
@@ -29,53 +48,341 @@ pub struct CreateCampaign<'info> {
         init,
         payer = authority,
         space = 8 + Campaign::INIT_SPACE,
-        seeds = [CAMPAIGN_SEED, title.as_bytes(), authority.key().as_ref()],
+        seeds = [
+            CAMPAIGN_SEED,
+            authority.key().as_ref(),
+            &user.campaigns_created.to_le_bytes(),
+        ],
         bump
     )]
     pub campaign: Account<'info, Campaign>,
 
+    /// CHECK: This is the PDA that owns the campaign's funds. It holds lamports directly for
+    /// SOL campaigns and is the authority of the vault token account for SPL campaigns.
+    #[account(
+        seeds = [VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: SystemAccount<'info>,
+
+    /// Mint the campaign accepts donations in. Omit for a native SOL campaign.
+    pub accepted_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Anyone can create the vault's token account ahead of the campaign, since
+    /// its address is predictable, so an existing one is reused
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = accepted_mint,
+        associated_token::authority = campaign_vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program that owns `accepted_mint`, either SPL Token or Token-2022
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// CHECK: Validated against `params.usd_target` by `load_price`. Only needed for
+    /// campaigns with a USD target.
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
-    ctx: Context<CreateCampaign>,
-    title: String,
-    description: String,
-    category: CampaignCategory,
-    organization_name: String,
-    target_amount: u64,
-    start_date: i64,
-    end_date: i64,
-    image_url: String,
-    is_urgent: bool
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateCampaignParams {
+    pub title: String,
+    pub description: String,
+    pub category: CampaignCategory,
+    pub organization_name: String,
+    pub target_amount: u64,
+    pub start_date: i64,
+    pub end_date: i64,
+    pub image_url: String,
+    pub is_urgent: bool,
+    pub allow_unsafe_mint_extensions: bool,
+    pub usd_target: Option<UsdTarget>,
+    pub funding_mode: FundingMode,
+    /// Lamports paid to whoever settles the campaign with `expire_campaign`
+    pub expiry_bounty: u64,
+    /// Milestones that gate withdrawals; leave empty to release funds on completion
+    pub milestones: Vec<MilestoneParams>,
+    /// Requires donors to approve each milestone before its funds are released
+    pub voting: Option<VotingConfig>,
+    /// Addresses withdrawals may be paid to
+    pub beneficiaries: Vec<Pubkey>,
+    /// Requires several signers to approve each withdrawal instead of the authority alone
+    pub approvals: Option<ApprovalConfig>,
+    pub donation_limits: DonationLimits,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MilestoneParams {
+    pub description: String,
+    pub amount: MilestoneAmount,
+    pub deadline: i64,
+}
+
+fn validate_milestones(milestones: &[MilestoneParams], start_date: i64) -> Result<()> {
+    require!(milestones.len() <= MAX_MILESTONES, RipplError::TooManyMilestones);
+
+    let mut total_bps: u64 = 0;
+    for milestone in milestones {
+        require!(
+            milestone.description.len() <= MAX_MILESTONE_DESCRIPTION_LENGTH,
+            RipplError::DescriptionTooLong
+        );
+        require!(milestone.deadline > start_date, RipplError::InvalidMilestone);
+        match milestone.amount {
+            MilestoneAmount::Fixed(amount) => {
+                require!(amount > 0, RipplError::InvalidMilestone);
+            }
+            MilestoneAmount::Percentage(bps) => {
+                require!(bps > 0, RipplError::InvalidMilestone);
+                total_bps += bps as u64;
+            }
+        }
+    }
+    // Percentage milestones can't release more than everything raised
+    require!(total_bps <= BPS_DENOMINATOR, RipplError::InvalidMilestone);
+
+    Ok(())
+}
+
+fn validate_voting(voting: &VotingConfig, milestones: &[MilestoneParams]) -> Result<()> {
+    // Donors vote on milestones, so there has to be something to vote on
+    require!(!milestones.is_empty(), RipplError::InvalidVotingConfig);
+    require!(
+        (MIN_VOTING_PERIOD..=MAX_VOTING_PERIOD).contains(&voting.voting_period),
+        RipplError::InvalidVotingConfig
+    );
+    require!(
+        voting.quorum_bps > 0 && (voting.quorum_bps as u64) <= BPS_DENOMINATOR,
+        RipplError::InvalidVotingConfig
+    );
+    require!(
+        voting.approval_threshold_bps > 0 &&
+            (voting.approval_threshold_bps as u64) <= BPS_DENOMINATOR,
+        RipplError::InvalidVotingConfig
+    );
+
+    Ok(())
+}
+
+/// Checks a start date is no more than a day in the past, and not so far
+/// ahead that the campaign would sit scheduled indefinitely
+pub(crate) fn validate_start_date(start_date: i64, now: i64) -> Result<()> {
+    require!(start_date >= now - MAX_START_DATE_BACKDATE, RipplError::InvalidStartDate);
+    require!(start_date <= now + MAX_START_DATE_DELAY, RipplError::InvalidStartDate);
+    Ok(())
+}
+
+/// Checks a campaign has at least one beneficiary, no more than it has room
+/// for, and no address listed twice
+pub(crate) fn validate_beneficiaries(beneficiaries: &[Pubkey]) -> Result<()> {
+    require!(
+        !beneficiaries.is_empty() && beneficiaries.len() <= MAX_BENEFICIARIES,
+        RipplError::InvalidBeneficiaries
+    );
+    for (i, beneficiary) in beneficiaries.iter().enumerate() {
+        require!(*beneficiary != Pubkey::default(), RipplError::InvalidBeneficiaries);
+        require!(!beneficiaries[..i].contains(beneficiary), RipplError::InvalidBeneficiaries);
+    }
+    Ok(())
+}
+
+/// Checks a hard cap leaves room to reach `target_amount` and covers what has
+/// been raised already, and that a per-donor limit fits under it. USD campaigns
+/// pass no target, since their target isn't in the campaign's unit.
+pub(crate) fn validate_donation_limits(
+    limits: &DonationLimits,
+    target_amount: Option<u64>,
+    raised_amount: u64
 ) -> Result<()> {
+    if let Some(hard_cap) = limits.hard_cap {
+        require!(
+            hard_cap > 0 && hard_cap >= raised_amount && hard_cap >= target_amount.unwrap_or(0),
+            RipplError::InvalidDonationLimits
+        );
+    }
+    if let Some(max_per_donor) = limits.max_per_donor {
+        require!(
+            max_per_donor > 0 && max_per_donor <= limits.hard_cap.unwrap_or(u64::MAX),
+            RipplError::InvalidDonationLimits
+        );
+    }
+    Ok(())
+}
+
+fn validate_approvals(approvals: &ApprovalConfig) -> Result<()> {
+    let signers = &approvals.signers;
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_APPROVAL_SIGNERS,
+        RipplError::InvalidApprovalConfig
+    );
+    for (i, signer) in signers.iter().enumerate() {
+        require!(!signers[..i].contains(signer), RipplError::InvalidApprovalConfig);
+    }
+    require!(
+        approvals.threshold > 0 && (approvals.threshold as usize) <= signers.len(),
+        RipplError::InvalidApprovalConfig
+    );
+    Ok(())
+}
+
+pub fn handler(ctx: Context<CreateCampaign>, params: CreateCampaignParams) -> Result<()> {
+    let clock = Clock::get()?;
+
     // Validate inputs
-    require!(title.len() <= MAX_TITLE_LENGTH, RipplError::TitleTooLong);
-    require!(description.len() <= MAX_DESCRIPTION_LENGTH, RipplError::DescriptionTooLong);
+    require!(params.title.len() <= MAX_TITLE_LENGTH, RipplError::TitleTooLong);
+    require!(params.description.len() <= MAX_DESCRIPTION_LENGTH, RipplError::DescriptionTooLong);
     require!(
-        organization_name.len() <= MAX_ORGANIZATION_NAME_LENGTH,
+        params.organization_name.len() <= MAX_ORGANIZATION_NAME_LENGTH,
         RipplError::OrganizationNameTooLong
     );
-    require!(image_url.len() <= MAX_IMAGE_URL_LENGTH, RipplError::ImageUrlTooLong);
-    require!(end_date - start_date >= MIN_CAMPAIGN_DURATION, RipplError::CampaignDurationTooShort);
-    require!(end_date - start_date <= MAX_CAMPAIGN_DURATION, RipplError::CampaignDurationTooLong);
-    require!(target_amount >= MIN_CAMPAIGN_TARGET, RipplError::TargetAmountTooLow);
+    require!(params.image_url.len() <= MAX_IMAGE_URL_LENGTH, RipplError::ImageUrlTooLong);
+    validate_start_date(params.start_date, clock.unix_timestamp)?;
+    require!(
+        params.end_date - params.start_date >= MIN_CAMPAIGN_DURATION,
+        RipplError::CampaignDurationTooShort
+    );
+    require!(
+        params.end_date - params.start_date <= MAX_CAMPAIGN_DURATION,
+        RipplError::CampaignDurationTooLong
+    );
+    require!(params.expiry_bounty <= MAX_EXPIRY_BOUNTY, RipplError::ExpiryBountyTooHigh);
+    validate_milestones(&params.milestones, params.start_date)?;
+    if let Some(voting) = &params.voting {
+        validate_voting(voting, &params.milestones)?;
+    }
+    validate_beneficiaries(&params.beneficiaries)?;
+    if let Some(approvals) = &params.approvals {
+        validate_approvals(approvals)?;
+    }
+    let target_amount = params.usd_target.is_none().then_some(params.target_amount);
+    validate_donation_limits(&params.donation_limits, target_amount, 0)?;
+
+    // A token campaign must come with its vault token account and a mint we can hold safely
+    let accepted_mint = ctx.accounts.accepted_mint.as_ref().map(|mint| mint.key());
+    if let Some(mint) = &ctx.accounts.accepted_mint {
+        require!(ctx.accounts.vault_token_account.is_some(), RipplError::MissingTokenAccounts);
+        check_mint_extensions(mint, params.allow_unsafe_mint_extensions)?;
+    }
+
+    // The target is checked in whichever unit the campaign is denominated in
+    if let Some(usd_target) = &params.usd_target {
+        require!(
+            usd_target.target_usd >= MIN_CAMPAIGN_TARGET_USD,
+            RipplError::TargetAmountTooLow
+        );
+        let price_feed = ctx.accounts.price_feed
+            .as_ref()
+            .ok_or(error!(RipplError::MissingPriceFeed))?;
+        load_price(price_feed, usd_target, clock.unix_timestamp)?;
+    } else if accepted_mint.is_some() {
+        require!(params.target_amount > 0, RipplError::TargetAmountTooLow);
+    } else {
+        require!(params.target_amount >= MIN_CAMPAIGN_TARGET, RipplError::TargetAmountTooLow);
+    }
+
+    let user = &mut ctx.accounts.user;
+    let campaign_id = user.campaigns_created;
+    user.campaigns_created = campaign_id
+        .checked_add(1)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
 
     let campaign = &mut ctx.accounts.campaign;
     campaign.authority = ctx.accounts.authority.key();
-    campaign.title = title;
-    campaign.description = description;
-    campaign.category = category;
-    campaign.organization_name = organization_name;
-    campaign.target_amount = target_amount;
+    campaign.id = campaign_id;
+    campaign.title = params.title;
+    campaign.description = params.description;
+    campaign.category = params.category;
+    campaign.organization_name = params.organization_name;
+    campaign.target_amount = params.target_amount;
     campaign.raised_amount = 0;
     campaign.donors_count = 0;
-    campaign.start_date = start_date;
-    campaign.end_date = end_date;
-    campaign.status = CampaignStatus::Active;
-    campaign.image_url = image_url;
-    campaign.is_urgent = is_urgent;
+    campaign.donation_count = 0;
+    campaign.start_date = params.start_date;
+    campaign.end_date = params.end_date;
+    // Campaigns starting in the future wait until their start date to open
+    campaign.status = if params.start_date > clock.unix_timestamp {
+        CampaignStatus::Scheduled
+    } else {
+        CampaignStatus::Active
+    };
+    campaign.image_url = params.image_url;
+    campaign.is_urgent = params.is_urgent;
+    campaign.accepted_mint = accepted_mint;
+    campaign.allow_unsafe_mint_extensions = params.allow_unsafe_mint_extensions;
+    campaign.usd_target = params.usd_target;
+    campaign.raised_usd = 0;
+    campaign.funding_mode = params.funding_mode;
+    campaign.refund_pool = 0;
+    campaign.expiry_bounty = params.expiry_bounty;
+    campaign.milestones = params.milestones
+        .into_iter()
+        .map(|milestone| Milestone {
+            description: milestone.description,
+            amount: milestone.amount,
+            deadline: milestone.deadline,
+            status: MilestoneStatus::Pending,
+            completed_at: None,
+            evidence_uri: String::new(),
+            evidence_hash: [0; 32],
+            voting_ends_at: None,
+            approve_weight: 0,
+            reject_weight: 0,
+        })
+        .collect();
+    campaign.voting = params.voting;
+    campaign.voteable_amount = 0;
+    campaign.withdrawn_amount = 0;
+    campaign.withdrawal_count = 0;
+    campaign.paused_at = None;
+    campaign.pause_count = 0;
+    campaign.total_paused_duration = 0;
+    campaign.pending_authority = None;
+    campaign.role_epoch = 0;
+    campaign.beneficiaries = params.beneficiaries;
+    campaign.pending_beneficiaries = None;
+    campaign.approvals = params.approvals;
+    campaign.withdrawal_proposal_count = 0;
+    campaign.donation_limits = params.donation_limits;
     campaign.bump = ctx.bumps.campaign;
 
+    // Park the bounty on the campaign account, on top of its rent-exempt balance
+    if params.expiry_bounty > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.campaign.to_account_info(),
+                }
+            ),
+            params.expiry_bounty
+        )?;
+    }
+
+    let campaign = &ctx.accounts.campaign;
+    emit_cpi!(CampaignCreated {
+        campaign: campaign.key(),
+        authority: campaign.authority,
+        title: campaign.title.clone(),
+        category: campaign.category.clone(),
+        target_amount: campaign.target_amount,
+        accepted_mint: campaign.accepted_mint,
+        usd_target: campaign.usd_target.as_ref().map(|usd_target| usd_target.target_usd),
+        funding_mode: campaign.funding_mode.clone(),
+        status: campaign.status.clone(),
+        start_date: campaign.start_date,
+        end_date: campaign.end_date,
+        beneficiaries: campaign.beneficiaries.clone(),
+        donation_limits: campaign.donation_limits.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
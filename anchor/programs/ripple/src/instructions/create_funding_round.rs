@@ -0,0 +1,84 @@
+// File: src/instructions/create_funding_round.rs
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::constants::FUNDING_ROUND_SEED;
+use crate::constants::MIN_DONATION_AMOUNT;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[instruction(params: CreateFundingRoundParams)]
+#[event_cpi]
+pub struct CreateFundingRound<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + FundingRound::INIT_SPACE,
+        seeds = [FUNDING_ROUND_SEED, admin.key().as_ref(), &params.id.to_le_bytes()],
+        bump
+    )]
+    pub funding_round: Account<'info, FundingRound>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateFundingRoundParams {
+    pub id: u64,
+    /// Lamports to split between campaigns, transferred into the round up front
+    pub pot: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub max_contribution_per_donor: u64,
+}
+
+pub fn handler(ctx: Context<CreateFundingRound>, params: CreateFundingRoundParams) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(params.pot > 0, RipplError::InvalidFundingRound);
+    require!(
+        params.end_time > params.start_time && params.end_time > clock.unix_timestamp,
+        RipplError::InvalidFundingRound
+    );
+    require!(
+        params.max_contribution_per_donor >= MIN_DONATION_AMOUNT,
+        RipplError::InvalidFundingRound
+    );
+
+    system_program::transfer(
+        CpiContext::new(ctx.accounts.system_program.to_account_info(), system_program::Transfer {
+            from: ctx.accounts.admin.to_account_info(),
+            to: ctx.accounts.funding_round.to_account_info(),
+        }),
+        params.pot
+    )?;
+
+    let funding_round = &mut ctx.accounts.funding_round;
+    funding_round.admin = ctx.accounts.admin.key();
+    funding_round.id = params.id;
+    funding_round.pot = params.pot;
+    funding_round.start_time = params.start_time;
+    funding_round.end_time = params.end_time;
+    funding_round.max_contribution_per_donor = params.max_contribution_per_donor;
+    funding_round.campaign_count = 0;
+    funding_round.total_score = 0;
+    funding_round.finalized = false;
+    funding_round.distributed = 0;
+    funding_round.bump = ctx.bumps.funding_round;
+
+    emit_cpi!(FundingRoundCreated {
+        funding_round: funding_round.key(),
+        admin: funding_round.admin,
+        pot: funding_round.pot,
+        start_time: funding_round.start_time,
+        end_time: funding_round.end_time,
+        max_contribution_per_donor: funding_round.max_contribution_per_donor,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+// File: src/instructions/create_matching_pool.rs
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+use crate::constants::MATCHING_POOL_SEED;
+use crate::constants::MAX_MATCHING_CAMPAIGNS;
+use crate::constants::MAX_MATCHING_CATEGORIES;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::transfer_tokens;
+
+#[derive(Accounts)]
+#[instruction(params: CreateMatchingPoolParams)]
+#[event_cpi]
+pub struct CreateMatchingPool<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    #[account(
+        init,
+        payer = sponsor,
+        space = 8 + MatchingPool::INIT_SPACE,
+        seeds = [MATCHING_POOL_SEED, sponsor.key().as_ref(), &params.id.to_le_bytes()],
+        bump
+    )]
+    pub matching_pool: Account<'info, MatchingPool>,
+
+    /// Mint the pool matches in. Omit for a SOL pool.
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = sponsor_token_account.owner == sponsor.key() @ RipplError::InvalidTokenAccount
+    )]
+    pub sponsor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The pool's address is predictable, so its token account may already
+    /// have been created by someone else and is reused
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        associated_token::mint = mint,
+        associated_token::authority = matching_pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    pub system_program: Program<'info, System>,
+    // Any extra accounts required by the mint's transfer hook are passed as
+    // remaining accounts.
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateMatchingPoolParams {
+    pub id: u64,
+    /// Campaign categories whose donations are matched
+    pub categories: Vec<CampaignCategory>,
+    /// Individual campaigns whose donations are matched
+    pub campaigns: Vec<Pubkey>,
+    /// Amount matched per unit donated, in basis points (10_000 = 1:1)
+    pub match_ratio_bps: u16,
+    pub per_donation_cap: u64,
+    /// Total to match, transferred into the pool up front
+    pub total_cap: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateMatchingPool<'info>>,
+    params: CreateMatchingPoolParams
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        !params.categories.is_empty() || !params.campaigns.is_empty(),
+        RipplError::InvalidMatchingPool
+    );
+    require!(params.categories.len() <= MAX_MATCHING_CATEGORIES, RipplError::InvalidMatchingPool);
+    require!(params.campaigns.len() <= MAX_MATCHING_CAMPAIGNS, RipplError::InvalidMatchingPool);
+    require!(params.match_ratio_bps > 0, RipplError::InvalidMatchingPool);
+    require!(params.per_donation_cap > 0 && params.total_cap > 0, RipplError::InvalidMatchingPool);
+    require!(
+        params.end_time > params.start_time && params.end_time > clock.unix_timestamp,
+        RipplError::InvalidMatchingPool
+    );
+
+    // Fund the pool with everything the sponsor has committed
+    let mint = ctx.accounts.mint.as_ref().map(|mint| mint.key());
+    let total_cap = match &ctx.accounts.mint {
+        Some(mint) => {
+            let (Some(sponsor_token_account), Some(pool_token_account), Some(token_program)) = (
+                &ctx.accounts.sponsor_token_account,
+                &mut ctx.accounts.pool_token_account,
+                &ctx.accounts.token_program,
+            ) else {
+                return Err(error!(RipplError::MissingTokenAccounts));
+            };
+            let balance_before = pool_token_account.amount;
+
+            transfer_tokens(
+                token_program,
+                sponsor_token_account,
+                mint,
+                pool_token_account,
+                &ctx.accounts.sponsor.to_account_info(),
+                ctx.remaining_accounts,
+                params.total_cap,
+                &[]
+            )?;
+
+            // Only commit what the pool actually received after transfer fees
+            pool_token_account.reload()?;
+            pool_token_account.amount
+                .checked_sub(balance_before)
+                .ok_or(RipplError::InsufficientFunds)?
+        }
+        None => {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.sponsor.to_account_info(),
+                        to: ctx.accounts.matching_pool.to_account_info(),
+                    }
+                ),
+                params.total_cap
+            )?;
+
+            params.total_cap
+        }
+    };
+
+    let matching_pool = &mut ctx.accounts.matching_pool;
+    matching_pool.sponsor = ctx.accounts.sponsor.key();
+    matching_pool.id = params.id;
+    matching_pool.mint = mint;
+    matching_pool.categories = params.categories;
+    matching_pool.campaigns = params.campaigns;
+    matching_pool.match_ratio_bps = params.match_ratio_bps;
+    matching_pool.per_donation_cap = params.per_donation_cap;
+    matching_pool.total_cap = total_cap;
+    matching_pool.matched_total = 0;
+    matching_pool.matched_count = 0;
+    matching_pool.start_time = params.start_time;
+    matching_pool.end_time = params.end_time;
+    matching_pool.bump = ctx.bumps.matching_pool;
+
+    emit_cpi!(MatchingPoolCreated {
+        matching_pool: matching_pool.key(),
+        sponsor: matching_pool.sponsor,
+        mint: matching_pool.mint,
+        categories: matching_pool.categories.clone(),
+        campaigns: matching_pool.campaigns.clone(),
+        match_ratio_bps: matching_pool.match_ratio_bps,
+        per_donation_cap: matching_pool.per_donation_cap,
+        total_cap: matching_pool.total_cap,
+        start_time: matching_pool.start_time,
+        end_time: matching_pool.end_time,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
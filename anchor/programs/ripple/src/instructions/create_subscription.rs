@@ -0,0 +1,157 @@
+// File: src/instructions/create_subscription.rs
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{ self, Approve, Mint, TokenAccount, TokenInterface };
+use crate::constants::MAX_SUBSCRIPTION_INTERVAL;
+use crate::constants::MIN_DONATION_AMOUNT;
+use crate::constants::MIN_SUBSCRIPTION_INTERVAL;
+use crate::constants::SUBSCRIPTION_SEED;
+use crate::constants::USER_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[instruction(params: CreateSubscriptionParams)]
+#[event_cpi]
+pub struct CreateSubscription<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    /// Executions credit the donor's profile, so it has to exist up front
+    #[account(
+        seeds = [USER_SEED, donor.key().as_ref()],
+        bump = user.bump
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(
+        init,
+        payer = donor,
+        space = 8 + Subscription::INIT_SPACE,
+        seeds = [SUBSCRIPTION_SEED, donor.key().as_ref(), &params.id.to_le_bytes()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    /// Mint to donate in. Omit for a SOL subscription funded from escrow.
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Token account the subscription is approved to draw donations from
+    #[account(
+        mut,
+        constraint = donor_token_account.owner == donor.key() @ RipplError::InvalidTokenAccount
+    )]
+    pub donor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSubscriptionParams {
+    pub id: u64,
+    pub target: SubscriptionTarget,
+    pub amount: u64,
+    /// Time between donations, in seconds
+    pub interval: i64,
+    /// When the first donation falls due; defaults to immediately
+    pub start_at: Option<i64>,
+    /// Number of donations to make; required for token subscriptions
+    pub max_count: Option<u32>,
+    /// Lamports to prepay into escrow for a SOL subscription
+    pub escrow_deposit: u64,
+}
+
+pub fn handler(ctx: Context<CreateSubscription>, params: CreateSubscriptionParams) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(params.amount >= MIN_DONATION_AMOUNT, RipplError::DonationTooLow);
+    require!(
+        (MIN_SUBSCRIPTION_INTERVAL..=MAX_SUBSCRIPTION_INTERVAL).contains(&params.interval),
+        RipplError::InvalidSubscription
+    );
+    require!(params.max_count != Some(0), RipplError::InvalidSubscription);
+
+    let mint = ctx.accounts.mint.as_ref().map(|mint| mint.key());
+    let funding = match &ctx.accounts.mint {
+        Some(mint) => {
+            let (Some(donor_token_account), Some(token_program)) = (
+                &ctx.accounts.donor_token_account,
+                &ctx.accounts.token_program,
+            ) else {
+                return Err(error!(RipplError::MissingTokenAccounts));
+            };
+            require!(donor_token_account.mint == mint.key(), RipplError::InvalidMint);
+            require!(params.escrow_deposit == 0, RipplError::InvalidSubscription);
+
+            // The allowance covers every scheduled donation and nothing more, so a
+            // token subscription must have a fixed number of donations. A token
+            // account only has one delegate; approving here replaces any other.
+            let max_count = params.max_count.ok_or(error!(RipplError::InvalidSubscription))?;
+            let allowance = params.amount
+                .checked_mul(max_count as u64)
+                .ok_or(error!(RipplError::InvalidSubscription))?;
+            token_interface::approve(
+                CpiContext::new(token_program.to_account_info(), Approve {
+                    to: donor_token_account.to_account_info(),
+                    delegate: ctx.accounts.subscription.to_account_info(),
+                    authority: ctx.accounts.donor.to_account_info(),
+                }),
+                allowance
+            )?;
+
+            SubscriptionFunding::Delegate
+        }
+        None => {
+            if params.escrow_deposit > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.donor.to_account_info(),
+                            to: ctx.accounts.subscription.to_account_info(),
+                        }
+                    ),
+                    params.escrow_deposit
+                )?;
+            }
+
+            SubscriptionFunding::Escrow
+        }
+    };
+
+    let subscription = &mut ctx.accounts.subscription;
+    subscription.donor = ctx.accounts.donor.key();
+    subscription.id = params.id;
+    subscription.target = params.target;
+    subscription.mint = mint;
+    subscription.amount = params.amount;
+    subscription.interval = params.interval;
+    subscription.next_due = params.start_at.map_or(clock.unix_timestamp, |start_at|
+        start_at.max(clock.unix_timestamp)
+    );
+    subscription.max_count = params.max_count;
+    subscription.executed_count = 0;
+    subscription.funding = funding;
+    subscription.escrow_balance = params.escrow_deposit;
+    subscription.status = SubscriptionStatus::Active;
+    subscription.created_at = clock.unix_timestamp;
+    subscription.bump = ctx.bumps.subscription;
+
+    emit_cpi!(SubscriptionCreated {
+        subscription: subscription.key(),
+        donor: subscription.donor,
+        target: subscription.target.clone(),
+        mint: subscription.mint,
+        amount: subscription.amount,
+        interval: subscription.interval,
+        next_due: subscription.next_due,
+        max_count: subscription.max_count,
+        funding: subscription.funding.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,17 +1,23 @@
 // File: src/instructions/donate.rs
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
 use crate::constants::BRONZE_THRESHOLD;
 use crate::constants::CHAMPION_THRESHOLD;
 use crate::constants::DONATION_SEED;
+use crate::constants::DONOR_POSITION_SEED;
 use crate::constants::GOLD_THRESHOLD;
+use crate::constants::MAX_DEDICATION_LENGTH;
 use crate::constants::MIN_DONATION_AMOUNT;
 use crate::constants::SILVER_THRESHOLD;
 use crate::constants::SUSTAINED_SUPPORTER_MIN_DONATIONS;
 use crate::state::*;
 use crate::errors::*;
-use crate::constants::CAMPAIGN_SEED;
+use crate::events::*;
+use crate::constants::VAULT_SEED;
 use crate::constants::USER_SEED;
+use crate::constants::SOL_DECIMALS;
+use crate::utils::{ load_price, pay_from_pool, take_matching_pool, transfer_tokens, usd_value };
 
 // #[derive(Accounts)]
 // #[instruction(amount: u64, payment_method: PaymentMethod, lol: String)]
@@ -116,7 +122,13 @@ use crate::constants::USER_SEED;
 // }
 
 #[derive(Accounts)]
-#[instruction(amount: u64, payment_method: PaymentMethod, count_in_string: String)]
+#[instruction(
+    amount: u64,
+    payment_method: PaymentMethod,
+    tribute: Option<TributeParams>,
+    donor_commitment: Option<[u8; 32]>
+)]
+#[event_cpi]
 pub struct Donate<'info> {
     #[account(mut)]
     pub donor: Signer<'info>,
@@ -128,104 +140,520 @@ pub struct Donate<'info> {
     )]
     pub user: Account<'info, User>,
 
+    /// Profile of a tribute's honoree. When passed, the donation counts towards the
+    /// honoree's stats and badges instead of the donor's.
     #[account(
         mut,
-        seeds = [
-            CAMPAIGN_SEED, 
-            campaign.title.as_bytes(), 
-            campaign.authority.as_ref(),
-        ],
-        bump = campaign.bump
+        constraint = Some(honoree_user.authority) == tribute.as_ref().map(|tribute| tribute.honoree) @ RipplError::InvalidHonoree
     )]
+    pub honoree_user: Option<Account<'info, User>>,
+
+    #[account(mut)]
     pub campaign: Account<'info, Campaign>,
 
     #[account(
         init,
         payer = donor,
-        space = 8 + std::mem::size_of::<Donation>(),
-        seeds = [
-            DONATION_SEED,
-            campaign.key().as_ref(),
-            donor.key().as_ref(),
-            count_in_string.as_bytes(),
-        ],
+        // Dedications are stored inline, so make room for the message
+        space = Donation::space(tribute.as_ref().map_or(0, |tribute| tribute.dedication.len())),
+        // Donations are numbered per campaign, so they can be walked from 0
+        seeds = [DONATION_SEED, campaign.key().as_ref(), &campaign.donation_count.to_le_bytes()],
         bump
     )]
     pub donation: Account<'info, Donation>,
 
+    /// The donor's running totals for the campaign. Omitted for anonymous
+    /// donations, which it would link to the donor.
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = 8 + DonorPosition::INIT_SPACE,
+        seeds = [DONOR_POSITION_SEED, campaign.key().as_ref(), donor.key().as_ref()],
+        bump
+    )]
+    pub donor_position: Option<Account<'info, DonorPosition>>,
+
     /// CHECK: This is a PDA owned by the program that serves as a vault for campaign donations.
     /// The seeds and constraints ensure it is the correct vault for this campaign.
     #[account(
         mut,
-        seeds = [
-            CAMPAIGN_SEED,
-            campaign.title.as_bytes(),
-            campaign.authority.as_ref(),
-            b"vault"
-        ],
+        seeds = [VAULT_SEED, campaign.key().as_ref()],
         bump
     )]
     pub campaign_vault: SystemAccount<'info>,
 
+    #[account(
+        constraint = Some(mint.key()) == campaign.accepted_mint @ RipplError::InvalidMint
+    )]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = Some(donor_token_account.mint) == campaign.accepted_mint @ RipplError::InvalidMint,
+        constraint = donor_token_account.owner == donor.key() @ RipplError::InvalidTokenAccount
+    )]
+    pub donor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = campaign_vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// CHECK: Validated against `campaign.usd_target` by `load_price`. Only needed for
+    /// campaigns with a USD target.
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
     pub system_program: Program<'info, System>,
+    // An optional matching pool (followed by its token account for token pools)
+    // and any extra accounts required by the mint's transfer hook are passed as
+    // remaining accounts.
 }
 
-pub fn handler(
-    ctx: Context<Donate>,
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Donate<'info>>,
     amount: u64,
     payment_method: PaymentMethod,
-    _count_in_string: String
+    tribute: Option<TributeParams>,
+    donor_commitment: Option<[u8; 32]>
 ) -> Result<()> {
     let clock = Clock::get()?;
 
-    // Validate campaign status
+    // Validate campaign status. Scheduled campaigns open once their start date arrives.
+    ctx.accounts.campaign.activate_if_started(clock.unix_timestamp);
+    require!(ctx.accounts.campaign.status != CampaignStatus::Scheduled, RipplError::CampaignNotStarted);
+    require!(ctx.accounts.campaign.status != CampaignStatus::Paused, RipplError::CampaignPaused);
     require!(ctx.accounts.campaign.status == CampaignStatus::Active, RipplError::CampaignNotActive);
     require!(ctx.accounts.campaign.end_date > clock.unix_timestamp, RipplError::CampaignEnded);
     require!(amount >= MIN_DONATION_AMOUNT, RipplError::DonationTooLow);
+    if let Some(tribute) = &tribute {
+        require!(tribute.dedication.len() <= MAX_DEDICATION_LENGTH, RipplError::DedicationTooLong);
+        require!(tribute.honoree != ctx.accounts.donor.key(), RipplError::InvalidHonoree);
+    }
+    let anonymous = donor_commitment.is_some();
+    require!(
+        ctx.accounts.donor_position.is_some() != anonymous,
+        RipplError::InvalidDonorPosition
+    );
+    let amount = apply_donation_limits(&ctx.accounts.campaign, amount, anonymous, true)?;
+
+    // A matching pool, if any, is passed ahead of the transfer hook accounts
+    let (mut matching, extra_accounts) = take_matching_pool(ctx.remaining_accounts)?;
+
+    let received_amount = if ctx.accounts.campaign.accepted_mint.is_some() {
+        // Transfer tokens from donor to the vault token account
+        let (Some(mint), Some(donor_token_account), Some(vault_token_account), Some(token_program)) = (
+            &ctx.accounts.mint,
+            &ctx.accounts.donor_token_account,
+            &mut ctx.accounts.vault_token_account,
+            &ctx.accounts.token_program,
+        ) else {
+            return Err(error!(RipplError::MissingTokenAccounts));
+        };
+
+        let balance_before = vault_token_account.amount;
+        transfer_tokens(
+            token_program,
+            donor_token_account,
+            mint,
+            vault_token_account,
+            &ctx.accounts.donor.to_account_info(),
+            extra_accounts,
+            amount,
+            &[]
+        )?;
+
+        // Transfer fees are withheld in the vault token account, so only credit
+        // what the vault actually received
+        vault_token_account.reload()?;
+        vault_token_account.amount
+            .checked_sub(balance_before)
+            .ok_or(error!(RipplError::InsufficientFunds))?
+    } else {
+        // Transfer funds from donor to campaign vault using system_program
+        system_program::transfer(
+            CpiContext::new(ctx.accounts.system_program.to_account_info(), system_program::Transfer {
+                from: ctx.accounts.donor.to_account_info(),
+                to: ctx.accounts.campaign_vault.to_account_info(),
+            }),
+            amount
+        )?;
 
-    // Transfer funds from donor to campaign vault using system_program
-    system_program::transfer(
-        CpiContext::new(ctx.accounts.system_program.to_account_info(), system_program::Transfer {
-            from: ctx.accounts.donor.to_account_info(),
-            to: ctx.accounts.campaign_vault.to_account_info(),
-        }),
         amount
-    )?;
+    };
+    if let Some(donor_position) = &ctx.accounts.donor_position {
+        check_donor_limit(&ctx.accounts.campaign, donor_position.total_amount, received_amount)?;
+    }
 
-    // Rest of the handler remains the same...
-    let campaign = &mut ctx.accounts.campaign;
-    campaign.raised_amount = campaign.raised_amount
-        .checked_add(amount)
-        .ok_or(error!(RipplError::InsufficientFunds))?;
-    campaign.donors_count = campaign.donors_count
-        .checked_add(1)
+    // Top the donation up from the sponsor's matching pool
+    let matched_amount = match &mut matching {
+        Some(matching) => {
+            let campaign_key = ctx.accounts.campaign.key();
+            require!(
+                matching.pool.is_eligible(&campaign_key, &ctx.accounts.campaign),
+                RipplError::MatchingPoolNotEligible
+            );
+            // Matches count towards the hard cap too
+            let match_room = ctx.accounts.campaign
+                .cap_room()
+                .map_or(u64::MAX, |room| room.saturating_sub(received_amount));
+            let match_amount = matching.pool
+                .match_for(received_amount, clock.unix_timestamp)
+                .min(match_room);
+            let received_match = if match_amount == 0 {
+                0
+            } else if let Some(vault_token_account) = &mut ctx.accounts.vault_token_account {
+                let (Some(mint), Some(token_program)) = (&ctx.accounts.mint, &ctx.accounts.token_program) else {
+                    return Err(error!(RipplError::MissingTokenAccounts));
+                };
+                let balance_before = vault_token_account.amount;
+                pay_from_pool(
+                    &matching.pool,
+                    matching.pool_token_account.as_ref(),
+                    &ctx.accounts.campaign_vault.to_account_info(),
+                    Some((mint, vault_token_account, token_program)),
+                    extra_accounts,
+                    match_amount
+                )?;
+                vault_token_account.reload()?;
+                vault_token_account.amount
+                    .checked_sub(balance_before)
+                    .ok_or(error!(RipplError::InsufficientFunds))?
+            } else {
+                pay_from_pool(
+                    &matching.pool,
+                    None,
+                    &ctx.accounts.campaign_vault.to_account_info(),
+                    None,
+                    extra_accounts,
+                    match_amount
+                )?;
+                match_amount
+            };
+
+            if match_amount > 0 {
+                let pool = &mut matching.pool;
+                pool.matched_total = pool.matched_total
+                    .checked_add(match_amount)
+                    .ok_or(error!(RipplError::InsufficientFunds))?;
+                pool.matched_count = pool.matched_count
+                    .checked_add(1)
+                    .ok_or(error!(RipplError::InsufficientFunds))?;
+                // The pool isn't part of the instruction's accounts, so persist it by hand
+                pool.exit(&crate::ID)?;
+            }
+            received_match
+        }
+        None => 0,
+    };
+    let gift_amount = received_amount
+        .checked_add(matched_amount)
         .ok_or(error!(RipplError::InsufficientFunds))?;
 
-    // Create donation record
+    // Value the donation in USD for fiat-denominated campaigns
+    let decimals = ctx.accounts.mint
+        .as_ref()
+        .map_or(SOL_DECIMALS, |mint| mint.decimals);
+    let donation_usd_value = value_donation(
+        &ctx.accounts.campaign,
+        &ctx.accounts.price_feed,
+        gift_amount,
+        decimals,
+        clock.unix_timestamp
+    )?;
+
+    // The donation takes the campaign's next sequence number
+    let index = ctx.accounts.campaign.donation_count;
+
+    // Repeat gifts only count once towards the campaign's donors
+    let new_donor = match &mut ctx.accounts.donor_position {
+        Some(donor_position) => {
+            let position_bump = ctx.bumps.donor_position.ok_or(error!(RipplError::InvalidDonorPosition))?;
+            let new_donor = track_donor(
+                donor_position,
+                ctx.accounts.campaign.key(),
+                ctx.accounts.donor.key(),
+                position_bump,
+                received_amount,
+                clock.unix_timestamp
+            )?;
+            // Supported campaigns follow the donor's own position, so they stay
+            // on the donor's profile even when a tribute credits the honoree's
+            if new_donor {
+                add_supported_campaign(&mut ctx.accounts.user)?;
+            }
+            new_donor
+        }
+        None => true,
+    };
+    let voteable_amount = if anonymous { 0 } else { received_amount };
+    credit_campaign(
+        &mut ctx.accounts.campaign,
+        gift_amount,
+        voteable_amount,
+        donation_usd_value,
+        new_donor
+    )?;
+
+    // Create donation record. Anonymous donations store a commitment to the
+    // donor instead of their key.
+    let public_donor = if anonymous { Pubkey::default() } else { ctx.accounts.donor.key() };
     let donation = &mut ctx.accounts.donation;
-    donation.donor = ctx.accounts.donor.key();
+    donation.donor = public_donor;
+    donation.donor_commitment = donor_commitment;
     donation.campaign = ctx.accounts.campaign.key();
-    donation.amount = amount;
+    donation.index = index;
+    donation.mint = ctx.accounts.campaign.accepted_mint;
+    donation.amount = received_amount;
+    donation.usd_value = donation_usd_value;
+    donation.matching_pool = matching
+        .as_ref()
+        .filter(|_| matched_amount > 0)
+        .map(|matching| matching.pool.key());
+    donation.matched_amount = matched_amount;
+    donation.honoree = tribute.as_ref().map(|tribute| tribute.honoree);
+    donation.dedication = tribute
+        .as_ref()
+        .map(|tribute| tribute.dedication.clone())
+        .unwrap_or_default();
     donation.timestamp = clock.unix_timestamp;
     donation.status = DonationStatus::Completed;
     donation.payment_method = payment_method;
     donation.bump = ctx.bumps.donation;
 
-    // Update user stats
-    let user = &mut ctx.accounts.user;
-    user.total_donations = user.total_donations
-        .checked_add(amount)
-        .ok_or(error!(RipplError::InsufficientFunds))?;
+    // Tributes can credit the honoree's profile instead of the donor's, while
+    // anonymous gifts are kept out of the donor's public stats altogether
+    let credited_user = match &mut ctx.accounts.honoree_user {
+        Some(honoree_user) => Some(honoree_user),
+        None if anonymous => None,
+        None => Some(&mut ctx.accounts.user),
+    };
+    let is_sol_donation = ctx.accounts.campaign.accepted_mint.is_none();
+    let (awarded_badges, credited_user_key, credited_authority, total_donations) = match credited_user {
+        Some(credited_user) => (
+            credit_donor(credited_user, received_amount, is_sol_donation)?,
+            credited_user.key(),
+            credited_user.authority,
+            credited_user.total_donations,
+        ),
+        None => (Vec::new(), Pubkey::default(), Pubkey::default(), 0),
+    };
+
+    let campaign = &ctx.accounts.campaign;
+    emit_cpi!(DonationReceived {
+        donation: ctx.accounts.donation.key(),
+        campaign: campaign.key(),
+        donor: public_donor,
+        mint: campaign.accepted_mint,
+        amount: received_amount,
+        usd_value: donation_usd_value,
+        payment_method: ctx.accounts.donation.payment_method.clone(),
+        raised_amount: campaign.raised_amount,
+        raised_usd: campaign.raised_usd,
+        donors_count: campaign.donors_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if let Some(tribute) = tribute {
+        emit_cpi!(TributeDonation {
+            donation: ctx.accounts.donation.key(),
+            campaign: campaign.key(),
+            donor: public_donor,
+            honoree: tribute.honoree,
+            dedication: tribute.dedication,
+            amount: received_amount,
+            credited_user: credited_user_key,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    if let (Some(matching), true) = (&matching, matched_amount > 0) {
+        emit_cpi!(DonationMatched {
+            matching_pool: matching.pool.key(),
+            donation: ctx.accounts.donation.key(),
+            campaign: campaign.key(),
+            amount: matched_amount,
+            matched_total: matching.pool.matched_total,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    for badge_type in awarded_badges {
+        emit_cpi!(BadgeAwarded {
+            user: credited_user_key,
+            authority: credited_authority,
+            badge_type,
+            total_donations,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+/// Dedicates a donation to someone else, such as a gift in their honor or a
+/// company donating on behalf of an employee
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TributeParams {
+    pub honoree: Pubkey,
+    pub dedication: String,
+}
+
+/// Values a donation in micro-USD if the campaign has a USD target
+pub(crate) fn value_donation(
+    campaign: &Campaign,
+    price_feed: &Option<UncheckedAccount>,
+    amount: u64,
+    decimals: u8,
+    now: i64
+) -> Result<Option<u64>> {
+    match &campaign.usd_target {
+        Some(usd_target) => {
+            let price_feed = price_feed.as_ref().ok_or(error!(RipplError::MissingPriceFeed))?;
+            let price = load_price(price_feed, usd_target, now)?;
+            Ok(Some(usd_value(amount, decimals, &price)?))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Fits a gift of `amount` within the campaign's hard cap. With `allow_partial`,
+/// a gift that would overshoot the cap is cut down to what still fits. Anonymous
+/// gifts are rejected outright when each donor's total is limited, since it
+/// can't be tracked for them.
+pub(crate) fn apply_donation_limits(
+    campaign: &Campaign,
+    amount: u64,
+    anonymous: bool,
+    allow_partial: bool
+) -> Result<u64> {
+    let mut amount = amount;
+    if let Some(room) = campaign.cap_room() {
+        // Too little room for a minimum donation counts as full, so trimming
+        // can't take a gift below the minimum
+        require!(room >= MIN_DONATION_AMOUNT, RipplError::HardCapReached);
+        require!(allow_partial || amount <= room, RipplError::HardCapExceeded);
+        amount = amount.min(room);
+    }
+    require!(
+        !anonymous || campaign.donation_limits.max_per_donor.is_none(),
+        RipplError::AnonymousDonationNotAllowed
+    );
+    Ok(amount)
+}
+
+/// Checks that a gift keeps the donor within the campaign's per-donor limit,
+/// given what they have given so far. Measured on what the vault received,
+/// which is what the donor's position is credited with, so transfer fees
+/// don't count against the donor.
+pub(crate) fn check_donor_limit(
+    campaign: &Campaign,
+    donor_total: u64,
+    received_amount: u64
+) -> Result<()> {
+    if let Some(max_per_donor) = campaign.donation_limits.max_per_donor {
+        let new_total = donor_total
+            .checked_add(received_amount)
+            .ok_or(error!(RipplError::InsufficientFunds))?;
+        require!(new_total <= max_per_donor, RipplError::DonorLimitExceeded);
+    }
+    Ok(())
+}
+
+/// Adds a donation to the donor's position, setting the position up on their
+/// first gift. Returns whether the donor is new to the campaign's donors.
+pub(crate) fn track_donor(
+    donor_position: &mut DonorPosition,
+    campaign: Pubkey,
+    donor: Pubkey,
+    bump: u8,
+    amount: u64,
+    now: i64
+) -> Result<bool> {
+    if donor_position.is_first_gift() {
+        donor_position.campaign = campaign;
+        donor_position.donor = donor;
+        donor_position.bump = bump;
+    }
+    // A donor whose earlier gifts were all refunded counts again
+    let new_donor = !donor_position.is_active();
+    donor_position.record(amount, now)?;
+
+    Ok(new_donor)
+}
+
+/// Counts a campaign the donor has just become a donor of towards their
+/// supported campaigns. Refunding every gift to it takes it back off.
+pub(crate) fn add_supported_campaign(user: &mut User) -> Result<()> {
     user.campaigns_supported = user.campaigns_supported
         .checked_add(1)
         .ok_or(error!(RipplError::InsufficientFunds))?;
+    Ok(())
+}
 
-    // Update user badges based on total donations
-    update_user_badges(user, amount)?;
+/// Adds a received donation to the campaign totals. `voteable_amount` is the
+/// part its donor can vote on milestones with: their own gift, without any
+/// match, or nothing while the donation is anonymous.
+pub(crate) fn credit_campaign(
+    campaign: &mut Campaign,
+    amount: u64,
+    voteable_amount: u64,
+    usd_value: Option<u64>,
+    new_donor: bool
+) -> Result<()> {
+    campaign.raised_amount = campaign.raised_amount
+        .checked_add(amount)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+    campaign.voteable_amount = campaign.voteable_amount
+        .checked_add(voteable_amount)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+    if let Some(value) = usd_value {
+        campaign.raised_usd = campaign.raised_usd
+            .checked_add(value)
+            .ok_or(error!(RipplError::InsufficientFunds))?;
+    }
+    if new_donor {
+        campaign.donors_count = campaign.donors_count
+            .checked_add(1)
+            .ok_or(error!(RipplError::InsufficientFunds))?;
+    }
+    campaign.donation_count = campaign.donation_count
+        .checked_add(1)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
 
     Ok(())
 }
-fn update_user_badges(user: &mut User, amount: u64) -> Result<()> {
+
+/// Updates the donor's stats and returns the badges the donation earned them
+pub(crate) fn credit_donor(
+    user: &mut User,
+    amount: u64,
+    is_sol_donation: bool
+) -> Result<Vec<BadgeType>> {
+    // Badge thresholds are denominated in lamports, so only SOL donations
+    // count towards the donor's total.
+    if is_sol_donation {
+        user.total_donations = user.total_donations
+            .checked_add(amount)
+            .ok_or(error!(RipplError::InsufficientFunds))?;
+    }
+
+    // Update user badges based on total donations
+    let badges_before = user.badges.len();
+    update_user_badges(user)?;
+    Ok(
+        user.badges[badges_before..]
+            .iter()
+            .map(|badge| badge.badge_type.clone())
+            .collect()
+    )
+}
+
+fn update_user_badges(user: &mut User) -> Result<()> {
     let total_donated = user.total_donations;
     let clock = Clock::get()?;
 
@@ -282,6 +710,6 @@ fn add_badge(user: &mut User, badge: Badge) -> Result<()> {
     Ok(())
 }
 
-fn has_badge(badges: &Vec<Badge>, badge_type: BadgeType) -> bool {
+fn has_badge(badges: &[Badge], badge_type: BadgeType) -> bool {
     badges.iter().any(|badge| badge.badge_type == badge_type)
 }
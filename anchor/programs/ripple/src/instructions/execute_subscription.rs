@@ -0,0 +1,302 @@
+// File: src/instructions/execute_subscription.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+use crate::constants::DONATION_SEED;
+use crate::constants::DONOR_POSITION_SEED;
+use crate::constants::SOL_DECIMALS;
+use crate::constants::SUBSCRIPTION_SEED;
+use crate::constants::USER_SEED;
+use crate::constants::VAULT_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::instructions::donate::{
+    add_supported_campaign,
+    apply_donation_limits,
+    check_donor_limit,
+    credit_campaign,
+    credit_donor,
+    track_donor,
+    value_donation,
+};
+use crate::utils::transfer_tokens;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ExecuteSubscription<'info> {
+    /// Anyone can execute a subscription once it is due; they pay the donation record's rent
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SUBSCRIPTION_SEED,
+            subscription.donor.as_ref(),
+            &subscription.id.to_le_bytes(),
+        ],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, subscription.donor.as_ref()],
+        bump = user.bump
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(
+        mut,
+        constraint = subscription.accepts_campaign(&campaign.key(), &campaign.authority) @ RipplError::InvalidSubscriptionTarget,
+        constraint = campaign.accepted_mint == subscription.mint @ RipplError::InvalidMint
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = keeper,
+        space = Donation::space(0),
+        seeds = [DONATION_SEED, campaign.key().as_ref(), &campaign.donation_count.to_le_bytes()],
+        bump
+    )]
+    pub donation: Account<'info, Donation>,
+
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = 8 + DonorPosition::INIT_SPACE,
+        seeds = [DONOR_POSITION_SEED, campaign.key().as_ref(), subscription.donor.as_ref()],
+        bump
+    )]
+    pub donor_position: Account<'info, DonorPosition>,
+
+    /// CHECK: This is a PDA owned by the program that serves as a vault for campaign donations.
+    /// The seeds and constraints ensure it is the correct vault for this campaign.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: SystemAccount<'info>,
+
+    #[account(
+        constraint = Some(mint.key()) == campaign.accepted_mint @ RipplError::InvalidMint
+    )]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Token account the subscription was approved to draw from
+    #[account(
+        mut,
+        constraint = Some(donor_token_account.mint) == campaign.accepted_mint @ RipplError::InvalidMint,
+        constraint = donor_token_account.owner == subscription.donor @ RipplError::InvalidTokenAccount
+    )]
+    pub donor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = campaign_vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// CHECK: Validated against `campaign.usd_target` by `load_price`. Only needed for
+    /// campaigns with a USD target.
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+    // Any extra accounts required by the mint's transfer hook are passed as
+    // remaining accounts.
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteSubscription<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let subscription = &ctx.accounts.subscription;
+    require!(subscription.status == SubscriptionStatus::Active, RipplError::SubscriptionNotActive);
+    require!(!subscription.is_exhausted(), RipplError::SubscriptionExhausted);
+    require!(clock.unix_timestamp >= subscription.next_due, RipplError::SubscriptionNotDue);
+    ctx.accounts.campaign.activate_if_started(clock.unix_timestamp);
+    require!(ctx.accounts.campaign.status != CampaignStatus::Scheduled, RipplError::CampaignNotStarted);
+    require!(ctx.accounts.campaign.status != CampaignStatus::Paused, RipplError::CampaignPaused);
+    require!(ctx.accounts.campaign.status == CampaignStatus::Active, RipplError::CampaignNotActive);
+    require!(ctx.accounts.campaign.end_date > clock.unix_timestamp, RipplError::CampaignEnded);
+
+    // Recurring gifts aren't cut down to fit under the hard cap. They fail
+    // instead, until the limits are raised or the donor cancels.
+    let amount = subscription.amount;
+    apply_donation_limits(&ctx.accounts.campaign, amount, false, false)?;
+    let donor = subscription.donor;
+    let received_amount = match subscription.funding {
+        SubscriptionFunding::Delegate => {
+            let (Some(mint), Some(donor_token_account), Some(vault_token_account), Some(token_program)) = (
+                &ctx.accounts.mint,
+                &ctx.accounts.donor_token_account,
+                &mut ctx.accounts.vault_token_account,
+                &ctx.accounts.token_program,
+            ) else {
+                return Err(error!(RipplError::MissingTokenAccounts));
+            };
+
+            // The subscription PDA signs as the delegate approved on the donor's token account
+            let id_bytes = subscription.id.to_le_bytes();
+            let subscription_seeds: [&[u8]; 4] = [
+                SUBSCRIPTION_SEED,
+                donor.as_ref(),
+                &id_bytes,
+                &[subscription.bump],
+            ];
+
+            let balance_before = vault_token_account.amount;
+            transfer_tokens(
+                token_program,
+                donor_token_account,
+                mint,
+                vault_token_account,
+                &subscription.to_account_info(),
+                ctx.remaining_accounts,
+                amount,
+                &[&subscription_seeds[..]]
+            )?;
+
+            // Transfer fees are withheld in the vault token account, so only credit
+            // what the vault actually received
+            vault_token_account.reload()?;
+            vault_token_account.amount
+                .checked_sub(balance_before)
+                .ok_or(error!(RipplError::InsufficientFunds))?
+        }
+        SubscriptionFunding::Escrow => {
+            let subscription = &mut ctx.accounts.subscription;
+            subscription.escrow_balance = subscription.escrow_balance
+                .checked_sub(amount)
+                .ok_or(error!(RipplError::InsufficientEscrow))?;
+
+            // The subscription account is owned by this program, so its escrow can be debited directly
+            let subscription_info = subscription.to_account_info();
+            let vault_info = ctx.accounts.campaign_vault.to_account_info();
+            **subscription_info.try_borrow_mut_lamports()? = subscription_info
+                .lamports()
+                .checked_sub(amount)
+                .ok_or(error!(RipplError::InsufficientEscrow))?;
+            **vault_info.try_borrow_mut_lamports()? = vault_info
+                .lamports()
+                .checked_add(amount)
+                .ok_or(error!(RipplError::InsufficientFunds))?;
+
+            amount
+        }
+    };
+    check_donor_limit(
+        &ctx.accounts.campaign,
+        ctx.accounts.donor_position.total_amount,
+        received_amount
+    )?;
+
+    // Schedule the next donation. A keeper running late doesn't get to execute a
+    // burst of catch-up donations; the schedule restarts from now instead.
+    let subscription = &mut ctx.accounts.subscription;
+    subscription.executed_count = subscription.executed_count
+        .checked_add(1)
+        .ok_or(error!(RipplError::SubscriptionExhausted))?;
+    let next_due = subscription.next_due
+        .checked_add(subscription.interval)
+        .ok_or(error!(RipplError::InvalidSubscription))?;
+    subscription.next_due = if next_due > clock.unix_timestamp {
+        next_due
+    } else {
+        clock.unix_timestamp
+            .checked_add(subscription.interval)
+            .ok_or(error!(RipplError::InvalidSubscription))?
+    };
+
+    let decimals = ctx.accounts.mint
+        .as_ref()
+        .map_or(SOL_DECIMALS, |mint| mint.decimals);
+    let donation_usd_value = value_donation(
+        &ctx.accounts.campaign,
+        &ctx.accounts.price_feed,
+        received_amount,
+        decimals,
+        clock.unix_timestamp
+    )?;
+
+    let index = ctx.accounts.campaign.donation_count;
+    let new_donor = track_donor(
+        &mut ctx.accounts.donor_position,
+        ctx.accounts.campaign.key(),
+        donor,
+        ctx.bumps.donor_position,
+        received_amount,
+        clock.unix_timestamp
+    )?;
+    credit_campaign(
+        &mut ctx.accounts.campaign,
+        received_amount,
+        received_amount,
+        donation_usd_value,
+        new_donor
+    )?;
+    if new_donor {
+        add_supported_campaign(&mut ctx.accounts.user)?;
+    }
+
+    // Create donation record
+    let donation = &mut ctx.accounts.donation;
+    donation.donor = donor;
+    donation.campaign = ctx.accounts.campaign.key();
+    donation.index = index;
+    donation.mint = ctx.accounts.campaign.accepted_mint;
+    donation.amount = received_amount;
+    donation.usd_value = donation_usd_value;
+    donation.timestamp = clock.unix_timestamp;
+    donation.status = DonationStatus::Completed;
+    donation.payment_method = PaymentMethod::CryptoWallet;
+    donation.bump = ctx.bumps.donation;
+
+    let is_sol_donation = ctx.accounts.campaign.accepted_mint.is_none();
+    let awarded_badges = credit_donor(&mut ctx.accounts.user, received_amount, is_sol_donation)?;
+    let total_donations = ctx.accounts.user.total_donations;
+
+    let campaign = &ctx.accounts.campaign;
+    emit_cpi!(DonationReceived {
+        donation: ctx.accounts.donation.key(),
+        campaign: campaign.key(),
+        donor,
+        mint: campaign.accepted_mint,
+        amount: received_amount,
+        usd_value: donation_usd_value,
+        payment_method: PaymentMethod::CryptoWallet,
+        raised_amount: campaign.raised_amount,
+        raised_usd: campaign.raised_usd,
+        donors_count: campaign.donors_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    for badge_type in awarded_badges {
+        emit_cpi!(BadgeAwarded {
+            user: ctx.accounts.user.key(),
+            authority: donor,
+            badge_type,
+            total_donations,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    let subscription = &ctx.accounts.subscription;
+    emit_cpi!(SubscriptionExecuted {
+        subscription: subscription.key(),
+        donation: ctx.accounts.donation.key(),
+        campaign: campaign.key(),
+        keeper: ctx.accounts.keeper.key(),
+        amount: received_amount,
+        executed_count: subscription.executed_count,
+        next_due: subscription.next_due,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+// File: src/instructions/execute_withdrawal.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+use crate::constants::VAULT_SEED;
+use crate::constants::WITHDRAWAL_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::instructions::withdraw_funds::validate_withdrawal;
+use crate::utils::{ pay_from_vault, VaultTokenAccounts };
+
+/// Pays out an approved withdrawal proposal. The proposal is closed and the
+/// withdrawal recorded in the campaign's ledger as with `withdraw_funds`.
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ExecuteWithdrawal<'info> {
+    /// One of the campaign's approval signers
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = matches!(campaign.status, CampaignStatus::Completed | CampaignStatus::Expired) @ RipplError::CampaignNotActive
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        has_one = campaign @ RipplError::InvalidWithdrawalProposal,
+        has_one = proposer @ RipplError::InvalidWithdrawalProposal,
+        has_one = recipient @ RipplError::InvalidWithdrawalProposal,
+        close = proposer
+    )]
+    pub withdrawal_proposal: Account<'info, WithdrawalProposal>,
+
+    /// Receives the proposal's rent back
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + Withdrawal::INIT_SPACE,
+        seeds = [
+            WITHDRAWAL_SEED,
+            campaign.key().as_ref(),
+            &campaign.withdrawal_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub withdrawal: Account<'info, Withdrawal>,
+
+    /// CHECK: This is a PDA owned by the program that serves as a vault for campaign donations.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, campaign.key().as_ref()],
+        bump,
+    )]
+    pub campaign_vault: SystemAccount<'info>,
+
+    /// CHECK: The recipient named in the proposal. It must still be one of the
+    /// campaign's beneficiaries.
+    #[account(
+        mut,
+        constraint = campaign.beneficiaries.contains(&recipient.key()) @ RipplError::RecipientNotBeneficiary
+    )]
+    pub recipient: AccountInfo<'info>,
+
+    #[account(
+        constraint = Some(mint.key()) == campaign.accepted_mint @ RipplError::InvalidMint
+    )]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = campaign_vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token account of `recipient` that receives the withdrawn tokens
+    #[account(
+        mut,
+        constraint = Some(recipient_token_account.mint) == campaign.accepted_mint @ RipplError::InvalidMint,
+        constraint = recipient_token_account.owner == recipient.key() @ RipplError::InvalidTokenAccount
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    pub system_program: Program<'info, System>,
+    // Any extra accounts required by the mint's transfer hook are passed as
+    // remaining accounts.
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ExecuteWithdrawal<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let campaign = &ctx.accounts.campaign;
+    let withdrawal_proposal = &ctx.accounts.withdrawal_proposal;
+
+    let approvals = campaign.approvals.as_ref().ok_or(error!(RipplError::ApprovalsNotEnabled))?;
+    require!(
+        approvals.signers.contains(&ctx.accounts.executor.key()),
+        RipplError::NotApprover
+    );
+    require!(
+        clock.unix_timestamp < withdrawal_proposal.expires_at,
+        RipplError::WithdrawalProposalExpired
+    );
+    require!(
+        withdrawal_proposal.approvals.len() >= (approvals.threshold as usize),
+        RipplError::ApprovalThresholdNotMet
+    );
+
+    let amount = withdrawal_proposal.amount;
+    let withdrawn_amount = validate_withdrawal(campaign, amount)?;
+
+    let token_accounts = match campaign.accepted_mint {
+        Some(_) =>
+            Some(
+                VaultTokenAccounts::try_from_options(
+                    &ctx.accounts.mint,
+                    &ctx.accounts.vault_token_account,
+                    &ctx.accounts.recipient_token_account,
+                    &ctx.accounts.token_program
+                )?
+            ),
+        None => None,
+    };
+
+    // Transfer funds from the vault to the recipient, signed by the vault PDA
+    pay_from_vault(
+        campaign,
+        &ctx.accounts.campaign_vault,
+        ctx.bumps.campaign_vault,
+        &ctx.accounts.recipient,
+        token_accounts.as_ref(),
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        amount
+    )?;
+
+    // Record the withdrawal in the campaign's ledger
+    let withdrawal = &mut ctx.accounts.withdrawal;
+    withdrawal.campaign = ctx.accounts.campaign.key();
+    withdrawal.sequence = ctx.accounts.campaign.withdrawal_count;
+    withdrawal.authority = ctx.accounts.executor.key();
+    withdrawal.recipient = ctx.accounts.recipient.key();
+    withdrawal.mint = ctx.accounts.campaign.accepted_mint;
+    withdrawal.amount = amount;
+    withdrawal.timestamp = clock.unix_timestamp;
+    withdrawal.purpose = ctx.accounts.withdrawal_proposal.purpose.clone();
+    withdrawal.receipt_hash = ctx.accounts.withdrawal_proposal.receipt_hash;
+    withdrawal.bump = ctx.bumps.withdrawal;
+
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.withdrawn_amount = withdrawn_amount;
+    campaign.withdrawal_count = campaign.withdrawal_count
+        .checked_add(1)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+
+    let withdrawal = &ctx.accounts.withdrawal;
+    emit_cpi!(FundsWithdrawn {
+        campaign: withdrawal.campaign,
+        withdrawal: withdrawal.key(),
+        sequence: withdrawal.sequence,
+        recipient: withdrawal.recipient,
+        mint: withdrawal.mint,
+        amount,
+        withdrawn_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
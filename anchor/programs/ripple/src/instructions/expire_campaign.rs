@@ -0,0 +1,55 @@
+// File: src/instructions/expire_campaign.rs
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::release_expiry_bounty;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ExpireCampaign<'info> {
+    /// Anyone can settle a campaign once it has ended; they collect the expiry bounty
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+}
+
+pub fn handler(ctx: Context<ExpireCampaign>) -> Result<()> {
+    let clock = Clock::get()?;
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(clock.unix_timestamp >= campaign.end_date, RipplError::CampaignNotEnded);
+    require!(
+        matches!(
+            campaign.status,
+            CampaignStatus::Active |
+                CampaignStatus::InProgress |
+                CampaignStatus::Scheduled |
+                CampaignStatus::Paused
+        ),
+        RipplError::InvalidStatusTransition
+    );
+
+    // Settle the outcome: a campaign that met its goal is completed, otherwise it expired
+    campaign.status = if campaign.goal_reached() {
+        CampaignStatus::Completed
+    } else {
+        CampaignStatus::Expired
+    };
+
+    let bounty_paid = campaign.expiry_bounty;
+    release_expiry_bounty(campaign, &ctx.accounts.caller.to_account_info())?;
+
+    emit_cpi!(CampaignSettled {
+        campaign: campaign.key(),
+        caller: ctx.accounts.caller.key(),
+        status: campaign.status.clone(),
+        raised_amount: campaign.raised_amount,
+        bounty_paid,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
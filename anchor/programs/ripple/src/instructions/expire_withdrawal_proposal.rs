@@ -0,0 +1,40 @@
+// File: src/instructions/expire_withdrawal_proposal.rs
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ExpireWithdrawalProposal<'info> {
+    /// Anyone can clear out a proposal once it has expired
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = proposer @ RipplError::InvalidWithdrawalProposal,
+        close = proposer
+    )]
+    pub withdrawal_proposal: Account<'info, WithdrawalProposal>,
+
+    /// Receives the proposal's rent back
+    #[account(mut)]
+    pub proposer: SystemAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ExpireWithdrawalProposal>) -> Result<()> {
+    let clock = Clock::get()?;
+    let withdrawal_proposal = &ctx.accounts.withdrawal_proposal;
+    require!(
+        clock.unix_timestamp >= withdrawal_proposal.expires_at,
+        RipplError::WithdrawalProposalNotExpired
+    );
+
+    emit_cpi!(WithdrawalProposalExpired {
+        campaign: withdrawal_proposal.campaign,
+        withdrawal_proposal: withdrawal_proposal.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
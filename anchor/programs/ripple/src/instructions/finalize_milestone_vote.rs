@@ -0,0 +1,95 @@
+// File: src/instructions/finalize_milestone_vote.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+use crate::constants::BPS_DENOMINATOR;
+use crate::constants::VAULT_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{ open_refund_pool, release_expiry_bounty };
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct FinalizeMilestoneVote<'info> {
+    /// Anyone can settle a vote once its window has closed
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: This is a PDA owned by the program that serves as a vault for campaign donations.
+    /// Its balance becomes the refund pool if donors reject the milestone.
+    #[account(
+        seeds = [VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: SystemAccount<'info>,
+
+    #[account(
+        constraint = Some(mint.key()) == campaign.accepted_mint @ RipplError::InvalidMint
+    )]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = campaign_vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+pub fn handler(ctx: Context<FinalizeMilestoneVote>, index: u8) -> Result<()> {
+    let clock = Clock::get()?;
+    let campaign_key = ctx.accounts.campaign.key();
+    let campaign = &mut ctx.accounts.campaign;
+    let voting = campaign.voting.clone().ok_or(error!(RipplError::MilestoneNotVoting))?;
+    // Matches and unrevealed anonymous gifts can't vote, so they don't count
+    // towards the quorum either
+    let voteable_amount = campaign.voteable_amount as u128;
+
+    let milestone = campaign.milestones
+        .get_mut(index as usize)
+        .ok_or(error!(RipplError::MilestoneNotFound))?;
+    require!(milestone.status == MilestoneStatus::Voting, RipplError::MilestoneNotVoting);
+    require!(
+        milestone.voting_ends_at.is_some_and(|ends_at| clock.unix_timestamp >= ends_at),
+        RipplError::VotingStillOpen
+    );
+
+    // A milestone passes when enough of the voteable amount voted and enough of
+    // those votes approve it
+    let approve_weight = milestone.approve_weight as u128;
+    let cast_weight = approve_weight + (milestone.reject_weight as u128);
+    let bps = BPS_DENOMINATOR as u128;
+    let quorum_met = cast_weight * bps >= voteable_amount * (voting.quorum_bps as u128);
+    let approved =
+        cast_weight > 0 &&
+        approve_weight * bps >= cast_weight * (voting.approval_threshold_bps as u128);
+
+    milestone.status = if quorum_met && approved {
+        MilestoneStatus::Approved
+    } else {
+        MilestoneStatus::Rejected
+    };
+    let event = MilestoneVoteFinalized {
+        campaign: campaign_key,
+        index,
+        status: milestone.status.clone(),
+        approve_weight: milestone.approve_weight,
+        reject_weight: milestone.reject_weight,
+        timestamp: clock.unix_timestamp,
+    };
+
+    // Donors didn't sign off, so what is left in the vault goes back to them
+    if event.status == MilestoneStatus::Rejected {
+        open_refund_pool(campaign, &ctx.accounts.campaign_vault, &ctx.accounts.vault_token_account)?;
+        release_expiry_bounty(campaign, &ctx.accounts.caller.to_account_info())?;
+    }
+
+    emit_cpi!(event);
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+// File: src/instructions/finalize_round.rs
+use anchor_lang::prelude::*;
+use crate::constants::FUNDING_ROUND_SEED;
+use crate::constants::SOL_DECIMALS;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::instructions::donate::value_donation;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct FinalizeRound<'info> {
+    /// Anyone can finalize a round once it has ended
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            FUNDING_ROUND_SEED,
+            funding_round.admin.as_ref(),
+            &funding_round.id.to_le_bytes(),
+        ],
+        bump = funding_round.bump,
+        constraint = !funding_round.finalized @ RipplError::RoundFinalized
+    )]
+    pub funding_round: Account<'info, FundingRound>,
+
+    /// CHECK: Receives whatever part of the pot is not allocated to a campaign.
+    #[account(mut, address = funding_round.admin @ RipplError::InvalidAuthority)]
+    pub admin: UncheckedAccount<'info>,
+    // Every registered campaign is passed as remaining accounts, as a writable
+    // (round participant, campaign, campaign vault) triple. Campaigns with a
+    // USD target are followed by their price feed, to value the match with.
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, FinalizeRound<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let funding_round_key = ctx.accounts.funding_round.key();
+
+    require!(
+        clock.unix_timestamp >= ctx.accounts.funding_round.end_time,
+        RipplError::RoundNotEnded
+    );
+    let campaign_count = ctx.accounts.funding_round.campaign_count as usize;
+    let mut settled: Vec<Pubkey> = Vec::with_capacity(campaign_count);
+    let mut distributed: u64 = 0;
+    let mut remaining = ctx.remaining_accounts;
+    while !remaining.is_empty() {
+        require!(remaining.len() >= 3, RipplError::InvalidRoundParticipants);
+        let mut round_participant = Account::<RoundParticipant>::try_from(&remaining[0])?;
+        let mut campaign = Account::<Campaign>::try_from(&remaining[1])?;
+        let vault = &remaining[2];
+        let width = if campaign.usd_target.is_some() { 4 } else { 3 };
+        require!(remaining.len() >= width, RipplError::InvalidRoundParticipants);
+        let price_feed = (width == 4).then(|| UncheckedAccount::try_from(&remaining[3]));
+        remaining = &remaining[width..];
+
+        require!(
+            round_participant.round == funding_round_key &&
+                round_participant.campaign == campaign.key() &&
+                round_participant.vault == vault.key() &&
+                !round_participant.removed,
+            RipplError::InvalidRoundParticipants
+        );
+        require!(
+            !settled.contains(&round_participant.key()),
+            RipplError::InvalidRoundParticipants
+        );
+        settled.push(round_participant.key());
+
+        // Campaigns that are refunding their donors forfeit their match
+        let score = round_participant.score().ok_or(error!(RipplError::InvalidFundingRound))?;
+        let refunding =
+            campaign.status == CampaignStatus::Cancelled || campaign.refunds_open(clock.unix_timestamp);
+        let allocation = if refunding {
+            0
+        } else {
+            ctx.accounts.funding_round
+                .allocation(score)
+                .ok_or(error!(RipplError::InvalidFundingRound))?
+        };
+        // Matches count towards the hard cap; whatever doesn't fit goes back to the admin
+        let allocation = campaign.cap_room().map_or(allocation, |room| allocation.min(room));
+
+        if allocation > 0 {
+            // The round account is owned by this program, so its pot can be debited directly
+            let round_info = ctx.accounts.funding_round.to_account_info();
+            **round_info.try_borrow_mut_lamports()? = round_info
+                .lamports()
+                .checked_sub(allocation)
+                .ok_or(error!(RipplError::InsufficientFunds))?;
+            **vault.try_borrow_mut_lamports()? = vault
+                .lamports()
+                .checked_add(allocation)
+                .ok_or(error!(RipplError::InsufficientFunds))?;
+            distributed = distributed
+                .checked_add(allocation)
+                .ok_or(error!(RipplError::InsufficientFunds))?;
+
+            // The match is raised like any donation, so it counts towards the goal
+            // and milestones, and refunds are shared with it
+            campaign.raised_amount = campaign.raised_amount
+                .checked_add(allocation)
+                .ok_or(error!(RipplError::InsufficientFunds))?;
+            round_participant.match_usd_value = value_donation(
+                &campaign,
+                &price_feed,
+                allocation,
+                SOL_DECIMALS,
+                clock.unix_timestamp
+            )?;
+            if let Some(usd_value) = round_participant.match_usd_value {
+                campaign.raised_usd = campaign.raised_usd
+                    .checked_add(usd_value)
+                    .ok_or(error!(RipplError::InsufficientFunds))?;
+            }
+        }
+
+        // Neither account is part of the instruction's accounts, so persist them by hand
+        round_participant.match_amount = allocation;
+        round_participant.exit(&crate::ID)?;
+        campaign.exit(&crate::ID)?;
+
+        emit_cpi!(RoundMatchDistributed {
+            funding_round: funding_round_key,
+            campaign: campaign.key(),
+            score,
+            amount: allocation,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    // Allocations are only verifiable if every registered campaign is settled at once
+    require!(settled.len() == campaign_count, RipplError::InvalidRoundParticipants);
+
+    // Rounding dust, forfeited matches and the whole pot of a round nobody
+    // contributed to go back to the admin
+    let funding_round = &mut ctx.accounts.funding_round;
+    let returned = funding_round.pot
+        .checked_sub(distributed)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+    if returned > 0 {
+        let round_info = funding_round.to_account_info();
+        let admin_info = ctx.accounts.admin.to_account_info();
+        **round_info.try_borrow_mut_lamports()? = round_info
+            .lamports()
+            .checked_sub(returned)
+            .ok_or(error!(RipplError::InsufficientFunds))?;
+        **admin_info.try_borrow_mut_lamports()? = admin_info
+            .lamports()
+            .checked_add(returned)
+            .ok_or(error!(RipplError::InsufficientFunds))?;
+    }
+
+    funding_round.finalized = true;
+    funding_round.distributed = distributed;
+
+    emit_cpi!(FundingRoundFinalized {
+        funding_round: funding_round_key,
+        total_score: funding_round.total_score,
+        distributed,
+        returned,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
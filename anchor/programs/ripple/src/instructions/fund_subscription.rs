@@ -0,0 +1,53 @@
+// File: src/instructions/fund_subscription.rs
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::constants::SUBSCRIPTION_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct FundSubscription<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_SEED, donor.key().as_ref(), &subscription.id.to_le_bytes()],
+        bump = subscription.bump,
+        constraint = subscription.funding == SubscriptionFunding::Escrow @ RipplError::InvalidSubscription
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FundSubscription>, amount: u64) -> Result<()> {
+    require!(amount > 0, RipplError::InvalidSubscription);
+
+    // Top up the prepaid escrow the keeper draws donations from
+    system_program::transfer(
+        CpiContext::new(ctx.accounts.system_program.to_account_info(), system_program::Transfer {
+            from: ctx.accounts.donor.to_account_info(),
+            to: ctx.accounts.subscription.to_account_info(),
+        }),
+        amount
+    )?;
+
+    let subscription = &mut ctx.accounts.subscription;
+    subscription.escrow_balance = subscription.escrow_balance
+        .checked_add(amount)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+
+    emit_cpi!(SubscriptionUpdated {
+        subscription: subscription.key(),
+        donor: subscription.donor,
+        status: subscription.status.clone(),
+        escrow_balance: subscription.escrow_balance,
+        next_due: subscription.next_due,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// File: src/instructions/grant_campaign_role.rs
+use anchor_lang::prelude::*;
+use crate::constants::CAMPAIGN_ROLE_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+#[event_cpi]
+pub struct GrantCampaignRole<'info> {
+    /// The campaign's authority, or one of its admins
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = campaign.authorizes(&authority.key(), authority_role.as_deref(), CampaignRoleKind::Admin) @ RipplError::InvalidAuthority
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// The signer's own role, when they aren't the campaign's authority
+    #[account(
+        seeds = [CAMPAIGN_ROLE_SEED, campaign.key().as_ref(), authority.key().as_ref()],
+        bump = authority_role.bump
+    )]
+    pub authority_role: Option<Account<'info, CampaignRole>>,
+
+    /// Granting a role to an existing member replaces their current one
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CampaignRole::INIT_SPACE,
+        seeds = [CAMPAIGN_ROLE_SEED, campaign.key().as_ref(), member.as_ref()],
+        bump
+    )]
+    pub campaign_role: Account<'info, CampaignRole>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<GrantCampaignRole>, member: Pubkey, role: CampaignRoleKind) -> Result<()> {
+    let clock = Clock::get()?;
+    let campaign = &ctx.accounts.campaign;
+    let authority = ctx.accounts.authority.key();
+    require!(member != campaign.authority, RipplError::InvalidAuthority);
+
+    // Only the authority can appoint or remove admins
+    let current_role = ctx.accounts.campaign_role.role;
+    let is_admin_change = role == CampaignRoleKind::Admin ||
+        (ctx.accounts.campaign_role.member == member && current_role == CampaignRoleKind::Admin);
+    require!(!is_admin_change || authority == campaign.authority, RipplError::InvalidAuthority);
+
+    let campaign_role = &mut ctx.accounts.campaign_role;
+    campaign_role.campaign = campaign.key();
+    campaign_role.member = member;
+    campaign_role.role = role;
+    campaign_role.granted_by = authority;
+    campaign_role.granted_at = clock.unix_timestamp;
+    campaign_role.epoch = campaign.role_epoch;
+    campaign_role.bump = ctx.bumps.campaign_role;
+
+    emit_cpi!(CampaignRoleGranted {
+        campaign: campaign.key(),
+        member,
+        role,
+        granted_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
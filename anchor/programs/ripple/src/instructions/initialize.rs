@@ -1,8 +1,9 @@
 // File: src/instructions/initialize.rs
 use anchor_lang::prelude::*;
-use crate::{ constants::{ MAX_NAME_LENGTH, USER_SEED }, errors::RipplError, state::* };
+use crate::{ constants::{ MAX_NAME_LENGTH, USER_SEED }, errors::RipplError, events::*, state::* };
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct Initialize<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -32,6 +33,14 @@ pub fn handler(ctx: Context<Initialize>, name: String) -> Result<()> {
     user.badges = Vec::new();
     user.rank = 0;
     user.bump = ctx.bumps.user;
+    user.campaigns_created = 0;
+
+    emit_cpi!(UserInitialized {
+        user: user.key(),
+        authority: user.authority,
+        name: user.name.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
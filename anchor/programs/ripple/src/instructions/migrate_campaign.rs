@@ -0,0 +1,174 @@
+// File: src/instructions/migrate_campaign.rs
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use crate::constants::CAMPAIGN_SEED;
+use crate::constants::VAULT_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::grow_account;
+
+/// Layout of campaigns created when addresses were derived from the title.
+/// These only ever accepted SOL, so their funds sit on the legacy vault itself.
+#[derive(AnchorDeserialize)]
+#[derive(InitSpace)]
+struct LegacyCampaign {
+    authority: Pubkey,
+    #[max_len(100)]
+    title: String,
+    #[max_len(1000)]
+    description: String,
+    category: CampaignCategory,
+    #[max_len(100)]
+    organization_name: String,
+    target_amount: u64,
+    raised_amount: u64,
+    donors_count: u32,
+    start_date: i64,
+    end_date: i64,
+    status: CampaignStatus,
+    #[max_len(200)]
+    image_url: String,
+    is_urgent: bool,
+    bump: u8,
+}
+
+/// Rewrites a campaign created when addresses were derived from the title into
+/// the current layout, and moves its funds into a vault derived from the
+/// campaign's key. The campaign keeps its address, so its donations and other
+/// records stay linked to it. Gifts made before the migration still count
+/// towards the raised amount, but have no refundable donation records.
+#[derive(Accounts)]
+#[event_cpi]
+pub struct MigrateCampaign<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: A campaign in its original layout, which can't be read as a
+    /// `Campaign`. Decoded and checked against its title-derived address in the
+    /// handler.
+    #[account(mut, owner = crate::ID)]
+    pub campaign: UncheckedAccount<'info>,
+
+    /// CHECK: The campaign's original vault, checked against its title-derived
+    /// address in the handler.
+    #[account(mut)]
+    pub legacy_vault: SystemAccount<'info>,
+
+    /// CHECK: The vault the campaign's funds are moved to.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateCampaign>) -> Result<()> {
+    let campaign_info = ctx.accounts.campaign.to_account_info();
+    let authority = ctx.accounts.authority.key();
+
+    let legacy = {
+        let data = campaign_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == Campaign::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        // Legacy campaigns were allocated at exactly their own layout's size
+        require!(
+            data.len() == 8 + LegacyCampaign::INIT_SPACE,
+            RipplError::CampaignAlreadyMigrated
+        );
+        LegacyCampaign::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(legacy.authority, authority, RipplError::InvalidAuthority);
+
+    let legacy_address = Pubkey::create_program_address(
+        &[CAMPAIGN_SEED, legacy.title.as_bytes(), authority.as_ref(), &[legacy.bump]],
+        &crate::ID
+    ).map_err(|_| error!(RipplError::InvalidLegacyCampaign))?;
+    require_keys_eq!(legacy_address, campaign_info.key(), RipplError::InvalidLegacyCampaign);
+
+    let (legacy_vault, legacy_vault_bump) = Pubkey::find_program_address(
+        &[CAMPAIGN_SEED, legacy.title.as_bytes(), authority.as_ref(), b"vault"],
+        &crate::ID
+    );
+    require_keys_eq!(
+        legacy_vault,
+        ctx.accounts.legacy_vault.key(),
+        RipplError::InvalidLegacyCampaign
+    );
+
+    let campaign = Campaign {
+        authority: legacy.authority,
+        // The address stays derived from the title, not from an id
+        id: 0,
+        title: legacy.title,
+        description: legacy.description,
+        category: legacy.category,
+        organization_name: legacy.organization_name,
+        target_amount: legacy.target_amount,
+        raised_amount: legacy.raised_amount,
+        donors_count: legacy.donors_count,
+        // Legacy donation records sit at addresses keyed by donor and are not
+        // indexed by the campaign, so they can't be refunded or vote on
+        // milestones. Indexing starts afresh with the first new donation.
+        donation_count: 0,
+        start_date: legacy.start_date,
+        end_date: legacy.end_date,
+        status: legacy.status,
+        image_url: legacy.image_url,
+        is_urgent: legacy.is_urgent,
+        // Legacy campaigns could pay out to any recipient; the authority is the
+        // one address they are known to trust
+        beneficiaries: vec![legacy.authority],
+        bump: legacy.bump,
+        ..Campaign::default()
+    };
+
+    // Grow the account to the current layout, with the authority covering the rent
+    grow_account(
+        &campaign_info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        8 + Campaign::INIT_SPACE
+    )?;
+    campaign.try_serialize(&mut &mut campaign_info.try_borrow_mut_data()?[..])?;
+
+    // SOL campaigns hold their funds on the vault itself
+    let legacy_vault_seeds: [&[u8]; 5] = [
+        CAMPAIGN_SEED,
+        campaign.title.as_bytes(),
+        authority.as_ref(),
+        b"vault",
+        &[legacy_vault_bump],
+    ];
+    let lamports = ctx.accounts.legacy_vault.lamports();
+    if lamports > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.legacy_vault.to_account_info(),
+                    to: ctx.accounts.campaign_vault.to_account_info(),
+                },
+                &[&legacy_vault_seeds[..]]
+            ),
+            lamports
+        )?;
+    }
+
+    emit_cpi!(CampaignMigrated {
+        campaign: campaign_info.key(),
+        authority,
+        legacy_vault,
+        vault: ctx.accounts.campaign_vault.key(),
+        lamports,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
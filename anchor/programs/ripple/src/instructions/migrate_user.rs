@@ -0,0 +1,98 @@
+// File: src/instructions/migrate_user.rs
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::constants::USER_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::grow_account;
+
+/// Layout of user profiles created before `campaigns_created` was added
+#[derive(AnchorDeserialize)]
+#[derive(InitSpace)]
+struct LegacyUser {
+    authority: Pubkey,
+    #[max_len(50)]
+    name: String,
+    wallet_address: Pubkey,
+    #[max_len(100)]
+    email: String,
+    #[max_len(200)]
+    avatar_url: String,
+    total_donations: u64,
+    campaigns_supported: u32,
+    impact_metrics: ImpactMetrics,
+    #[max_len(5)]
+    badges: Vec<Badge>,
+    rank: u32,
+    bump: u8,
+}
+
+/// Grows a user profile created before campaigns were numbered to the current
+/// layout, so its owner can create campaigns again.
+#[derive(Accounts)]
+#[event_cpi]
+pub struct MigrateUser<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: A profile in its original layout, which is too small to be written
+    /// as a `User`. Decoded by hand in the handler.
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [USER_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub user: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateUser>) -> Result<()> {
+    let user_info = ctx.accounts.user.to_account_info();
+
+    let legacy = {
+        let data = user_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == User::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        // Legacy profiles were allocated at exactly their own layout's size
+        require!(data.len() == 8 + LegacyUser::INIT_SPACE, RipplError::UserAlreadyMigrated);
+        LegacyUser::deserialize(&mut &data[8..])?
+    };
+
+    // Legacy profiles never numbered their campaigns; those they created keep
+    // their title-derived addresses, so numbering starts from zero
+    let user = User {
+        authority: legacy.authority,
+        name: legacy.name,
+        wallet_address: legacy.wallet_address,
+        email: legacy.email,
+        avatar_url: legacy.avatar_url,
+        total_donations: legacy.total_donations,
+        campaigns_supported: legacy.campaigns_supported,
+        impact_metrics: legacy.impact_metrics,
+        badges: legacy.badges,
+        rank: legacy.rank,
+        bump: legacy.bump,
+        campaigns_created: 0,
+    };
+
+    grow_account(
+        &user_info,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        8 + User::INIT_SPACE
+    )?;
+    user.try_serialize(&mut &mut user_info.try_borrow_mut_data()?[..])?;
+
+    emit_cpi!(UserMigrated {
+        user: user_info.key(),
+        authority: user.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
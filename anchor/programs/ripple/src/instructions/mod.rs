@@ -1,12 +1,85 @@
 // File: src/instructions/mod.rs
+// Every instruction module exposes its own `handler`; lib.rs calls them by path.
+#![allow(ambiguous_glob_reexports)]
+
+pub mod accept_campaign_authority;
+pub mod apply_beneficiary_change;
+pub mod approve_withdrawal;
+pub mod cancel_campaign;
+pub mod cancel_subscription;
+pub mod claim_refund;
+pub mod complete_milestone;
 pub mod create_campaign;
+pub mod create_funding_round;
+pub mod create_matching_pool;
+pub mod create_subscription;
 pub mod donate;
+pub mod execute_subscription;
+pub mod execute_withdrawal;
+pub mod expire_campaign;
+pub mod expire_withdrawal_proposal;
+pub mod finalize_milestone_vote;
+pub mod finalize_round;
+pub mod fund_subscription;
+pub mod grant_campaign_role;
 pub mod initialize;
+pub mod migrate_campaign;
+pub mod migrate_user;
+pub mod pause_campaign;
+pub mod propose_beneficiary_change;
+pub mod propose_campaign_authority;
+pub mod propose_withdrawal;
+pub mod reclaim_matching_pool;
+pub mod register_round_campaign;
+pub mod remove_round_campaign;
+pub mod resume_campaign;
+pub mod return_round_match;
+pub mod reveal_donation;
+pub mod revoke_campaign_role;
+pub mod set_mock_price;
+pub mod set_subscription_status;
+pub mod tally_round_contribution;
 pub mod update_campaign;
+pub mod vote_milestone;
 pub mod withdraw_funds;
 
+pub use accept_campaign_authority::*;
+pub use apply_beneficiary_change::*;
+pub use approve_withdrawal::*;
+pub use cancel_campaign::*;
+pub use cancel_subscription::*;
+pub use claim_refund::*;
+pub use complete_milestone::*;
 pub use create_campaign::*;
+pub use create_funding_round::*;
+pub use create_matching_pool::*;
+pub use create_subscription::*;
 pub use donate::*;
+pub use execute_subscription::*;
+pub use execute_withdrawal::*;
+pub use expire_campaign::*;
+pub use expire_withdrawal_proposal::*;
+pub use finalize_milestone_vote::*;
+pub use finalize_round::*;
+pub use fund_subscription::*;
+pub use grant_campaign_role::*;
 pub use initialize::*;
+pub use migrate_campaign::*;
+pub use migrate_user::*;
+pub use pause_campaign::*;
+pub use propose_beneficiary_change::*;
+pub use propose_campaign_authority::*;
+pub use propose_withdrawal::*;
+pub use reclaim_matching_pool::*;
+pub use register_round_campaign::*;
+pub use remove_round_campaign::*;
+pub use resume_campaign::*;
+pub use return_round_match::*;
+pub use reveal_donation::*;
+pub use revoke_campaign_role::*;
+pub use set_mock_price::*;
+pub use set_subscription_status::*;
+pub use tally_round_contribution::*;
 pub use update_campaign::*;
+pub use vote_milestone::*;
 pub use withdraw_funds::*;
@@ -0,0 +1,56 @@
+// File: src/instructions/pause_campaign.rs
+use anchor_lang::prelude::*;
+use crate::constants::CAMPAIGN_ROLE_SEED;
+use crate::constants::MAX_PAUSE_REASON_LENGTH;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct PauseCampaign<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = campaign.authorizes(&authority.key(), campaign_role.as_deref(), CampaignRoleKind::StatusManager) @ RipplError::InvalidAuthority
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// The signer's role on the campaign, when they aren't its authority
+    #[account(
+        seeds = [CAMPAIGN_ROLE_SEED, campaign.key().as_ref(), authority.key().as_ref()],
+        bump = campaign_role.bump
+    )]
+    pub campaign_role: Option<Account<'info, CampaignRole>>,
+}
+
+/// Stops a campaign taking donations until it is resumed. The reason is only
+/// recorded in the emitted event, which together with `CampaignResumed` makes
+/// up the campaign's pause history.
+pub fn handler(ctx: Context<PauseCampaign>, reason: String) -> Result<()> {
+    require!(reason.len() <= MAX_PAUSE_REASON_LENGTH, RipplError::PauseReasonTooLong);
+
+    let clock = Clock::get()?;
+    let campaign = &mut ctx.accounts.campaign;
+    // A scheduled campaign whose start date has passed is open already
+    campaign.activate_if_started(clock.unix_timestamp);
+    require!(campaign.status == CampaignStatus::Active, RipplError::CampaignNotActive);
+    require!(campaign.end_date > clock.unix_timestamp, RipplError::CampaignEnded);
+
+    campaign.status = CampaignStatus::Paused;
+    campaign.paused_at = Some(clock.unix_timestamp);
+    campaign.pause_count = campaign.pause_count
+        .checked_add(1)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+
+    emit_cpi!(CampaignPaused {
+        campaign: campaign.key(),
+        authority: campaign.authority,
+        reason,
+        pause_count: campaign.pause_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+// File: src/instructions/propose_beneficiary_change.rs
+use anchor_lang::prelude::*;
+use crate::constants::BENEFICIARY_CHANGE_DELAY;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::instructions::create_campaign::validate_beneficiaries;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeBeneficiaryChange<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = campaign.authority == authority.key() @ RipplError::InvalidAuthority
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Proposes replacing the campaign's beneficiaries. The change can only be
+/// applied with `apply_beneficiary_change` once `BENEFICIARY_CHANGE_DELAY` has
+/// passed, so donors can see where funds will go before they can be sent
+/// there. A new proposal restarts the delay; passing `None` withdraws it.
+pub fn handler(
+    ctx: Context<ProposeBeneficiaryChange>,
+    beneficiaries: Option<Vec<Pubkey>>
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let campaign = &mut ctx.accounts.campaign;
+
+    campaign.pending_beneficiaries = match beneficiaries {
+        Some(beneficiaries) => {
+            validate_beneficiaries(&beneficiaries)?;
+            Some(BeneficiaryChange {
+                beneficiaries,
+                effective_at: clock.unix_timestamp + BENEFICIARY_CHANGE_DELAY,
+            })
+        }
+        None => {
+            require!(
+                campaign.pending_beneficiaries.is_some(),
+                RipplError::NoPendingBeneficiaryChange
+            );
+            None
+        }
+    };
+
+    let pending = campaign.pending_beneficiaries.as_ref();
+    emit_cpi!(BeneficiaryChangeProposed {
+        campaign: campaign.key(),
+        beneficiaries: pending.map(|change| change.beneficiaries.clone()),
+        effective_at: pending.map(|change| change.effective_at),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
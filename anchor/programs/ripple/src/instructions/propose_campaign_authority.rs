@@ -0,0 +1,37 @@
+// File: src/instructions/propose_campaign_authority.rs
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeCampaignAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = campaign.authority == authority.key() @ RipplError::InvalidAuthority
+    )]
+    pub campaign: Account<'info, Campaign>,
+}
+
+/// Offers control of the campaign to `new_authority`, which takes over once it
+/// calls `accept_campaign_authority`. Passing `None` withdraws the offer.
+/// Campaigns still using a title-derived vault have to be migrated first,
+/// since that vault is derived from the original authority.
+pub fn handler(ctx: Context<ProposeCampaignAuthority>, new_authority: Option<Pubkey>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    require!(new_authority != Some(campaign.authority), RipplError::InvalidAuthority);
+
+    campaign.pending_authority = new_authority;
+
+    emit_cpi!(CampaignAuthorityProposed {
+        campaign: campaign.key(),
+        authority: campaign.authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+// File: src/instructions/propose_withdrawal.rs
+use anchor_lang::prelude::*;
+use crate::constants::MAX_WITHDRAWAL_PURPOSE_LENGTH;
+use crate::constants::WITHDRAWAL_PROPOSAL_LIFETIME;
+use crate::constants::WITHDRAWAL_PROPOSAL_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::instructions::withdraw_funds::validate_withdrawal;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ProposeWithdrawal<'info> {
+    /// One of the campaign's approval signers; their proposal counts as their approval
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = matches!(campaign.status, CampaignStatus::Completed | CampaignStatus::Expired) @ RipplError::CampaignNotActive
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + WithdrawalProposal::INIT_SPACE,
+        seeds = [
+            WITHDRAWAL_PROPOSAL_SEED,
+            campaign.key().as_ref(),
+            &campaign.withdrawal_proposal_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub withdrawal_proposal: Account<'info, WithdrawalProposal>,
+
+    /// CHECK: Only the address is recorded, and it must be one of the campaign's beneficiaries
+    #[account(
+        constraint = campaign.beneficiaries.contains(&recipient.key()) @ RipplError::RecipientNotBeneficiary
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens a withdrawal for the campaign's approval signers to sign off on. It
+/// can be executed with `execute_withdrawal` once enough of them approve,
+/// until it expires after `WITHDRAWAL_PROPOSAL_LIFETIME`.
+pub fn handler(
+    ctx: Context<ProposeWithdrawal>,
+    amount: u64,
+    purpose: String,
+    receipt_hash: Option<[u8; 32]>
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let proposer = ctx.accounts.proposer.key();
+    let campaign = &ctx.accounts.campaign;
+
+    let approvals = campaign.approvals.as_ref().ok_or(error!(RipplError::ApprovalsNotEnabled))?;
+    require!(approvals.signers.contains(&proposer), RipplError::NotApprover);
+    require!(purpose.len() <= MAX_WITHDRAWAL_PURPOSE_LENGTH, RipplError::PurposeTooLong);
+    // Checked again on execution, but there's no point collecting approvals for
+    // a withdrawal that can't go through
+    validate_withdrawal(campaign, amount)?;
+
+    let withdrawal_proposal = &mut ctx.accounts.withdrawal_proposal;
+    withdrawal_proposal.campaign = campaign.key();
+    withdrawal_proposal.index = campaign.withdrawal_proposal_count;
+    withdrawal_proposal.proposer = proposer;
+    withdrawal_proposal.recipient = ctx.accounts.recipient.key();
+    withdrawal_proposal.amount = amount;
+    withdrawal_proposal.purpose = purpose;
+    withdrawal_proposal.receipt_hash = receipt_hash;
+    withdrawal_proposal.approvals = vec![proposer];
+    withdrawal_proposal.created_at = clock.unix_timestamp;
+    withdrawal_proposal.expires_at = clock.unix_timestamp + WITHDRAWAL_PROPOSAL_LIFETIME;
+    withdrawal_proposal.bump = ctx.bumps.withdrawal_proposal;
+
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.withdrawal_proposal_count = campaign.withdrawal_proposal_count
+        .checked_add(1)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+
+    let withdrawal_proposal = &ctx.accounts.withdrawal_proposal;
+    emit_cpi!(WithdrawalProposed {
+        campaign: withdrawal_proposal.campaign,
+        withdrawal_proposal: withdrawal_proposal.key(),
+        proposer,
+        recipient: withdrawal_proposal.recipient,
+        amount,
+        expires_at: withdrawal_proposal.expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// File: src/instructions/reclaim_matching_pool.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+use crate::constants::MATCHING_POOL_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::pay_from_pool;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ReclaimMatchingPool<'info> {
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    /// The pool is kept open after reclaiming, as refunds of matched donations
+    /// return funds to it that the sponsor can reclaim again
+    #[account(
+        mut,
+        seeds = [MATCHING_POOL_SEED, sponsor.key().as_ref(), &matching_pool.id.to_le_bytes()],
+        bump = matching_pool.bump
+    )]
+    pub matching_pool: Account<'info, MatchingPool>,
+
+    #[account(
+        constraint = Some(mint.key()) == matching_pool.mint @ RipplError::InvalidMint
+    )]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = matching_pool,
+        associated_token::token_program = token_program
+    )]
+    pub pool_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = Some(sponsor_token_account.mint) == matching_pool.mint @ RipplError::InvalidMint,
+        constraint = sponsor_token_account.owner == sponsor.key() @ RipplError::InvalidTokenAccount
+    )]
+    pub sponsor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    // Any extra accounts required by the mint's transfer hook are passed as
+    // remaining accounts.
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ReclaimMatchingPool<'info>>) -> Result<()> {
+    let clock = Clock::get()?;
+    let matching_pool = &ctx.accounts.matching_pool;
+    require!(clock.unix_timestamp >= matching_pool.end_time, RipplError::MatchingPoolActive);
+
+    let amount = matching_pool.available();
+    let token_accounts = match matching_pool.mint {
+        Some(_) => {
+            let (Some(mint), Some(sponsor_token_account), Some(token_program)) = (
+                &ctx.accounts.mint,
+                &ctx.accounts.sponsor_token_account,
+                &ctx.accounts.token_program,
+            ) else {
+                return Err(error!(RipplError::MissingTokenAccounts));
+            };
+            Some((mint, sponsor_token_account, token_program))
+        }
+        None => None,
+    };
+
+    if amount > 0 {
+        pay_from_pool(
+            matching_pool,
+            ctx.accounts.pool_token_account.as_ref(),
+            &ctx.accounts.sponsor.to_account_info(),
+            token_accounts,
+            ctx.remaining_accounts,
+            amount
+        )?;
+    }
+
+    // Nothing is left to match
+    let matching_pool = &mut ctx.accounts.matching_pool;
+    matching_pool.total_cap = matching_pool.matched_total;
+
+    emit_cpi!(MatchingPoolReclaimed {
+        matching_pool: matching_pool.key(),
+        sponsor: matching_pool.sponsor,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
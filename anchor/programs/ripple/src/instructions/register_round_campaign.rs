@@ -0,0 +1,90 @@
+// File: src/instructions/register_round_campaign.rs
+use anchor_lang::prelude::*;
+use crate::constants::FUNDING_ROUND_SEED;
+use crate::constants::MAX_ROUND_CAMPAIGNS;
+use crate::constants::ROUND_PARTICIPANT_SEED;
+use crate::constants::VAULT_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct RegisterRoundCampaign<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// Campaigns join a round only with its admin's sign-off, so the limited
+    /// slots can't be filled by anyone else
+    #[account(address = funding_round.admin @ RipplError::InvalidAuthority)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            FUNDING_ROUND_SEED,
+            funding_round.admin.as_ref(),
+            &funding_round.id.to_le_bytes(),
+        ],
+        bump = funding_round.bump,
+        constraint = !funding_round.finalized @ RipplError::RoundFinalized
+    )]
+    pub funding_round: Account<'info, FundingRound>,
+
+    #[account(
+        constraint = campaign.authority == authority.key() @ RipplError::InvalidAuthority,
+        constraint = campaign.accepted_mint.is_none() @ RipplError::InvalidMint
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: This is a PDA owned by the program that serves as a vault for campaign donations.
+    /// Recorded so the round can pay the campaign's match into it.
+    #[account(
+        seeds = [VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RoundParticipant::INIT_SPACE,
+        seeds = [ROUND_PARTICIPANT_SEED, funding_round.key().as_ref(), campaign.key().as_ref()],
+        bump
+    )]
+    pub round_participant: Account<'info, RoundParticipant>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterRoundCampaign>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(clock.unix_timestamp < ctx.accounts.funding_round.end_time, RipplError::RoundNotOpen);
+    require!(!ctx.accounts.campaign.is_closed(), RipplError::CampaignNotActive);
+
+    let funding_round = &mut ctx.accounts.funding_round;
+    require!(funding_round.campaign_count < MAX_ROUND_CAMPAIGNS, RipplError::RoundFull);
+    funding_round.campaign_count += 1;
+
+    let round_participant = &mut ctx.accounts.round_participant;
+    round_participant.round = funding_round.key();
+    round_participant.campaign = ctx.accounts.campaign.key();
+    round_participant.vault = ctx.accounts.campaign_vault.key();
+    round_participant.sum_sqrt = 0;
+    round_participant.contributions = 0;
+    round_participant.contributors = 0;
+    round_participant.match_amount = 0;
+    round_participant.match_usd_value = None;
+    round_participant.removed = false;
+    round_participant.bump = ctx.bumps.round_participant;
+
+    emit_cpi!(RoundCampaignRegistered {
+        funding_round: funding_round.key(),
+        campaign: round_participant.campaign,
+        campaign_count: funding_round.campaign_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+// File: src/instructions/remove_round_campaign.rs
+use anchor_lang::prelude::*;
+use crate::constants::FUNDING_ROUND_SEED;
+use crate::constants::ROUND_PARTICIPANT_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Takes a campaign out of a funding round before it is finalized, freeing its
+/// slot and dropping its score from the round's total.
+#[derive(Accounts)]
+#[event_cpi]
+pub struct RemoveRoundCampaign<'info> {
+    #[account(address = funding_round.admin @ RipplError::InvalidAuthority)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            FUNDING_ROUND_SEED,
+            funding_round.admin.as_ref(),
+            &funding_round.id.to_le_bytes(),
+        ],
+        bump = funding_round.bump,
+        constraint = !funding_round.finalized @ RipplError::RoundFinalized
+    )]
+    pub funding_round: Account<'info, FundingRound>,
+
+    /// Kept rather than closed: its contribution records stay behind, so the
+    /// campaign can't register again and start its score over
+    #[account(
+        mut,
+        seeds = [
+            ROUND_PARTICIPANT_SEED,
+            funding_round.key().as_ref(),
+            round_participant.campaign.as_ref(),
+        ],
+        bump = round_participant.bump,
+        constraint = !round_participant.removed @ RipplError::RoundCampaignRemoved
+    )]
+    pub round_participant: Account<'info, RoundParticipant>,
+}
+
+pub fn handler(ctx: Context<RemoveRoundCampaign>) -> Result<()> {
+    let funding_round = &mut ctx.accounts.funding_round;
+    let round_participant = &mut ctx.accounts.round_participant;
+
+    let score = round_participant.score().ok_or(error!(RipplError::InvalidFundingRound))?;
+    funding_round.total_score = funding_round.total_score
+        .checked_sub(score)
+        .ok_or(error!(RipplError::InvalidFundingRound))?;
+    funding_round.campaign_count = funding_round.campaign_count
+        .checked_sub(1)
+        .ok_or(error!(RipplError::InvalidFundingRound))?;
+    round_participant.removed = true;
+
+    emit_cpi!(RoundCampaignRemoved {
+        funding_round: funding_round.key(),
+        campaign: round_participant.campaign,
+        campaign_count: funding_round.campaign_count,
+        total_score: funding_round.total_score,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
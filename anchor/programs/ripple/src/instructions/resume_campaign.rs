@@ -0,0 +1,61 @@
+// File: src/instructions/resume_campaign.rs
+use anchor_lang::prelude::*;
+use crate::constants::CAMPAIGN_ROLE_SEED;
+use crate::constants::MAX_CAMPAIGN_DURATION;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ResumeCampaign<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = campaign.authorizes(&authority.key(), campaign_role.as_deref(), CampaignRoleKind::StatusManager) @ RipplError::InvalidAuthority,
+        constraint = campaign.status == CampaignStatus::Paused @ RipplError::CampaignNotPaused
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// The signer's role on the campaign, when they aren't its authority
+    #[account(
+        seeds = [CAMPAIGN_ROLE_SEED, campaign.key().as_ref(), authority.key().as_ref()],
+        bump = campaign_role.bump
+    )]
+    pub campaign_role: Option<Account<'info, CampaignRole>>,
+}
+
+/// Reopens a paused campaign for donations. With `extend_end_date`, the end
+/// date is pushed back by however long the campaign was paused, up to the
+/// longest duration a campaign may run for.
+pub fn handler(ctx: Context<ResumeCampaign>, extend_end_date: bool) -> Result<()> {
+    let clock = Clock::get()?;
+    let campaign = &mut ctx.accounts.campaign;
+
+    let paused_at = campaign.paused_at.ok_or(error!(RipplError::CampaignNotPaused))?;
+    let paused_duration = clock.unix_timestamp.saturating_sub(paused_at).max(0);
+
+    if extend_end_date {
+        let latest_end_date = campaign.start_date.saturating_add(MAX_CAMPAIGN_DURATION);
+        campaign.end_date = campaign.end_date
+            .saturating_add(paused_duration)
+            .min(latest_end_date)
+            .max(campaign.end_date);
+    }
+
+    campaign.status = CampaignStatus::Active;
+    campaign.paused_at = None;
+    campaign.total_paused_duration = campaign.total_paused_duration.saturating_add(paused_duration);
+
+    emit_cpi!(CampaignResumed {
+        campaign: campaign.key(),
+        authority: campaign.authority,
+        paused_duration,
+        total_paused_duration: campaign.total_paused_duration,
+        end_date: campaign.end_date,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
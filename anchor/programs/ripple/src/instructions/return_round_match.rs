@@ -0,0 +1,111 @@
+// File: src/instructions/return_round_match.rs
+use anchor_lang::prelude::*;
+use crate::constants::FUNDING_ROUND_SEED;
+use crate::constants::ROUND_PARTICIPANT_SEED;
+use crate::constants::VAULT_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::pay_from_vault;
+
+/// Gives a funding round's match back to the round's admin when the campaign it
+/// went to is refunding its donors, rather than leaving it in the vault.
+#[derive(Accounts)]
+#[event_cpi]
+pub struct ReturnRoundMatch<'info> {
+    /// Anyone can return a match once the campaign is refunding
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [
+            FUNDING_ROUND_SEED,
+            funding_round.admin.as_ref(),
+            &funding_round.id.to_le_bytes(),
+        ],
+        bump = funding_round.bump
+    )]
+    pub funding_round: Account<'info, FundingRound>,
+
+    /// CHECK: Funded the round's pot, so receives the returned match.
+    #[account(mut, address = funding_round.admin @ RipplError::InvalidAuthority)]
+    pub admin: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [ROUND_PARTICIPANT_SEED, funding_round.key().as_ref(), campaign.key().as_ref()],
+        bump = round_participant.bump,
+        constraint = round_participant.match_amount > 0 @ RipplError::NoRoundMatch
+    )]
+    pub round_participant: Account<'info, RoundParticipant>,
+
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: This is a PDA owned by the program that serves as a vault for campaign donations.
+    /// The seeds ensure it is the correct vault for this campaign.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ReturnRoundMatch>) -> Result<()> {
+    let clock = Clock::get()?;
+    let campaign = &ctx.accounts.campaign;
+    let match_amount = ctx.accounts.round_participant.match_amount;
+
+    // Like a donation, the match of a cancelled campaign gets its pro-rata share
+    // of what is left in the vault; all-or-nothing campaigns that missed their
+    // goal never paid anything out, so it goes back in full
+    let amount = if campaign.status == CampaignStatus::Cancelled {
+        campaign
+            .cancellation_refund(match_amount)
+            .ok_or(error!(RipplError::InsufficientFunds))?
+    } else {
+        require!(campaign.refunds_open(clock.unix_timestamp), RipplError::RoundMatchNotReturnable);
+        match_amount
+    };
+
+    // Rounds only accept SOL campaigns, so the match sits on the vault itself
+    pay_from_vault(
+        campaign,
+        &ctx.accounts.campaign_vault,
+        ctx.bumps.campaign_vault,
+        &ctx.accounts.admin.to_account_info(),
+        None,
+        &ctx.accounts.system_program,
+        &[],
+        amount
+    )?;
+
+    // Take the match back out of the campaign totals
+    let campaign = &mut ctx.accounts.campaign;
+    if campaign.status == CampaignStatus::Cancelled {
+        campaign.refund_pool = campaign.refund_pool
+            .checked_sub(amount)
+            .ok_or(error!(RipplError::InsufficientFunds))?;
+    }
+    campaign.raised_amount = campaign.raised_amount
+        .checked_sub(match_amount)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+    campaign.raised_usd = campaign.raised_usd.saturating_sub(
+        ctx.accounts.round_participant.match_usd_value.unwrap_or(0)
+    );
+
+    ctx.accounts.round_participant.match_usd_value = None;
+    ctx.accounts.round_participant.match_amount = 0;
+
+    emit_cpi!(RoundMatchReturned {
+        funding_round: ctx.accounts.funding_round.key(),
+        campaign: ctx.accounts.campaign.key(),
+        admin: ctx.accounts.admin.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+// File: src/instructions/reveal_donation.rs
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::constants::DONOR_POSITION_SEED;
+use crate::constants::USER_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::instructions::donate::{ add_supported_campaign, track_donor };
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct RevealDonation<'info> {
+    #[account(mut)]
+    pub donor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, donor.key().as_ref()],
+        bump = user.bump
+    )]
+    pub user: Account<'info, User>,
+
+    #[account(
+        mut,
+        constraint = donation.donor_commitment.is_some() @ RipplError::InvalidCommitment,
+        has_one = campaign @ RipplError::InvalidCommitment
+    )]
+    pub donation: Account<'info, Donation>,
+
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        init_if_needed,
+        payer = donor,
+        space = 8 + DonorPosition::INIT_SPACE,
+        seeds = [DONOR_POSITION_SEED, campaign.key().as_ref(), donor.key().as_ref()],
+        bump
+    )]
+    pub donor_position: Account<'info, DonorPosition>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Publicly attaches an anonymous donation to its donor, after which it can be
+/// refunded, voted with and tallied like any other. Donors who only need to
+/// prove a gift to someone, such as for a tax receipt, can share the salt
+/// privately instead.
+pub fn handler(ctx: Context<RevealDonation>, salt: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let donor = ctx.accounts.donor.key();
+    let donation = &mut ctx.accounts.donation;
+
+    require!(
+        donation.donor_commitment == Some(donation_commitment(&donor, &salt)),
+        RipplError::InvalidCommitment
+    );
+
+    donation.donor = donor;
+    donation.donor_commitment = None;
+
+    // The anonymous gift was counted as a donor of its own; fold it into the
+    // donor's position so they aren't counted twice
+    let new_donor = track_donor(
+        &mut ctx.accounts.donor_position,
+        donation.campaign,
+        donor,
+        ctx.bumps.donor_position,
+        donation.amount,
+        donation.timestamp
+    )?;
+    if new_donor {
+        // The gift now shows on the donor's profile like any other
+        add_supported_campaign(&mut ctx.accounts.user)?;
+    } else {
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.donors_count = campaign.donors_count.saturating_sub(1);
+    }
+    // Revealed gifts can vote on milestones like any other
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.voteable_amount = campaign.voteable_amount
+        .checked_add(donation.amount)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+
+    emit_cpi!(DonationRevealed {
+        donation: donation.key(),
+        campaign: donation.campaign,
+        donor,
+        amount: donation.amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Commitment stored in place of the donor on an anonymous donation
+fn donation_commitment(donor: &Pubkey, salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[donor.as_ref(), salt]).to_bytes()
+}
@@ -0,0 +1,56 @@
+// File: src/instructions/revoke_campaign_role.rs
+use anchor_lang::prelude::*;
+use crate::constants::CAMPAIGN_ROLE_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct RevokeCampaignRole<'info> {
+    /// The campaign's authority, or one of its admins. Receives the role
+    /// account's rent.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = campaign.authorizes(&authority.key(), authority_role.as_deref(), CampaignRoleKind::Admin) @ RipplError::InvalidAuthority
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// The signer's own role, when they aren't the campaign's authority
+    #[account(
+        seeds = [CAMPAIGN_ROLE_SEED, campaign.key().as_ref(), authority.key().as_ref()],
+        bump = authority_role.bump
+    )]
+    pub authority_role: Option<Account<'info, CampaignRole>>,
+
+    #[account(
+        mut,
+        close = authority,
+        has_one = campaign @ RipplError::InvalidAuthority
+    )]
+    pub campaign_role: Account<'info, CampaignRole>,
+}
+
+pub fn handler(ctx: Context<RevokeCampaignRole>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let authority = ctx.accounts.authority.key();
+    let campaign_role = &ctx.accounts.campaign_role;
+
+    // Only the authority can remove admins
+    require!(
+        campaign_role.role != CampaignRoleKind::Admin || authority == campaign.authority,
+        RipplError::InvalidAuthority
+    );
+
+    emit_cpi!(CampaignRoleRevoked {
+        campaign: campaign.key(),
+        member: campaign_role.member,
+        role: campaign_role.role,
+        revoked_by: authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
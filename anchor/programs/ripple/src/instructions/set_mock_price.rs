@@ -0,0 +1,42 @@
+// File: src/instructions/set_mock_price.rs
+use anchor_lang::prelude::*;
+use crate::constants::MOCK_PRICE_FEED_SEED;
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetMockPrice<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MockPriceFeed::INIT_SPACE,
+        seeds = [MOCK_PRICE_FEED_SEED, authority.key().as_ref()],
+        bump
+    )]
+    pub price_feed: Account<'info, MockPriceFeed>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetMockPrice>,
+    price: i64,
+    confidence: u64,
+    exponent: i32,
+    publish_time: i64
+) -> Result<()> {
+    require!(cfg!(feature = "mock-oracle"), RipplError::MockOracleDisabled);
+
+    let price_feed = &mut ctx.accounts.price_feed;
+    price_feed.authority = ctx.accounts.authority.key();
+    price_feed.price = price;
+    price_feed.confidence = confidence;
+    price_feed.exponent = exponent;
+    price_feed.publish_time = publish_time;
+    price_feed.bump = ctx.bumps.price_feed;
+
+    Ok(())
+}
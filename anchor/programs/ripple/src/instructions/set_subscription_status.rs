@@ -0,0 +1,40 @@
+// File: src/instructions/set_subscription_status.rs
+use anchor_lang::prelude::*;
+use crate::constants::SUBSCRIPTION_SEED;
+use crate::state::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct SetSubscriptionStatus<'info> {
+    pub donor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_SEED, donor.key().as_ref(), &subscription.id.to_le_bytes()],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+}
+
+pub fn handler(ctx: Context<SetSubscriptionStatus>, status: SubscriptionStatus) -> Result<()> {
+    let clock = Clock::get()?;
+    let subscription = &mut ctx.accounts.subscription;
+
+    // Donations missed while paused are skipped rather than executed in a burst on resume
+    if status == SubscriptionStatus::Active && subscription.status == SubscriptionStatus::Paused {
+        subscription.next_due = subscription.next_due.max(clock.unix_timestamp);
+    }
+    subscription.status = status;
+
+    emit_cpi!(SubscriptionUpdated {
+        subscription: subscription.key(),
+        donor: subscription.donor,
+        status: subscription.status.clone(),
+        escrow_balance: subscription.escrow_balance,
+        next_due: subscription.next_due,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+// File: src/instructions/tally_round_contribution.rs
+use anchor_lang::prelude::*;
+use crate::constants::FUNDING_ROUND_SEED;
+use crate::constants::ROUND_CONTRIBUTION_SEED;
+use crate::constants::ROUND_PARTICIPANT_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[event_cpi]
+pub struct TallyRoundContribution<'info> {
+    /// Anyone can tally a donation into a round; they pay for the contribution record
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            FUNDING_ROUND_SEED,
+            funding_round.admin.as_ref(),
+            &funding_round.id.to_le_bytes(),
+        ],
+        bump = funding_round.bump,
+        constraint = !funding_round.finalized @ RipplError::RoundFinalized
+    )]
+    pub funding_round: Account<'info, FundingRound>,
+
+    #[account(
+        mut,
+        seeds = [
+            ROUND_PARTICIPANT_SEED,
+            funding_round.key().as_ref(),
+            round_participant.campaign.as_ref(),
+        ],
+        bump = round_participant.bump,
+        constraint = !round_participant.removed @ RipplError::RoundCampaignRemoved
+    )]
+    pub round_participant: Account<'info, RoundParticipant>,
+
+    #[account(
+        mut,
+        constraint = donation.campaign == round_participant.campaign @ RipplError::InvalidRoundParticipants,
+        constraint = donation.status == DonationStatus::Completed @ RipplError::DonationNotCompleted,
+        constraint = donation.donor_commitment.is_none() @ RipplError::AnonymousDonation,
+        constraint = donation.funding_round.is_none() @ RipplError::DonationAlreadyTallied
+    )]
+    pub donation: Account<'info, Donation>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + RoundContribution::INIT_SPACE,
+        seeds = [
+            ROUND_CONTRIBUTION_SEED,
+            funding_round.key().as_ref(),
+            round_participant.campaign.as_ref(),
+            donation.donor.as_ref(),
+        ],
+        bump
+    )]
+    pub round_contribution: Account<'info, RoundContribution>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<TallyRoundContribution>) -> Result<()> {
+    let clock = Clock::get()?;
+    let funding_round = &mut ctx.accounts.funding_round;
+    let round_participant = &mut ctx.accounts.round_participant;
+    let donation = &mut ctx.accounts.donation;
+    let round_contribution = &mut ctx.accounts.round_contribution;
+
+    // Only donations made during the round count towards it
+    require!(funding_round.is_open(donation.timestamp), RipplError::RoundNotOpen);
+
+    if round_contribution.donor == Pubkey::default() {
+        round_contribution.round = funding_round.key();
+        round_contribution.campaign = round_participant.campaign;
+        round_contribution.donor = donation.donor;
+        round_contribution.bump = ctx.bumps.round_contribution;
+    }
+
+    // Cap what a single donor can count towards the campaign's score
+    let old_amount = round_contribution.amount;
+    let new_amount = old_amount
+        .saturating_add(donation.amount)
+        .min(funding_round.max_contribution_per_donor);
+
+    let old_score = round_participant.score().ok_or(error!(RipplError::InvalidFundingRound))?;
+    round_participant.sum_sqrt = round_participant.sum_sqrt
+        .checked_sub(isqrt(old_amount) as u128)
+        .and_then(|sum| sum.checked_add(isqrt(new_amount) as u128))
+        .ok_or(error!(RipplError::InvalidFundingRound))?;
+    round_participant.contributions = round_participant.contributions
+        .checked_add(new_amount - old_amount)
+        .ok_or(error!(RipplError::InvalidFundingRound))?;
+    if old_amount == 0 && new_amount > 0 {
+        round_participant.contributors = round_participant.contributors
+            .checked_add(1)
+            .ok_or(error!(RipplError::InvalidFundingRound))?;
+    }
+    let new_score = round_participant.score().ok_or(error!(RipplError::InvalidFundingRound))?;
+
+    funding_round.total_score = funding_round.total_score
+        .checked_sub(old_score)
+        .and_then(|total| total.checked_add(new_score))
+        .ok_or(error!(RipplError::InvalidFundingRound))?;
+
+    round_contribution.amount = new_amount;
+    donation.funding_round = Some(funding_round.key());
+
+    emit_cpi!(RoundContributionTallied {
+        funding_round: funding_round.key(),
+        campaign: round_participant.campaign,
+        donor: donation.donor,
+        donation: donation.key(),
+        counted_amount: new_amount,
+        score: new_score,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
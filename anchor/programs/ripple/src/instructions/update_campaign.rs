@@ -1,48 +1,101 @@
 // File: src/instructions/update_campaign.rs
 use anchor_lang::prelude::*;
-use crate::constants::CAMPAIGN_SEED;
+use crate::constants::CAMPAIGN_ROLE_SEED;
 use crate::constants::MAX_CAMPAIGN_DURATION;
 use crate::constants::MAX_DESCRIPTION_LENGTH;
 use crate::constants::MAX_IMAGE_URL_LENGTH;
+use crate::constants::MAX_TITLE_LENGTH;
+use crate::constants::MIN_CAMPAIGN_DURATION;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
+use crate::instructions::create_campaign::{ validate_donation_limits, validate_start_date };
+use crate::utils::release_expiry_bounty;
 
 #[derive(Accounts)]
+#[event_cpi]
 pub struct UpdateCampaign<'info> {
+    /// The campaign's authority, or a member with a role covering the update
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// The signer's role on the campaign, when they aren't its authority
     #[account(
-        mut,
-        seeds = [
-            CAMPAIGN_SEED, 
-            campaign.title.as_bytes(), 
-            authority.key().as_ref()
-        ],
-        bump = campaign.bump,
-        constraint = campaign.authority == authority.key() @ RipplError::InvalidAuthority
+        seeds = [CAMPAIGN_ROLE_SEED, campaign.key().as_ref(), authority.key().as_ref()],
+        bump = campaign_role.bump
     )]
-    pub campaign: Account<'info, Campaign>,
+    pub campaign_role: Option<Account<'info, CampaignRole>>,
+
+    /// Receives the expiry bounty when a member closes the campaign
+    #[account(mut, address = campaign.authority @ RipplError::InvalidAuthority)]
+    pub campaign_authority: Option<SystemAccount<'info>>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateCampaignParams {
+    pub title: Option<String>,
     pub description: Option<String>,
     pub image_url: Option<String>,
+    /// Only while the campaign is still scheduled
+    pub start_date: Option<i64>,
     pub end_date: Option<i64>,
     pub status: Option<CampaignStatus>,
     pub is_urgent: Option<bool>,
+    /// Replaces both limits; the hard cap can't drop below what has been raised
+    pub donation_limits: Option<DonationLimits>,
 }
 
 pub fn handler(ctx: Context<UpdateCampaign>, params: UpdateCampaignParams) -> Result<()> {
-    let campaign = &mut ctx.accounts.campaign;
     let clock = Clock::get()?;
 
+    // Members need a role to update the campaign at all. Editors change its
+    // details, and status managers its status.
+    let authority = ctx.accounts.authority.key();
+    let campaign_role = ctx.accounts.campaign_role.as_deref();
+    require!(
+        authority == ctx.accounts.campaign.authority || campaign_role.is_some(),
+        RipplError::InvalidAuthority
+    );
+    let edits_details = params.title.is_some() ||
+        params.description.is_some() ||
+        params.image_url.is_some() ||
+        params.start_date.is_some() ||
+        params.end_date.is_some() ||
+        params.is_urgent.is_some() ||
+        params.donation_limits.is_some();
+    require!(
+        !edits_details ||
+            ctx.accounts.campaign.authorizes(&authority, campaign_role, CampaignRoleKind::Editor),
+        RipplError::InvalidAuthority
+    );
+    require!(
+        params.status.is_none() ||
+            ctx.accounts.campaign.authorizes(&authority, campaign_role, CampaignRoleKind::StatusManager),
+        RipplError::InvalidAuthority
+    );
+
+    let campaign = &mut ctx.accounts.campaign;
+
+    // Cancelled campaigns are frozen
+    require!(campaign.status != CampaignStatus::Cancelled, RipplError::CampaignCancelled);
+
     // Validate campaign is not expired
     require!(campaign.end_date > clock.unix_timestamp, RipplError::CampaignEnded);
 
+    // A scheduled campaign whose start date has passed is open already
+    let mut status_changed = campaign.activate_if_started(clock.unix_timestamp);
+
+    // Update title if provided
+    if let Some(title) = params.title {
+        require!(title.len() <= MAX_TITLE_LENGTH, RipplError::TitleTooLong);
+        campaign.title = title;
+    }
+
     // Update description if provided
     if let Some(description) = params.description {
         require!(description.len() <= MAX_DESCRIPTION_LENGTH, RipplError::DescriptionTooLong);
@@ -55,6 +108,17 @@ pub fn handler(ctx: Context<UpdateCampaign>, params: UpdateCampaignParams) -> Re
         campaign.image_url = image_url;
     }
 
+    // Scheduled campaigns can be moved to a new start date until they open
+    if let Some(new_start_date) = params.start_date {
+        require!(campaign.status == CampaignStatus::Scheduled, RipplError::CampaignAlreadyStarted);
+        validate_start_date(new_start_date, clock.unix_timestamp)?;
+        require!(
+            campaign.milestones.iter().all(|milestone| milestone.deadline > new_start_date),
+            RipplError::InvalidMilestone
+        );
+        campaign.start_date = new_start_date;
+    }
+
     // Update end date if provided
     if let Some(new_end_date) = params.end_date {
         require!(new_end_date > clock.unix_timestamp, RipplError::CampaignDurationTooShort);
@@ -65,7 +129,20 @@ pub fn handler(ctx: Context<UpdateCampaign>, params: UpdateCampaignParams) -> Re
         campaign.end_date = new_end_date;
     }
 
+    if params.start_date.is_some() {
+        require!(
+            campaign.end_date - campaign.start_date >= MIN_CAMPAIGN_DURATION,
+            RipplError::CampaignDurationTooShort
+        );
+        require!(
+            campaign.end_date - campaign.start_date <= MAX_CAMPAIGN_DURATION,
+            RipplError::CampaignDurationTooLong
+        );
+        status_changed |= campaign.activate_if_started(clock.unix_timestamp);
+    }
+
     // Update status if provided
+    status_changed |= params.status.is_some();
     if let Some(new_status) = params.status {
         // Validate status transition
         match (&campaign.status, &new_status) {
@@ -80,6 +157,18 @@ pub fn handler(ctx: Context<UpdateCampaign>, params: UpdateCampaignParams) -> Re
                 return Err(error!(RipplError::InvalidStatusTransition));
             }
         }
+
+        // Closing the campaign by hand returns the expiry bounty to its creator
+        if campaign.is_closed() {
+            let bounty_recipient = match &ctx.accounts.campaign_authority {
+                Some(campaign_authority) => campaign_authority.to_account_info(),
+                None => {
+                    require!(authority == campaign.authority, RipplError::InvalidAuthority);
+                    ctx.accounts.authority.to_account_info()
+                }
+            };
+            release_expiry_bounty(campaign, &bounty_recipient)?;
+        }
     }
 
     // Update urgency if provided
@@ -87,5 +176,26 @@ pub fn handler(ctx: Context<UpdateCampaign>, params: UpdateCampaignParams) -> Re
         campaign.is_urgent = is_urgent;
     }
 
+    // Limits apply to donations from here on; donors already over a lowered
+    // per-donor limit just can't give more
+    if let Some(donation_limits) = params.donation_limits {
+        let target_amount = campaign.usd_target.is_none().then_some(campaign.target_amount);
+        validate_donation_limits(&donation_limits, target_amount, campaign.raised_amount)?;
+        campaign.donation_limits = donation_limits;
+    }
+
+    let campaign = &ctx.accounts.campaign;
+    emit_cpi!(CampaignUpdated {
+        campaign: campaign.key(),
+        authority: campaign.authority,
+        title: campaign.title.clone(),
+        new_status: status_changed.then(|| campaign.status.clone()),
+        start_date: campaign.start_date,
+        end_date: campaign.end_date,
+        is_urgent: campaign.is_urgent,
+        donation_limits: campaign.donation_limits.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -0,0 +1,112 @@
+// File: src/instructions/vote_milestone.rs
+use anchor_lang::prelude::*;
+use crate::constants::MILESTONE_VOTE_SEED;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+#[event_cpi]
+pub struct VoteMilestone<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// One vote per donor per milestone
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + MilestoneVote::INIT_SPACE,
+        seeds = [
+            MILESTONE_VOTE_SEED,
+            campaign.key().as_ref(),
+            &[index],
+            voter.key().as_ref(),
+        ],
+        bump
+    )]
+    pub vote: Account<'info, MilestoneVote>,
+
+    pub system_program: Program<'info, System>,
+    // The voter's `Donation` accounts for this campaign are passed as remaining
+    // accounts; their amounts add up to the vote's weight.
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VoteMilestone<'info>>,
+    index: u8,
+    approve: bool
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let campaign_key = ctx.accounts.campaign.key();
+    let voter_key = ctx.accounts.voter.key();
+
+    let weight = donation_weight(ctx.remaining_accounts, &campaign_key, &voter_key)?;
+
+    let milestone = ctx.accounts.campaign.milestones
+        .get_mut(index as usize)
+        .ok_or(error!(RipplError::MilestoneNotFound))?;
+    require!(milestone.status == MilestoneStatus::Voting, RipplError::MilestoneNotVoting);
+    require!(
+        milestone.voting_ends_at.is_some_and(|ends_at| clock.unix_timestamp < ends_at),
+        RipplError::VotingClosed
+    );
+
+    // Tally the vote on the milestone
+    let tally = if approve { &mut milestone.approve_weight } else { &mut milestone.reject_weight };
+    *tally = tally.checked_add(weight).ok_or(error!(RipplError::InvalidVoteWeight))?;
+    let (approve_weight, reject_weight) = (milestone.approve_weight, milestone.reject_weight);
+
+    let vote = &mut ctx.accounts.vote;
+    vote.campaign = campaign_key;
+    vote.milestone_index = index;
+    vote.voter = voter_key;
+    vote.approve = approve;
+    vote.weight = weight;
+    vote.timestamp = clock.unix_timestamp;
+    vote.bump = ctx.bumps.vote;
+
+    emit_cpi!(MilestoneVoteCast {
+        campaign: campaign_key,
+        index,
+        voter: voter_key,
+        approve,
+        weight,
+        approve_weight,
+        reject_weight,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Sums the amounts of the voter's completed donations to the campaign. Each
+/// donation account may only be passed once.
+fn donation_weight<'info>(
+    donation_accounts: &'info [AccountInfo<'info>],
+    campaign: &Pubkey,
+    voter: &Pubkey
+) -> Result<u64> {
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(donation_accounts.len());
+    let mut weight: u64 = 0;
+
+    for account_info in donation_accounts {
+        require!(!seen.contains(account_info.key), RipplError::InvalidVoteWeight);
+        seen.push(account_info.key());
+
+        let donation = Account::<Donation>::try_from(account_info)?;
+        require!(
+            donation.campaign == *campaign &&
+                donation.donor == *voter &&
+                donation.status == DonationStatus::Completed,
+            RipplError::InvalidVoteWeight
+        );
+        weight = weight.checked_add(donation.amount).ok_or(error!(RipplError::InvalidVoteWeight))?;
+    }
+
+    require!(weight > 0, RipplError::InvalidVoteWeight);
+    Ok(weight)
+}
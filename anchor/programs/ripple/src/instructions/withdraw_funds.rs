@@ -18,7 +18,7 @@
 //         ],
 //         bump = campaign.bump,
 //         constraint = campaign.authority == authority.key() @ RipplError::InvalidAuthority,
-//         constraint = campaign.status == CampaignStatus::Completed @ RipplError::CampaignNotActive
+//         constraint = matches!(campaign.status, CampaignStatus::Completed | CampaignStatus::Expired) @ RipplError::CampaignNotActive
 //     )]
 //     pub campaign: Account<'info, Campaign>,
 
@@ -55,83 +55,183 @@
 
 // File: src/instructions/withdraw_funds.rs
 use anchor_lang::prelude::*;
-use crate::constants::CAMPAIGN_SEED;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+use crate::constants::CAMPAIGN_ROLE_SEED;
+use crate::constants::MAX_WITHDRAWAL_PURPOSE_LENGTH;
+use crate::constants::VAULT_SEED;
+use crate::constants::WITHDRAWAL_SEED;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
+use crate::utils::{ pay_from_vault, VaultTokenAccounts };
 
 #[derive(Accounts)]
+#[instruction(amount: u64, purpose: String, receipt_hash: Option<[u8; 32]>)]
+#[event_cpi]
 pub struct WithdrawFunds<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
         mut,
+        constraint = campaign.authorizes(&authority.key(), campaign_role.as_deref(), CampaignRoleKind::Treasurer) @ RipplError::InvalidAuthority,
+        constraint = matches!(campaign.status, CampaignStatus::Completed | CampaignStatus::Expired) @ RipplError::CampaignNotActive,
+        constraint = campaign.approvals.is_none() @ RipplError::ApprovalRequired
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// The signer's role on the campaign, when they aren't its authority
+    #[account(
+        seeds = [CAMPAIGN_ROLE_SEED, campaign.key().as_ref(), authority.key().as_ref()],
+        bump = campaign_role.bump
+    )]
+    pub campaign_role: Option<Account<'info, CampaignRole>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Withdrawal::INIT_SPACE,
         seeds = [
-            CAMPAIGN_SEED, 
-            campaign.title.as_bytes(), 
-            authority.key().as_ref()
+            WITHDRAWAL_SEED,
+            campaign.key().as_ref(),
+            &campaign.withdrawal_count.to_le_bytes(),
         ],
-        bump = campaign.bump,
-        constraint = campaign.authority == authority.key() @ RipplError::InvalidAuthority,
-        constraint = campaign.status == CampaignStatus::Completed @ RipplError::CampaignNotActive
+        bump
     )]
-    pub campaign: Account<'info, Campaign>,
+    pub withdrawal: Account<'info, Withdrawal>,
 
     /// CHECK: This is a PDA owned by the program that serves as a vault for campaign donations.
     /// The seeds and constraints ensure it is the correct vault for this campaign, and only the
-    /// campaign authority can withdraw from it when the campaign is completed.
+    /// campaign authority can withdraw from it once the campaign is completed or expired.
     #[account(
         mut,
-        seeds = [
-            CAMPAIGN_SEED,
-            campaign.title.as_bytes(),
-            campaign.authority.as_ref(),
-            b"vault"
-        ],
+        seeds = [VAULT_SEED, campaign.key().as_ref()],
         bump,
     )]
     pub campaign_vault: SystemAccount<'info>,
 
     /// CHECK: This is the recipient account that will receive the withdrawn funds.
-    /// It can be any valid system account as specified by the campaign authority.
-    #[account(mut)]
+    /// It must be one of the beneficiaries the campaign declared.
+    #[account(
+        mut,
+        constraint = campaign.beneficiaries.contains(&recipient.key()) @ RipplError::RecipientNotBeneficiary
+    )]
     pub recipient: AccountInfo<'info>,
 
+    #[account(
+        constraint = Some(mint.key()) == campaign.accepted_mint @ RipplError::InvalidMint
+    )]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = campaign_vault,
+        associated_token::token_program = token_program
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token account of `recipient` that receives the withdrawn tokens
+    #[account(
+        mut,
+        constraint = Some(recipient_token_account.mint) == campaign.accepted_mint @ RipplError::InvalidMint,
+        constraint = recipient_token_account.owner == recipient.key() @ RipplError::InvalidTokenAccount
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
     pub system_program: Program<'info, System>,
+    // Any extra accounts required by the mint's transfer hook are passed as
+    // remaining accounts.
+}
+
+/// Checks `amount` can leave the campaign's vault and returns the campaign's
+/// total withdrawn once it has
+pub(crate) fn validate_withdrawal(campaign: &Campaign, amount: u64) -> Result<u64> {
+    // All-or-nothing campaigns only release funds once the goal is met, so an
+    // expired one is left to its donors' refunds
+    if campaign.funding_mode == FundingMode::AllOrNothing {
+        require!(campaign.goal_reached(), RipplError::FundingGoalNotReached);
+    }
+
+    // Campaigns with milestones only release what their completed milestones unlock
+    let withdrawn_amount = campaign.withdrawn_amount
+        .checked_add(amount)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+    if let Some(released_amount) = campaign.released_amount() {
+        require!(withdrawn_amount <= released_amount, RipplError::MilestoneNotReached);
+    }
+
+    Ok(withdrawn_amount)
 }
-pub fn handler(ctx: Context<WithdrawFunds>, amount: u64) -> Result<()> {
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawFunds<'info>>,
+    amount: u64,
+    purpose: String,
+    receipt_hash: Option<[u8; 32]>
+) -> Result<()> {
+    let clock = Clock::get()?;
     let campaign = &ctx.accounts.campaign;
-    let vault_balance = ctx.accounts.campaign_vault.lamports();
-
-    // Ensure amount is available
-    require!(vault_balance >= amount, RipplError::InsufficientFunds);
-
-    // Get campaign vault bump
-    let vault_bump = ctx.bumps.campaign_vault;
-
-    // Construct seeds for signing
-    let vault_seeds = [
-        CAMPAIGN_SEED,
-        campaign.title.as_bytes(),
-        campaign.authority.as_ref(),
-        b"vault",
-        &[vault_bump],
-    ];
-
-    // Transfer funds from vault to recipient using invoke_signed
-    anchor_lang::solana_program::program::invoke_signed(
-        &anchor_lang::solana_program::system_instruction::transfer(
-            &ctx.accounts.campaign_vault.key(),
-            &ctx.accounts.recipient.key(),
-            amount
-        ),
-        &[
-            ctx.accounts.campaign_vault.to_account_info(),
-            ctx.accounts.recipient.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-        &[&vault_seeds[..]]
+    require!(purpose.len() <= MAX_WITHDRAWAL_PURPOSE_LENGTH, RipplError::PurposeTooLong);
+    let withdrawn_amount = validate_withdrawal(campaign, amount)?;
+
+    let token_accounts = match campaign.accepted_mint {
+        Some(_) =>
+            Some(
+                VaultTokenAccounts::try_from_options(
+                    &ctx.accounts.mint,
+                    &ctx.accounts.vault_token_account,
+                    &ctx.accounts.recipient_token_account,
+                    &ctx.accounts.token_program
+                )?
+            ),
+        None => None,
+    };
+
+    // Transfer funds from the vault to the recipient, signed by the vault PDA
+    pay_from_vault(
+        campaign,
+        &ctx.accounts.campaign_vault,
+        ctx.bumps.campaign_vault,
+        &ctx.accounts.recipient,
+        token_accounts.as_ref(),
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        amount
     )?;
 
+    // Record the withdrawal in the campaign's ledger
+    let withdrawal = &mut ctx.accounts.withdrawal;
+    withdrawal.campaign = ctx.accounts.campaign.key();
+    withdrawal.sequence = ctx.accounts.campaign.withdrawal_count;
+    withdrawal.authority = ctx.accounts.authority.key();
+    withdrawal.recipient = ctx.accounts.recipient.key();
+    withdrawal.mint = ctx.accounts.campaign.accepted_mint;
+    withdrawal.amount = amount;
+    withdrawal.timestamp = clock.unix_timestamp;
+    withdrawal.purpose = purpose;
+    withdrawal.receipt_hash = receipt_hash;
+    withdrawal.bump = ctx.bumps.withdrawal;
+
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.withdrawn_amount = withdrawn_amount;
+    campaign.withdrawal_count = campaign.withdrawal_count
+        .checked_add(1)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+
+    let withdrawal = &ctx.accounts.withdrawal;
+    emit_cpi!(FundsWithdrawn {
+        campaign: withdrawal.campaign,
+        withdrawal: withdrawal.key(),
+        sequence: withdrawal.sequence,
+        recipient: withdrawal.recipient,
+        mint: withdrawal.mint,
+        amount,
+        withdrawn_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -3,8 +3,10 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use instructions::*;
 
@@ -20,37 +22,25 @@ pub mod ripple {
 
     pub fn create_campaign(
         ctx: Context<CreateCampaign>,
-        title: String,
-        description: String,
-        category: state::CampaignCategory,
-        organization_name: String,
-        target_amount: u64,
-        start_date: i64,
-        end_date: i64,
-        image_url: String,
-        is_urgent: bool
-    ) -> Result<()> {
-        instructions::create_campaign::handler(
-            ctx,
-            title,
-            description,
-            category,
-            organization_name,
-            target_amount,
-            start_date,
-            end_date,
-            image_url,
-            is_urgent
-        )
+        params: instructions::create_campaign::CreateCampaignParams
+    ) -> Result<()> {
+        instructions::create_campaign::handler(ctx, params)
     }
 
-    pub fn donate(
-        ctx: Context<Donate>,
+    pub fn donate<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Donate<'info>>,
         amount: u64,
         payment_method: state::PaymentMethod,
-        count_in_string: String,
+        tribute: Option<instructions::donate::TributeParams>,
+        donor_commitment: Option<[u8; 32]>
     ) -> Result<()> {
-        instructions::donate::handler(ctx, amount, payment_method, count_in_string)
+        instructions::donate::handler(
+            ctx,
+            amount,
+            payment_method,
+            tribute,
+            donor_commitment
+        )
     }
 
     pub fn update_campaign(
@@ -60,47 +50,204 @@ pub mod ripple {
         instructions::update_campaign::handler(ctx, params)
     }
 
-    pub fn withdraw_funds(ctx: Context<WithdrawFunds>, amount: u64) -> Result<()> {
-        instructions::withdraw_funds::handler(ctx, amount)
+    pub fn withdraw_funds<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawFunds<'info>>,
+        amount: u64,
+        purpose: String,
+        receipt_hash: Option<[u8; 32]>
+    ) -> Result<()> {
+        instructions::withdraw_funds::handler(ctx, amount, purpose, receipt_hash)
     }
-}
 
-// Event definitions
-#[event]
-pub struct CampaignCreated {
-    pub campaign: Pubkey,
-    pub authority: Pubkey,
-    pub title: String,
-    pub category: state::CampaignCategory,
-    pub target_amount: u64,
-}
+    pub fn complete_milestone(
+        ctx: Context<CompleteMilestone>,
+        index: u8,
+        evidence_uri: String,
+        evidence_hash: [u8; 32]
+    ) -> Result<()> {
+        instructions::complete_milestone::handler(ctx, index, evidence_uri, evidence_hash)
+    }
 
-#[event]
-pub struct DonationReceived {
-    pub donation: Pubkey,
-    pub campaign: Pubkey,
-    pub donor: Pubkey,
-    pub amount: u64,
-    pub payment_method: state::PaymentMethod,
-}
+    pub fn vote_milestone<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VoteMilestone<'info>>,
+        index: u8,
+        approve: bool
+    ) -> Result<()> {
+        instructions::vote_milestone::handler(ctx, index, approve)
+    }
 
-#[event]
-pub struct CampaignUpdated {
-    pub campaign: Pubkey,
-    pub authority: Pubkey,
-    pub new_status: Option<state::CampaignStatus>,
-}
+    pub fn finalize_milestone_vote(ctx: Context<FinalizeMilestoneVote>, index: u8) -> Result<()> {
+        instructions::finalize_milestone_vote::handler(ctx, index)
+    }
 
-#[event]
-pub struct FundsWithdrawn {
-    pub campaign: Pubkey,
-    pub recipient: Pubkey,
-    pub amount: u64,
-}
+    pub fn expire_campaign(ctx: Context<ExpireCampaign>) -> Result<()> {
+        instructions::expire_campaign::handler(ctx)
+    }
+
+    pub fn cancel_campaign(ctx: Context<CancelCampaign>) -> Result<()> {
+        instructions::cancel_campaign::handler(ctx)
+    }
+
+    pub fn claim_refund<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimRefund<'info>>
+    ) -> Result<()> {
+        instructions::claim_refund::handler(ctx)
+    }
+
+    pub fn create_subscription(
+        ctx: Context<CreateSubscription>,
+        params: instructions::create_subscription::CreateSubscriptionParams
+    ) -> Result<()> {
+        instructions::create_subscription::handler(ctx, params)
+    }
+
+    pub fn fund_subscription(ctx: Context<FundSubscription>, amount: u64) -> Result<()> {
+        instructions::fund_subscription::handler(ctx, amount)
+    }
+
+    pub fn execute_subscription<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteSubscription<'info>>
+    ) -> Result<()> {
+        instructions::execute_subscription::handler(ctx)
+    }
+
+    pub fn set_subscription_status(
+        ctx: Context<SetSubscriptionStatus>,
+        status: state::SubscriptionStatus
+    ) -> Result<()> {
+        instructions::set_subscription_status::handler(ctx, status)
+    }
+
+    pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
+        instructions::cancel_subscription::handler(ctx)
+    }
+
+    pub fn create_matching_pool<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateMatchingPool<'info>>,
+        params: instructions::create_matching_pool::CreateMatchingPoolParams
+    ) -> Result<()> {
+        instructions::create_matching_pool::handler(ctx, params)
+    }
+
+    pub fn reclaim_matching_pool<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReclaimMatchingPool<'info>>
+    ) -> Result<()> {
+        instructions::reclaim_matching_pool::handler(ctx)
+    }
+
+    pub fn create_funding_round(
+        ctx: Context<CreateFundingRound>,
+        params: instructions::create_funding_round::CreateFundingRoundParams
+    ) -> Result<()> {
+        instructions::create_funding_round::handler(ctx, params)
+    }
+
+    pub fn register_round_campaign(ctx: Context<RegisterRoundCampaign>) -> Result<()> {
+        instructions::register_round_campaign::handler(ctx)
+    }
 
-#[event]
-pub struct BadgeAwarded {
-    pub user: Pubkey,
-    pub badge_type: state::BadgeType,
-    pub timestamp: i64,
+    pub fn remove_round_campaign(ctx: Context<RemoveRoundCampaign>) -> Result<()> {
+        instructions::remove_round_campaign::handler(ctx)
+    }
+
+    pub fn tally_round_contribution(ctx: Context<TallyRoundContribution>) -> Result<()> {
+        instructions::tally_round_contribution::handler(ctx)
+    }
+
+    pub fn finalize_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeRound<'info>>
+    ) -> Result<()> {
+        instructions::finalize_round::handler(ctx)
+    }
+
+    pub fn return_round_match(ctx: Context<ReturnRoundMatch>) -> Result<()> {
+        instructions::return_round_match::handler(ctx)
+    }
+
+    pub fn reveal_donation(ctx: Context<RevealDonation>, salt: [u8; 32]) -> Result<()> {
+        instructions::reveal_donation::handler(ctx, salt)
+    }
+
+    pub fn migrate_campaign(ctx: Context<MigrateCampaign>) -> Result<()> {
+        instructions::migrate_campaign::handler(ctx)
+    }
+
+    pub fn migrate_user(ctx: Context<MigrateUser>) -> Result<()> {
+        instructions::migrate_user::handler(ctx)
+    }
+
+    pub fn pause_campaign(ctx: Context<PauseCampaign>, reason: String) -> Result<()> {
+        instructions::pause_campaign::handler(ctx, reason)
+    }
+
+    pub fn resume_campaign(ctx: Context<ResumeCampaign>, extend_end_date: bool) -> Result<()> {
+        instructions::resume_campaign::handler(ctx, extend_end_date)
+    }
+
+    pub fn propose_campaign_authority(
+        ctx: Context<ProposeCampaignAuthority>,
+        new_authority: Option<Pubkey>
+    ) -> Result<()> {
+        instructions::propose_campaign_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_campaign_authority(ctx: Context<AcceptCampaignAuthority>) -> Result<()> {
+        instructions::accept_campaign_authority::handler(ctx)
+    }
+
+    pub fn grant_campaign_role(
+        ctx: Context<GrantCampaignRole>,
+        member: Pubkey,
+        role: state::CampaignRoleKind
+    ) -> Result<()> {
+        instructions::grant_campaign_role::handler(ctx, member, role)
+    }
+
+    pub fn revoke_campaign_role(ctx: Context<RevokeCampaignRole>) -> Result<()> {
+        instructions::revoke_campaign_role::handler(ctx)
+    }
+
+    pub fn propose_beneficiary_change(
+        ctx: Context<ProposeBeneficiaryChange>,
+        beneficiaries: Option<Vec<Pubkey>>
+    ) -> Result<()> {
+        instructions::propose_beneficiary_change::handler(ctx, beneficiaries)
+    }
+
+    pub fn apply_beneficiary_change(ctx: Context<ApplyBeneficiaryChange>) -> Result<()> {
+        instructions::apply_beneficiary_change::handler(ctx)
+    }
+
+    pub fn propose_withdrawal(
+        ctx: Context<ProposeWithdrawal>,
+        amount: u64,
+        purpose: String,
+        receipt_hash: Option<[u8; 32]>
+    ) -> Result<()> {
+        instructions::propose_withdrawal::handler(ctx, amount, purpose, receipt_hash)
+    }
+
+    pub fn approve_withdrawal(ctx: Context<ApproveWithdrawal>) -> Result<()> {
+        instructions::approve_withdrawal::handler(ctx)
+    }
+
+    pub fn execute_withdrawal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteWithdrawal<'info>>
+    ) -> Result<()> {
+        instructions::execute_withdrawal::handler(ctx)
+    }
+
+    pub fn expire_withdrawal_proposal(ctx: Context<ExpireWithdrawalProposal>) -> Result<()> {
+        instructions::expire_withdrawal_proposal::handler(ctx)
+    }
+
+    pub fn set_mock_price(
+        ctx: Context<SetMockPrice>,
+        price: i64,
+        confidence: u64,
+        exponent: i32,
+        publish_time: i64
+    ) -> Result<()> {
+        instructions::set_mock_price::handler(ctx, price, confidence, exponent, publish_time)
+    }
 }
@@ -1,11 +1,23 @@
 // File: src/state/campaign.rs
 use anchor_lang::prelude::*;
+use crate::state::{
+    ApprovalConfig,
+    CampaignRole,
+    CampaignRoleKind,
+    Milestone,
+    MilestoneStatus,
+    UsdTarget,
+    VotingConfig,
+};
 
 #[account]
 #[derive(Default)]
 #[derive(InitSpace)]
 pub struct Campaign {
     pub authority: Pubkey,
+    /// Index among the authority's campaigns that the campaign's address is derived
+    /// from. Zero for migrated campaigns, which keep their title-derived address.
+    pub id: u64,
     #[max_len(100)]
     pub title: String, // 100 chars max
     #[max_len(1000)]
@@ -15,20 +27,165 @@ pub struct Campaign {
     pub organization_name: String, // 100 chars max
     pub target_amount: u64,
     pub raised_amount: u64,
+    /// Number of distinct donors with an unrefunded donation. Each anonymous
+    /// donation counts as its own donor, since it can't be linked to others.
     pub donors_count: u32,
+    /// Number of donations received, including repeat gifts from the same donor.
+    /// Also the index the next donation is assigned.
+    pub donation_count: u64,
     pub start_date: i64,
     pub end_date: i64,
     pub status: CampaignStatus,
     #[max_len(200)]
     pub image_url: String, // 200 chars max
     pub is_urgent: bool,
+    /// SPL mint donations are accepted in, or `None` for native SOL.
+    /// `target_amount` and `raised_amount` are in this mint's base units.
+    pub accepted_mint: Option<Pubkey>,
+    /// Opts in to mint extensions that let a third party move vault funds,
+    /// such as a Token-2022 permanent delegate.
+    pub allow_unsafe_mint_extensions: bool,
+    /// Fiat target and price feed, for campaigns that raise towards a USD amount
+    pub usd_target: Option<UsdTarget>,
+    /// Sum of each donation's USD value at the time it was made, in micro-USD
+    pub raised_usd: u64,
+    pub funding_mode: FundingMode,
+    /// Vault funds still owed to donors of a cancelled campaign. Set to the vault
+    /// balance on cancellation and paid out pro-rata as refunds are claimed.
+    pub refund_pool: u64,
+    /// Lamports held on the campaign account for whoever calls `expire_campaign`
+    pub expiry_bounty: u64,
+    /// Deliverables that gate withdrawals. Empty for campaigns that release
+    /// everything once completed.
+    #[max_len(5)]
+    pub milestones: Vec<Milestone>,
+    /// Donor sign-off on milestones, or `None` when the authority's report is enough
+    pub voting: Option<VotingConfig>,
+    /// Part of `raised_amount` that donors can vote on milestones with: their own
+    /// gifts, excluding matches and donations that are still anonymous. Milestone
+    /// quorums are measured against it.
+    pub voteable_amount: u64,
+    /// Total paid out to the authority through `withdraw_funds`
+    pub withdrawn_amount: u64,
+    /// Number of `Withdrawal` records, and the sequence number of the next one
+    pub withdrawal_count: u64,
+    /// When the current pause started, while the campaign is paused
+    pub paused_at: Option<i64>,
+    /// Number of times the campaign has been paused
+    pub pause_count: u32,
+    /// Seconds the campaign has spent paused, not counting a pause in progress
+    pub total_paused_duration: i64,
+    /// Wallet offered control of the campaign, until it accepts
+    pub pending_authority: Option<Pubkey>,
+    /// Bumped each time control changes hands. Roles granted before the
+    /// current authority took over no longer authorize anything.
+    pub role_epoch: u32,
+    /// Addresses `withdraw_funds` may pay out to
+    #[max_len(3)]
+    pub beneficiaries: Vec<Pubkey>,
+    /// Replacement beneficiaries waiting out `BENEFICIARY_CHANGE_DELAY`
+    pub pending_beneficiaries: Option<BeneficiaryChange>,
+    /// Signers who approve withdrawals, or `None` when the authority withdraws alone
+    pub approvals: Option<ApprovalConfig>,
+    /// Number of withdrawal proposals made, and the index of the next one
+    pub withdrawal_proposal_count: u64,
+    /// Hard cap and per-donor limit on what the campaign accepts
+    pub donation_limits: DonationLimits,
     pub bump: u8,
 }
 
+impl Campaign {
+    /// Whether `signer` may act as `role` on the campaign. The authority can do
+    /// anything; anyone else needs a `CampaignRole` that allows it, granted
+    /// since the current authority took over.
+    pub fn authorizes(
+        &self,
+        signer: &Pubkey,
+        campaign_role: Option<&CampaignRole>,
+        role: CampaignRoleKind
+    ) -> bool {
+        *signer == self.authority ||
+            campaign_role.is_some_and(|campaign_role| {
+                campaign_role.epoch == self.role_epoch && campaign_role.allows(role)
+            })
+    }
+
+    /// Opens a scheduled campaign once its start date has arrived. Returns
+    /// whether the campaign was activated.
+    pub fn activate_if_started(&mut self, now: i64) -> bool {
+        if self.status == CampaignStatus::Scheduled && now >= self.start_date {
+            self.status = CampaignStatus::Active;
+            return true;
+        }
+        false
+    }
+
+    /// Room left under the hard cap, or `None` when the campaign is uncapped
+    pub fn cap_room(&self) -> Option<u64> {
+        self.donation_limits.hard_cap.map(|hard_cap| hard_cap.saturating_sub(self.raised_amount))
+    }
+
+    /// Whether the campaign has met its target, in USD when it has a USD target
+    pub fn goal_reached(&self) -> bool {
+        match &self.usd_target {
+            Some(usd_target) => self.raised_usd >= usd_target.target_usd,
+            None => self.raised_amount >= self.target_amount,
+        }
+    }
+
+    /// Whether donors can reclaim their donations in full: an all-or-nothing
+    /// campaign that ended without reaching its goal. A paused campaign may
+    /// still be resumed with its end date pushed back, so refunds wait until
+    /// it is resumed or settled.
+    pub fn refunds_open(&self, now: i64) -> bool {
+        self.funding_mode == FundingMode::AllOrNothing &&
+            self.status != CampaignStatus::Paused &&
+            now >= self.end_date &&
+            !self.goal_reached()
+    }
+
+    /// Funds unlocked by the milestones reached so far, or `None` when the
+    /// campaign has no milestones and withdrawals are not capped
+    pub fn released_amount(&self) -> Option<u64> {
+        if self.milestones.is_empty() {
+            return None;
+        }
+        let released = self.milestones
+            .iter()
+            .filter(|milestone| milestone.status == MilestoneStatus::Approved)
+            .fold(0u64, |total, milestone| {
+                total.saturating_add(milestone.release_amount(self.raised_amount))
+            });
+        Some(released)
+    }
+
+    /// Whether the campaign has reached a final state and can no longer change status
+    pub fn is_closed(&self) -> bool {
+        matches!(
+            self.status,
+            CampaignStatus::Completed | CampaignStatus::Expired | CampaignStatus::Cancelled
+        )
+    }
+
+    /// Share of the refund pool owed for a donation of `amount` to a cancelled
+    /// campaign. `raised_amount` is reduced as refunds are claimed, so the last
+    /// claimant receives whatever is left in the pool.
+    pub fn cancellation_refund(&self, amount: u64) -> Option<u64> {
+        if self.raised_amount == 0 {
+            return None;
+        }
+        let share = (amount as u128)
+            .checked_mul(self.refund_pool as u128)?
+            .checked_div(self.raised_amount as u128)?;
+        u64::try_from(share).ok()
+    }
+}
+
 #[derive(InitSpace)]
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
 pub enum CampaignCategory {
+    #[default]
     Healthcare,
     Education,
     FoodSupply,
@@ -37,23 +194,52 @@ pub enum CampaignCategory {
     WaterSanitation,
 }
 
-impl Default for CampaignCategory {
-    fn default() -> Self {
-        CampaignCategory::Healthcare
-    }
-}
 #[derive(InitSpace)]
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
 pub enum CampaignStatus {
+    #[default]
     Active,
     InProgress,
     Completed,
     Expired,
+    Cancelled,
+    /// Created ahead of its `start_date`. Becomes `Active` once the start date
+    /// arrives; until then it can be edited but not donated to.
+    Scheduled,
+    /// Temporarily halted by the authority; donations resume with `resume_campaign`
+    Paused,
 }
 
-impl Default for CampaignStatus {
-    fn default() -> Self {
-        CampaignStatus::Active
-    }
+/// A new set of beneficiaries proposed by the authority. Donors get until
+/// `effective_at` to see the change before withdrawals can go to it.
+#[derive(InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct BeneficiaryChange {
+    #[max_len(3)]
+    pub beneficiaries: Vec<Pubkey>,
+    pub effective_at: i64,
+}
+
+/// Limits on what a campaign accepts, in the campaign's unit. Unlike
+/// `target_amount`, these are enforced on every donation.
+#[derive(InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
+pub struct DonationLimits {
+    /// Most the campaign will raise, matches included. The donation that reaches
+    /// it is cut down to fit, and later ones are rejected.
+    pub hard_cap: Option<u64>,
+    /// Most a single donor can give in total, net of refunds
+    pub max_per_donor: Option<u64>,
+}
+
+#[derive(InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
+pub enum FundingMode {
+    /// The organizer keeps whatever is raised
+    #[default]
+    KeepItAll,
+    /// Funds are only released if the target is reached by `end_date`;
+    /// otherwise donors can claim refunds
+    AllOrNothing,
 }
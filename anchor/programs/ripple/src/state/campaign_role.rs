@@ -0,0 +1,43 @@
+// File: src/state/campaign_role.rs
+use anchor_lang::prelude::*;
+
+/// Scoped permission over a campaign held by someone other than its authority.
+/// Cancelling the campaign, reporting milestones, changing its beneficiaries
+/// and handing it over are left to the authority alone.
+#[account]
+#[derive(Default)]
+#[derive(InitSpace)]
+pub struct CampaignRole {
+    pub campaign: Pubkey,
+    pub member: Pubkey,
+    pub role: CampaignRoleKind,
+    pub granted_by: Pubkey,
+    pub granted_at: i64,
+    /// The campaign's `role_epoch` when the role was granted. The role lapses
+    /// once the campaign changes hands.
+    pub epoch: u32,
+    pub bump: u8,
+}
+
+impl CampaignRole {
+    /// Whether the role lets its member act as `role`. Admins can act as any role.
+    pub fn allows(&self, role: CampaignRoleKind) -> bool {
+        self.role == CampaignRoleKind::Admin || self.role == role
+    }
+}
+
+#[derive(InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CampaignRoleKind {
+    /// Edits the campaign's title, description, image, dates, urgency and
+    /// donation limits
+    #[default]
+    Editor,
+    /// Moves the campaign between statuses with `update_campaign`, and pauses
+    /// or resumes it
+    StatusManager,
+    /// Withdraws funds
+    Treasurer,
+    /// Everything the roles above can do, and grants and revokes them
+    Admin,
+}
@@ -1,13 +1,37 @@
 // File: src/state/donation.rs
 use anchor_lang::prelude::*;
+use crate::constants::MAX_DEDICATION_LENGTH;
+use crate::constants::MAX_IMPACT_DESCRIPTION_LENGTH;
+use crate::constants::MAX_TRANSACTION_HASH_LENGTH;
 
 #[account]
 #[derive(Default)]
 #[derive(InitSpace)]
 pub struct Donation {
+    /// Donor's key, or `Pubkey::default()` while the donation is anonymous
     pub donor: Pubkey,
+    /// `sha256(donor || salt)` for anonymous donations. The donor proves the
+    /// donation is theirs by revealing the salt.
+    pub donor_commitment: Option<[u8; 32]>,
     pub campaign: Pubkey,
+    /// Position of the donation in the campaign's sequence, which its address
+    /// is derived from
+    pub index: u64,
+    /// Mint the donation was made in, or `None` for native SOL.
+    pub mint: Option<Pubkey>,
     pub amount: u64,
+    /// Value of `amount` plus any match in micro-USD when donated, if the campaign has a USD target
+    pub usd_value: Option<u64>,
+    /// Matching pool that topped up the donation, if any
+    pub matching_pool: Option<Pubkey>,
+    /// Amount the matching pool added on top of `amount`
+    pub matched_amount: u64,
+    /// Funding round the donation was tallied in, if any
+    pub funding_round: Option<Pubkey>,
+    /// Person the donation was made in honor of or on behalf of, if any
+    pub honoree: Option<Pubkey>,
+    #[max_len(200)]
+    pub dedication: String, // 200 chars max
     pub timestamp: i64,
     pub status: DonationStatus,
     pub payment_method: PaymentMethod,
@@ -18,30 +42,34 @@ pub struct Donation {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+impl Donation {
+    /// Account space for a donation with a dedication of `dedication_len`
+    /// bytes. The transaction hash and impact description are never set
+    /// on-chain, so no room is kept for them.
+    pub fn space(dedication_len: usize) -> usize {
+        8 + Donation::INIT_SPACE
+            - MAX_DEDICATION_LENGTH
+            - MAX_TRANSACTION_HASH_LENGTH
+            - MAX_IMPACT_DESCRIPTION_LENGTH
+            + dedication_len
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
 #[derive(InitSpace)]
 pub enum DonationStatus {
+    #[default]
     Pending,
     Completed,
     Allocated,
     Spent,
-}
-
-impl Default for DonationStatus {
-    fn default() -> Self {
-        DonationStatus::Pending
-    }
+    Refunded,
 }
 
 #[derive(InitSpace)]
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
 pub enum PaymentMethod {
+    #[default]
     CryptoWallet,
     Card,
 }
-
-impl Default for PaymentMethod {
-    fn default() -> Self {
-        PaymentMethod::CryptoWallet
-    }
-}
@@ -0,0 +1,52 @@
+// File: src/state/donor_position.rs
+use anchor_lang::prelude::*;
+use crate::errors::*;
+
+/// Everything one donor has given to one campaign
+#[account]
+#[derive(Default)]
+#[derive(InitSpace)]
+pub struct DonorPosition {
+    pub campaign: Pubkey,
+    pub donor: Pubkey,
+    /// Total given, in the campaign's mint, net of refunds and excluding matches
+    pub total_amount: u64,
+    /// Donations that haven't been refunded
+    pub donation_count: u32,
+    pub first_donation_at: i64,
+    pub last_donation_at: i64,
+    pub bump: u8,
+}
+
+impl DonorPosition {
+    /// Whether the donor has never given to the campaign before
+    pub fn is_first_gift(&self) -> bool {
+        self.first_donation_at == 0
+    }
+
+    /// Whether the donor currently counts towards the campaign's donors
+    pub fn is_active(&self) -> bool {
+        self.donation_count > 0
+    }
+
+    /// Adds a donation of `amount` to the position
+    pub fn record(&mut self, amount: u64, now: i64) -> Result<()> {
+        if self.is_first_gift() {
+            self.first_donation_at = now;
+        }
+        self.last_donation_at = self.last_donation_at.max(now);
+        self.total_amount = self.total_amount
+            .checked_add(amount)
+            .ok_or(error!(RipplError::InsufficientFunds))?;
+        self.donation_count = self.donation_count
+            .checked_add(1)
+            .ok_or(error!(RipplError::InsufficientFunds))?;
+        Ok(())
+    }
+
+    /// Takes a refunded donation of `amount` back out of the position
+    pub fn remove(&mut self, amount: u64) {
+        self.total_amount = self.total_amount.saturating_sub(amount);
+        self.donation_count = self.donation_count.saturating_sub(1);
+    }
+}
@@ -0,0 +1,100 @@
+// File: src/state/funding_round.rs
+use anchor_lang::prelude::*;
+
+/// A quadratic funding grant round. Donations made to registered campaigns during
+/// the round are tallied, and `finalize_round` splits the matching pot between
+/// campaigns in proportion to the square of the sum of the square roots of their
+/// donors' contributions. Rounds are denominated in SOL.
+#[account]
+#[derive(InitSpace)]
+pub struct FundingRound {
+    pub admin: Pubkey,
+    /// Admin-chosen identifier, so one admin can run several rounds
+    pub id: u64,
+    /// Lamports committed to matching, held on top of the account's rent
+    pub pot: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    /// Most a single donor's contributions to one campaign count towards its match
+    pub max_contribution_per_donor: u64,
+    pub campaign_count: u32,
+    /// Sum of every registered campaign's score
+    pub total_score: u128,
+    pub finalized: bool,
+    /// Total paid into campaign vaults at finalization
+    pub distributed: u64,
+    pub bump: u8,
+}
+
+impl FundingRound {
+    pub fn is_open(&self, now: i64) -> bool {
+        now >= self.start_time && now < self.end_time
+    }
+
+    /// Share of the pot owed to a campaign with `score`
+    pub fn allocation(&self, score: u128) -> Option<u64> {
+        if self.total_score == 0 {
+            return Some(0);
+        }
+        let allocation = (self.pot as u128).checked_mul(score)?.checked_div(self.total_score)?;
+        u64::try_from(allocation).ok()
+    }
+}
+
+/// A campaign registered in a funding round
+#[account]
+#[derive(InitSpace)]
+pub struct RoundParticipant {
+    pub round: Pubkey,
+    pub campaign: Pubkey,
+    /// Vault the campaign's match is paid into
+    pub vault: Pubkey,
+    /// Sum of the square roots of each donor's counted contributions
+    pub sum_sqrt: u128,
+    /// Total counted contributions, after the per-donor cap
+    pub contributions: u64,
+    pub contributors: u32,
+    /// Match paid to the campaign when the round was finalized. Cleared when
+    /// `return_round_match` gives it back to the admin.
+    pub match_amount: u64,
+    /// Value of `match_amount` in micro-USD when it was paid, if the campaign
+    /// has a USD target
+    pub match_usd_value: Option<u64>,
+    /// Set when the admin takes the campaign out of the round. Removed campaigns
+    /// aren't tallied or finalized, and can't register in the round again.
+    pub removed: bool,
+    pub bump: u8,
+}
+
+impl RoundParticipant {
+    /// The campaign's quadratic funding score, `sum_sqrt` squared
+    pub fn score(&self) -> Option<u128> {
+        self.sum_sqrt.checked_mul(self.sum_sqrt)
+    }
+}
+
+/// A donor's counted contributions to one campaign in a funding round
+#[account]
+#[derive(InitSpace)]
+pub struct RoundContribution {
+    pub round: Pubkey,
+    pub campaign: Pubkey,
+    pub donor: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+/// Integer square root, rounded down
+pub fn isqrt(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+    // Newton's method converges from above when started at or above the root
+    let mut x = value;
+    let mut y = x / 2 + (x & 1);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
@@ -0,0 +1,61 @@
+// File: src/state/matching_pool.rs
+use anchor_lang::prelude::*;
+use crate::constants::BPS_DENOMINATOR;
+use crate::state::{ Campaign, CampaignCategory };
+
+/// Sponsor funds that top up eligible donations passed through `donate`
+#[account]
+#[derive(InitSpace)]
+pub struct MatchingPool {
+    pub sponsor: Pubkey,
+    /// Sponsor-chosen identifier, so one sponsor can run several pools
+    pub id: u64,
+    /// Mint the pool matches in, or `None` for native SOL. Only campaigns
+    /// accepting the same mint can be matched.
+    pub mint: Option<Pubkey>,
+    /// Campaign categories whose donations are matched
+    #[max_len(6)]
+    pub categories: Vec<CampaignCategory>,
+    /// Individual campaigns whose donations are matched, whatever their category
+    #[max_len(10)]
+    pub campaigns: Vec<Pubkey>,
+    /// Amount matched per unit donated, in basis points (10_000 = 1:1)
+    pub match_ratio_bps: u16,
+    pub per_donation_cap: u64,
+    /// Total the sponsor has committed to the pool
+    pub total_cap: u64,
+    /// Matched so far, net of matches returned by refunds
+    pub matched_total: u64,
+    pub matched_count: u32,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub bump: u8,
+}
+
+impl MatchingPool {
+    pub fn is_eligible(&self, campaign_key: &Pubkey, campaign: &Campaign) -> bool {
+        self.mint == campaign.accepted_mint &&
+            (self.categories.contains(&campaign.category) || self.campaigns.contains(campaign_key))
+    }
+
+    /// Funds still available to match
+    pub fn available(&self) -> u64 {
+        self.total_cap.saturating_sub(self.matched_total)
+    }
+
+    /// Match owed for a donation of `amount`, capped per donation and by what
+    /// is left in the pool. Nothing is matched outside the pool's window.
+    pub fn match_for(&self, amount: u64, now: i64) -> u64 {
+        if now < self.start_time || now >= self.end_time {
+            return 0;
+        }
+        let matched = (amount as u128)
+            .saturating_mul(self.match_ratio_bps as u128)
+            .checked_div(BPS_DENOMINATOR as u128)
+            .unwrap_or(0);
+        u64::try_from(matched)
+            .unwrap_or(u64::MAX)
+            .min(self.per_donation_cap)
+            .min(self.available())
+    }
+}
@@ -0,0 +1,85 @@
+// File: src/state/milestone.rs
+use anchor_lang::prelude::*;
+use crate::constants::BPS_DENOMINATOR;
+
+/// A deliverable that unlocks part of a campaign's funds once the organizer
+/// shows it has been reached
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(InitSpace)]
+pub struct Milestone {
+    #[max_len(100)]
+    pub description: String, // 100 chars max
+    pub amount: MilestoneAmount,
+    pub deadline: i64,
+    pub status: MilestoneStatus,
+    pub completed_at: Option<i64>,
+    /// Where the evidence of completion is published, e.g. an IPFS or Arweave URI
+    #[max_len(200)]
+    pub evidence_uri: String, // 200 chars max
+    /// SHA-256 of the evidence content, so it can't be swapped after the fact
+    pub evidence_hash: [u8; 32],
+    /// End of the donor vote, for campaigns that put milestones to a vote
+    pub voting_ends_at: Option<i64>,
+    pub approve_weight: u64,
+    pub reject_weight: u64,
+}
+
+impl Milestone {
+    /// Funds this milestone releases out of `raised_amount`
+    pub fn release_amount(&self, raised_amount: u64) -> u64 {
+        match self.amount {
+            MilestoneAmount::Fixed(amount) => amount,
+            MilestoneAmount::Percentage(bps) =>
+                ((raised_amount as u128) * (bps as u128) / (BPS_DENOMINATOR as u128)) as u64,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(InitSpace)]
+pub enum MilestoneAmount {
+    /// A fixed amount, in the campaign's unit
+    Fixed(u64),
+    /// A share of the raised amount, in basis points
+    Percentage(u16),
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Default)]
+#[derive(InitSpace)]
+pub enum MilestoneStatus {
+    #[default]
+    Pending,
+    /// Reported as reached and waiting on the donor vote
+    Voting,
+    /// Reached, and approved by donors where the campaign requires it
+    Approved,
+    /// Donors voted the milestone down; the campaign is cancelled
+    Rejected,
+}
+
+/// How donors sign off on milestones. Quorum is measured against the campaign's
+/// `voteable_amount`, since each donor votes with the amount they donated.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(InitSpace)]
+pub struct VotingConfig {
+    /// How long donors have to vote once a milestone is reported, in seconds
+    pub voting_period: i64,
+    /// Share of the voteable amount that must vote, in basis points
+    pub quorum_bps: u16,
+    /// Share of the votes cast that must approve, in basis points
+    pub approval_threshold_bps: u16,
+}
+
+/// A donor's vote on one milestone, weighted by their donations to the campaign
+#[account]
+#[derive(Default)]
+#[derive(InitSpace)]
+pub struct MilestoneVote {
+    pub campaign: Pubkey,
+    pub milestone_index: u8,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub weight: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
@@ -1,8 +1,24 @@
 // File: src/state/mod.rs
 pub mod campaign;
+pub mod campaign_role;
 pub mod donation;
+pub mod donor_position;
+pub mod funding_round;
+pub mod matching_pool;
+pub mod milestone;
+pub mod price_feed;
+pub mod subscription;
 pub mod user;
+pub mod withdrawal;
 
 pub use campaign::*;
+pub use campaign_role::*;
 pub use donation::*;
-pub use user::*;
\ No newline at end of file
+pub use donor_position::*;
+pub use funding_round::*;
+pub use matching_pool::*;
+pub use milestone::*;
+pub use price_feed::*;
+pub use subscription::*;
+pub use user::*;
+pub use withdrawal::*;
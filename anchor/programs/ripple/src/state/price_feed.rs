@@ -0,0 +1,38 @@
+// File: src/state/price_feed.rs
+use anchor_lang::prelude::*;
+
+/// A price account with a fixed layout that stands in for a real oracle on
+/// localnet and in tests. Anyone can publish their own mock feed, so they are
+/// only accepted by builds with the `mock-oracle` feature.
+#[account]
+#[derive(Default)]
+#[derive(InitSpace)]
+pub struct MockPriceFeed {
+    pub authority: Pubkey,
+    pub price: i64,
+    pub confidence: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(InitSpace)]
+pub enum PriceFeedKind {
+    /// A `MockPriceFeed` account owned by this program (`mock-oracle` builds only)
+    Mock,
+    /// A Pyth pull oracle `PriceUpdateV2` account
+    Pyth,
+}
+
+/// Fiat target of a campaign and the price account used to value donations against it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(InitSpace)]
+pub struct UsdTarget {
+    /// Target in micro-USD (6 decimals)
+    pub target_usd: u64,
+    pub price_feed: Pubkey,
+    pub feed_kind: PriceFeedKind,
+    /// Pyth price feed id the price account must carry; unused for mock feeds
+    pub feed_id: [u8; 32],
+}
@@ -0,0 +1,67 @@
+// File: src/state/subscription.rs
+use anchor_lang::prelude::*;
+
+/// A recurring donation that any keeper can execute through `execute_subscription`
+/// once it falls due
+#[account]
+#[derive(InitSpace)]
+pub struct Subscription {
+    pub donor: Pubkey,
+    /// Donor-chosen identifier, so one donor can hold several subscriptions
+    pub id: u64,
+    pub target: SubscriptionTarget,
+    /// Mint the subscription donates in, or `None` for native SOL
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    /// Time between donations, in seconds
+    pub interval: i64,
+    pub next_due: i64,
+    /// Number of donations after which the subscription ends, or `None` for no limit
+    pub max_count: Option<u32>,
+    pub executed_count: u32,
+    pub funding: SubscriptionFunding,
+    /// Lamports prepaid for SOL subscriptions, held on top of the account's rent
+    pub escrow_balance: u64,
+    pub status: SubscriptionStatus,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Subscription {
+    pub fn is_exhausted(&self) -> bool {
+        self.max_count.is_some_and(|max_count| self.executed_count >= max_count)
+    }
+
+    /// Whether `campaign` is a valid recipient of this subscription's donations
+    pub fn accepts_campaign(&self, campaign: &Pubkey, campaign_authority: &Pubkey) -> bool {
+        match self.target {
+            SubscriptionTarget::Campaign(target) => target == *campaign,
+            SubscriptionTarget::Organization(authority) => authority == *campaign_authority,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(InitSpace)]
+pub enum SubscriptionTarget {
+    /// Always donate to this campaign
+    Campaign(Pubkey),
+    /// Donate to whichever active campaign of this authority the keeper supplies
+    Organization(Pubkey),
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(InitSpace)]
+pub enum SubscriptionFunding {
+    /// Donations are paid out of lamports prepaid into the subscription account
+    Escrow,
+    /// The subscription account is approved as delegate on the donor's token account
+    Delegate,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+#[derive(InitSpace)]
+pub enum SubscriptionStatus {
+    Active,
+    Paused,
+}
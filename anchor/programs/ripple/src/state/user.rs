@@ -14,12 +14,17 @@ pub struct User {
     #[max_len(200)]
     pub avatar_url: String,
     pub total_donations: u64,
+    /// Number of distinct campaigns the user has given to and not been fully
+    /// refunded by. Tributes count here for the donor, not the honoree.
     pub campaigns_supported: u32,
     pub impact_metrics: ImpactMetrics,
     #[max_len(5)]
     pub badges: Vec<Badge>,
     pub rank: u32,
     pub bump: u8,
+    /// Number of campaigns the user has created, and the id of the next one.
+    /// Profiles created before this field existed get it from `migrate_user`.
+    pub campaigns_created: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
@@ -0,0 +1,58 @@
+// File: src/state/withdrawal.rs
+use anchor_lang::prelude::*;
+
+/// A permanent record of funds leaving a campaign vault through `withdraw_funds`
+#[account]
+#[derive(Default)]
+#[derive(InitSpace)]
+pub struct Withdrawal {
+    pub campaign: Pubkey,
+    /// Position of this withdrawal in the campaign's ledger, starting at 0
+    pub sequence: u64,
+    pub authority: Pubkey,
+    pub recipient: Pubkey,
+    /// Mint the withdrawal was paid in, or `None` for native SOL
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub timestamp: i64,
+    #[max_len(200)]
+    pub purpose: String, // 200 chars max
+    /// Hash of an off-chain receipt or invoice backing the withdrawal
+    pub receipt_hash: Option<[u8; 32]>,
+    pub bump: u8,
+}
+
+/// Signers who must approve a campaign's withdrawals, for organizations that
+/// require several people to sign off on disbursements
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(InitSpace)]
+pub struct ApprovalConfig {
+    #[max_len(5)]
+    pub signers: Vec<Pubkey>,
+    /// Number of signers that must approve a withdrawal before it can be executed
+    pub threshold: u8,
+}
+
+/// A withdrawal waiting on approval from the campaign's signers. Closed once it
+/// is executed or expires; the `Withdrawal` record is what remains.
+#[account]
+#[derive(Default)]
+#[derive(InitSpace)]
+pub struct WithdrawalProposal {
+    pub campaign: Pubkey,
+    /// Position among the campaign's proposals, which the address is derived from
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    #[max_len(200)]
+    pub purpose: String, // 200 chars max
+    pub receipt_hash: Option<[u8; 32]>,
+    /// Signers who have approved, including the proposer
+    #[max_len(5)]
+    pub approvals: Vec<Pubkey>,
+    pub created_at: i64,
+    /// After this the proposal can no longer be approved or executed
+    pub expires_at: i64,
+    pub bump: u8,
+}
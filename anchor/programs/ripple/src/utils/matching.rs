@@ -0,0 +1,96 @@
+// File: src/utils/matching.rs
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+use crate::constants::MATCHING_POOL_SEED;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::transfer_tokens;
+
+/// A matching pool passed to an instruction through its remaining accounts
+pub struct MatchingPoolAccounts<'info> {
+    pub pool: Account<'info, MatchingPool>,
+    /// Token account holding the pool's funds, for token pools
+    pub pool_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// Splits a matching pool off the front of `remaining_accounts`. A token pool is
+/// followed by its token account. Whatever is left over is returned so it can
+/// still be passed on to transfer hooks.
+pub fn take_matching_pool<'info>(
+    remaining_accounts: &'info [AccountInfo<'info>]
+) -> Result<(Option<MatchingPoolAccounts<'info>>, &'info [AccountInfo<'info>])> {
+    let Some(first) = remaining_accounts.first() else {
+        return Ok((None, remaining_accounts));
+    };
+    if first.owner != &crate::ID || !first.try_borrow_data()?.starts_with(&MatchingPool::DISCRIMINATOR) {
+        return Ok((None, remaining_accounts));
+    }
+    require!(first.is_writable, RipplError::InvalidMatchingPool);
+    let pool = Account::<MatchingPool>::try_from(first)?;
+
+    if pool.mint.is_none() {
+        return Ok((Some(MatchingPoolAccounts { pool, pool_token_account: None }), &remaining_accounts[1..]));
+    }
+
+    let token_account_info = remaining_accounts
+        .get(1)
+        .ok_or(error!(RipplError::MissingTokenAccounts))?;
+    let pool_token_account = InterfaceAccount::<TokenAccount>::try_from(token_account_info)?;
+    require!(
+        pool_token_account.owner == pool.key() && Some(pool_token_account.mint) == pool.mint,
+        RipplError::InvalidTokenAccount
+    );
+
+    Ok((
+        Some(MatchingPoolAccounts { pool, pool_token_account: Some(pool_token_account) }),
+        &remaining_accounts[2..],
+    ))
+}
+
+/// Pays `amount` out of a matching pool, signed by the pool PDA. SOL pools pay
+/// lamports to `recipient`; token pools pay into `token_account`.
+pub fn pay_from_pool<'info>(
+    pool: &Account<'info, MatchingPool>,
+    pool_token_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    recipient: &AccountInfo<'info>,
+    token_account: Option<(&InterfaceAccount<'info, Mint>, &InterfaceAccount<'info, TokenAccount>, &Interface<'info, TokenInterface>)>,
+    extra_accounts: &[AccountInfo<'info>],
+    amount: u64
+) -> Result<()> {
+    match (pool_token_account, token_account) {
+        (Some(pool_token_account), Some((mint, token_account, token_program))) => {
+            let id_bytes = pool.id.to_le_bytes();
+            let pool_seeds: [&[u8]; 4] = [
+                MATCHING_POOL_SEED,
+                pool.sponsor.as_ref(),
+                &id_bytes,
+                &[pool.bump],
+            ];
+            transfer_tokens(
+                token_program,
+                pool_token_account,
+                mint,
+                token_account,
+                &pool.to_account_info(),
+                extra_accounts,
+                amount,
+                &[&pool_seeds[..]]
+            )
+        }
+        (None, None) => {
+            // The pool account is owned by this program, so its lamports can be debited directly
+            let pool_info = pool.to_account_info();
+            **pool_info.try_borrow_mut_lamports()? = pool_info
+                .lamports()
+                .checked_sub(amount)
+                .ok_or(error!(RipplError::InsufficientFunds))?;
+            **recipient.try_borrow_mut_lamports()? = recipient
+                .lamports()
+                .checked_add(amount)
+                .ok_or(error!(RipplError::InsufficientFunds))?;
+            Ok(())
+        }
+        _ => Err(error!(RipplError::MissingTokenAccounts)),
+    }
+}
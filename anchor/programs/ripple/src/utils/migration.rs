@@ -0,0 +1,26 @@
+// File: src/utils/migration.rs
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Resizes a program account that predates its current layout to `space` bytes,
+/// with `payer` topping up its rent. New bytes are zeroed.
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    space: usize
+) -> Result<()> {
+    let rent_due = Rent::get()?.minimum_balance(space).saturating_sub(account.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(system_program.to_account_info(), system_program::Transfer {
+                from: payer.to_account_info(),
+                to: account.clone(),
+            }),
+            rent_due
+        )?;
+    }
+    account.realloc(space, true)?;
+
+    Ok(())
+}
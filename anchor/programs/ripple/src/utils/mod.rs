@@ -0,0 +1,12 @@
+// File: src/utils/mod.rs
+pub mod matching;
+pub mod migration;
+pub mod price;
+pub mod token;
+pub mod vault;
+
+pub use matching::*;
+pub use migration::*;
+pub use price::*;
+pub use token::*;
+pub use vault::*;
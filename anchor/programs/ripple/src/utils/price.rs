@@ -0,0 +1,137 @@
+// File: src/utils/price.rs
+use anchor_lang::prelude::*;
+use crate::constants::{
+    MAX_PRICE_AGE,
+    MAX_PRICE_CONFIDENCE_BPS,
+    PYTH_RECEIVER_PROGRAM_ID,
+    USD_DECIMALS,
+};
+use crate::errors::*;
+use crate::state::*;
+
+/// Anchor discriminator of the Pyth receiver's `PriceUpdateV2` account
+const PYTH_PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// A price normalised from any supported feed format
+pub struct OraclePrice {
+    pub price: i64,
+    pub confidence: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+#[derive(AnchorDeserialize)]
+enum PythVerificationLevel {
+    Partial {
+        #[allow(dead_code)]
+        num_signatures: u8,
+    },
+    Full,
+}
+
+#[derive(AnchorDeserialize)]
+struct PythPriceMessage {
+    feed_id: [u8; 32],
+    price: i64,
+    conf: u64,
+    exponent: i32,
+    publish_time: i64,
+    _prev_publish_time: i64,
+    _ema_price: i64,
+    _ema_conf: u64,
+}
+
+#[derive(AnchorDeserialize)]
+struct PythPriceUpdateV2 {
+    _write_authority: Pubkey,
+    verification_level: PythVerificationLevel,
+    price_message: PythPriceMessage,
+    _posted_slot: u64,
+}
+
+/// Reads the price from `price_account` according to the campaign's feed kind and
+/// rejects prices that are stale, non-positive or too uncertain.
+pub fn load_price(
+    price_account: &AccountInfo,
+    usd_target: &UsdTarget,
+    now: i64
+) -> Result<OraclePrice> {
+    require_keys_eq!(price_account.key(), usd_target.price_feed, RipplError::InvalidPriceFeed);
+
+    let price = match usd_target.feed_kind {
+        PriceFeedKind::Mock => {
+            require!(cfg!(feature = "mock-oracle"), RipplError::MockOracleDisabled);
+            require_keys_eq!(*price_account.owner, crate::ID, RipplError::InvalidPriceFeed);
+            let data = price_account.try_borrow_data()?;
+            let feed = MockPriceFeed::try_deserialize(&mut &data[..])?;
+            OraclePrice {
+                price: feed.price,
+                confidence: feed.confidence,
+                exponent: feed.exponent,
+                publish_time: feed.publish_time,
+            }
+        }
+        PriceFeedKind::Pyth => {
+            require_keys_eq!(
+                *price_account.owner,
+                PYTH_RECEIVER_PROGRAM_ID,
+                RipplError::InvalidPriceFeed
+            );
+            let data = price_account.try_borrow_data()?;
+            require!(
+                data.len() > 8 && data[..8] == PYTH_PRICE_UPDATE_V2_DISCRIMINATOR,
+                RipplError::InvalidPriceFeed
+            );
+            let update = PythPriceUpdateV2::deserialize(&mut &data[8..])?;
+            require!(
+                matches!(update.verification_level, PythVerificationLevel::Full),
+                RipplError::InvalidPriceFeed
+            );
+            // Price update accounts can be re-posted for any feed, so the feed id
+            // is what actually pins the asset being priced
+            require!(
+                update.price_message.feed_id == usd_target.feed_id,
+                RipplError::InvalidPriceFeed
+            );
+            OraclePrice {
+                price: update.price_message.price,
+                confidence: update.price_message.conf,
+                exponent: update.price_message.exponent,
+                publish_time: update.price_message.publish_time,
+            }
+        }
+    };
+
+    require!(price.price > 0, RipplError::InvalidPrice);
+    require!(
+        now.saturating_sub(price.publish_time) <= MAX_PRICE_AGE,
+        RipplError::StalePrice
+    );
+    require!(
+        (price.confidence as u128) * 10_000 <=
+            (price.price as u128) * (MAX_PRICE_CONFIDENCE_BPS as u128),
+        RipplError::PriceConfidenceTooWide
+    );
+
+    Ok(price)
+}
+
+/// Values `amount` base units of a token with `decimals` in micro-USD
+pub fn usd_value(amount: u64, decimals: u8, price: &OraclePrice) -> Result<u64> {
+    let value = (amount as u128)
+        .checked_mul(price.price as u128)
+        .ok_or(error!(RipplError::InvalidPrice))?;
+
+    // value is scaled by 10^(decimals - exponent); rescale to USD_DECIMALS
+    let scale = (USD_DECIMALS as i64) + (price.exponent as i64) - (decimals as i64);
+    let factor = 10u128
+        .checked_pow(scale.unsigned_abs() as u32)
+        .ok_or(error!(RipplError::InvalidPrice))?;
+    let scaled = if scale >= 0 {
+        value.checked_mul(factor).ok_or(error!(RipplError::InvalidPrice))?
+    } else {
+        value / factor
+    };
+
+    u64::try_from(scaled).map_err(|_| error!(RipplError::InvalidPrice))
+}
@@ -0,0 +1,66 @@
+// File: src/utils/token.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{ BaseStateWithExtensions, ExtensionType, StateWithExtensions },
+};
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+use crate::errors::*;
+
+/// Rejects mints whose extensions would let someone other than the vault PDA
+/// move or lock the campaign's funds. A permanent delegate can drain the vault at
+/// will, so it is only accepted when the campaign explicitly opts in.
+pub fn check_mint_extensions(
+    mint: &InterfaceAccount<Mint>,
+    allow_unsafe_extensions: bool
+) -> Result<()> {
+    let mint_info = mint.to_account_info();
+    if mint_info.owner != &spl_token_2022::ID {
+        return Ok(());
+    }
+
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    for extension in mint_state.get_extension_types()? {
+        match extension {
+            ExtensionType::NonTransferable => {
+                return Err(error!(RipplError::UnsupportedMintExtension));
+            }
+            ExtensionType::PermanentDelegate if !allow_unsafe_extensions => {
+                return Err(error!(RipplError::UnsafeMintExtension));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves tokens with `transfer_checked`, appending whatever extra accounts the
+/// mint's transfer hook requires from `extra_accounts`. Works for both the
+/// legacy token program and Token-2022.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_tokens<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    authority: &AccountInfo<'info>,
+    extra_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]]
+) -> Result<()> {
+    spl_token_2022::onchain::invoke_transfer_checked(
+        &token_program.key(),
+        from.to_account_info(),
+        mint.to_account_info(),
+        to.to_account_info(),
+        authority.clone(),
+        extra_accounts,
+        amount,
+        mint.decimals,
+        signer_seeds
+    )?;
+
+    Ok(())
+}
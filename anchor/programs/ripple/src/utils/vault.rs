@@ -0,0 +1,138 @@
+// File: src/utils/vault.rs
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{ Mint, TokenAccount, TokenInterface };
+use crate::constants::VAULT_SEED;
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::transfer_tokens;
+
+/// Token accounts needed to move a token campaign's funds in or out of its vault
+pub struct VaultTokenAccounts<'a, 'info> {
+    pub mint: &'a InterfaceAccount<'info, Mint>,
+    pub vault_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    /// The token account on the other side of the transfer
+    pub token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'a, 'info> VaultTokenAccounts<'a, 'info> {
+    /// Collects the optional token accounts of an instruction, failing if any is missing
+    pub fn try_from_options(
+        mint: &'a Option<InterfaceAccount<'info, Mint>>,
+        vault_token_account: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+        token_account: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+        token_program: &'a Option<Interface<'info, TokenInterface>>
+    ) -> Result<Self> {
+        match (mint, vault_token_account, token_account, token_program) {
+            (Some(mint), Some(vault_token_account), Some(token_account), Some(token_program)) =>
+                Ok(Self {
+                    mint,
+                    vault_token_account,
+                    token_account,
+                    token_program,
+                }),
+            _ => Err(error!(RipplError::MissingTokenAccounts)),
+        }
+    }
+}
+
+/// Balance held in the campaign vault, in the campaign's own unit
+pub fn vault_balance(vault: &SystemAccount, token_accounts: Option<&VaultTokenAccounts>) -> u64 {
+    match token_accounts {
+        Some(accounts) => accounts.vault_token_account.amount,
+        None => vault.lamports(),
+    }
+}
+
+/// Cancels the campaign and snapshots what is left in its vault as the pool
+/// donors are refunded from, pro-rata to their donations
+pub fn open_refund_pool(
+    campaign: &mut Campaign,
+    vault: &SystemAccount,
+    vault_token_account: &Option<InterfaceAccount<TokenAccount>>
+) -> Result<()> {
+    let refund_pool = match campaign.accepted_mint {
+        Some(_) =>
+            vault_token_account
+                .as_ref()
+                .ok_or(error!(RipplError::MissingTokenAccounts))?.amount,
+        None => vault.lamports(),
+    };
+
+    campaign.status = CampaignStatus::Cancelled;
+    campaign.refund_pool = refund_pool;
+
+    Ok(())
+}
+
+/// Pays `amount` out of the campaign vault, signed by the vault PDA. SOL campaigns
+/// pay lamports to `recipient`; token campaigns pay into `token_accounts.token_account`.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_from_vault<'info>(
+    campaign: &Account<'info, Campaign>,
+    vault: &SystemAccount<'info>,
+    vault_bump: u8,
+    recipient: &AccountInfo<'info>,
+    token_accounts: Option<&VaultTokenAccounts<'_, 'info>>,
+    system_program: &Program<'info, System>,
+    extra_accounts: &[AccountInfo<'info>],
+    amount: u64
+) -> Result<()> {
+    require!(
+        vault_balance(vault, token_accounts) >= amount,
+        RipplError::InsufficientFunds
+    );
+
+    let campaign_key = campaign.key();
+    let vault_seeds: [&[u8]; 3] = [VAULT_SEED, campaign_key.as_ref(), &[vault_bump]];
+
+    match token_accounts {
+        Some(accounts) =>
+            transfer_tokens(
+                accounts.token_program,
+                accounts.vault_token_account,
+                accounts.mint,
+                accounts.token_account,
+                &vault.to_account_info(),
+                extra_accounts,
+                amount,
+                &[&vault_seeds[..]]
+            ),
+        None =>
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: vault.to_account_info(),
+                        to: recipient.clone(),
+                    },
+                    &[&vault_seeds[..]]
+                ),
+                amount
+            ),
+    }
+}
+
+/// Moves the campaign's expiry bounty off the campaign account to `recipient`.
+/// Safe to call more than once; the bounty is only paid the first time.
+pub fn release_expiry_bounty(campaign: &mut Account<Campaign>, recipient: &AccountInfo) -> Result<()> {
+    let bounty = campaign.expiry_bounty;
+    if bounty == 0 {
+        return Ok(());
+    }
+    campaign.expiry_bounty = 0;
+
+    // The campaign account is owned by this program, so its lamports can be debited directly
+    let campaign_info = campaign.to_account_info();
+    **campaign_info.try_borrow_mut_lamports()? = campaign_info
+        .lamports()
+        .checked_sub(bounty)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+    **recipient.try_borrow_mut_lamports()? = recipient
+        .lamports()
+        .checked_add(bounty)
+        .ok_or(error!(RipplError::InsufficientFunds))?;
+
+    Ok(())
+}
@@ -0,0 +1,290 @@
+// File: tests/10-usd-targets.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import {
+  setupTest,
+  ERROR_MESSAGES,
+  getCurrentTimestamp,
+  getFutureTimestamp,
+  sleep,
+} from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestUser,
+  findNextCampaignPDA,
+  findCampaignVaultPDA,
+  findDonationPDA,
+  findDonorPositionPDA,
+  findFundingRoundPDA,
+  findMockPriceFeedPDA,
+  findRoundContributionPDA,
+  findRoundParticipantPDA,
+  findUserPDA,
+} from "./utils/helpers";
+
+describe("USD Targets", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donor: Keypair;
+  let priceFeedPDA: PublicKey;
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+
+  const title = "Raise $10,000";
+  const targetUsd = 10_000 * 1_000_000; // micro-USD
+  // $150.00 per SOL with an exponent of -8, as Pyth reports it
+  const solPrice = 150 * 100_000_000;
+  const priceExponent = -8;
+
+  const setMockPrice = async (
+    price: number,
+    confidence: number,
+    publishTime: number
+  ) => {
+    await program.methods
+      .setMockPrice(
+        new anchor.BN(price),
+        new anchor.BN(confidence),
+        priceExponent,
+        new anchor.BN(publishTime)
+      )
+      .accounts({
+        authority: authority.publicKey,
+        priceFeed: priceFeedPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+  };
+
+  const donate = async (index: number, amount: number) => {
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    const [donationPDA] = await findDonationPDA(campaignPDA, index, program);
+
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      donor.publicKey,
+      program
+    );
+
+    await program.methods
+      .donate(new anchor.BN(amount), { cryptoWallet: {} }, null, null)
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        donation: donationPDA,
+        donorPosition: donorPositionPDA,
+        campaignVault: vaultPDA,
+        priceFeed: priceFeedPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc();
+
+    return donationPDA;
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+
+    [priceFeedPDA] = await findMockPriceFeedPDA(authority.publicKey, program);
+    [campaignPDA] = await findNextCampaignPDA(authority.publicKey, program);
+    [vaultPDA] = await findCampaignVaultPDA(campaignPDA, program);
+
+    await setMockPrice(solPrice, solPrice / 1000, getCurrentTimestamp());
+
+    const [userPDA] = await findUserPDA(authority.publicKey, program);
+    await program.methods
+      .createCampaign({
+        title,
+        description: "A fiat-denominated campaign",
+        category: { education: {} },
+        organizationName: "Test Organization",
+        targetAmount: new anchor.BN(0),
+        startDate: new anchor.BN(getCurrentTimestamp() - 60),
+        endDate: new anchor.BN(getFutureTimestamp(30)),
+        imageUrl: "https://example.com/image.jpg",
+        isUrgent: false,
+        allowUnsafeMintExtensions: false,
+        usdTarget: {
+          targetUsd: new anchor.BN(targetUsd),
+          priceFeed: priceFeedPDA,
+          feedKind: { mock: {} },
+          feedId: Array(32).fill(0),
+        },
+        fundingMode: { keepItAll: {} },
+        expiryBounty: new anchor.BN(0),
+        milestones: [],
+        voting: null,
+        beneficiaries: [authority.publicKey],
+        approvals: null,
+        donationLimits: { hardCap: null, maxPerDonor: null },
+      })
+      .accounts({
+        authority: authority.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        campaignVault: vaultPDA,
+        priceFeed: priceFeedPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+  });
+
+  it("should record the USD value of a donation", async () => {
+    const donationPDA = await donate(0, 2 * LAMPORTS_PER_SOL);
+
+    const donation = await program.account.donation.fetch(donationPDA);
+    expect(donation.usdValue.toNumber()).to.eq(300 * 1_000_000);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.raisedUsd.toNumber()).to.eq(300 * 1_000_000);
+    expect(campaign.usdTarget.targetUsd.toNumber()).to.eq(targetUsd);
+  });
+
+  it("should reject a donation priced with a stale feed", async () => {
+    await setMockPrice(solPrice, solPrice / 1000, getCurrentTimestamp() - 600);
+
+    try {
+      await donate(1, LAMPORTS_PER_SOL);
+      expect.fail("Should reject stale prices");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.STALE_PRICE);
+    }
+  });
+
+  it("should reject a donation when the confidence interval is too wide", async () => {
+    await setMockPrice(solPrice, solPrice / 10, getCurrentTimestamp());
+
+    try {
+      await donate(1, LAMPORTS_PER_SOL);
+      expect.fail("Should reject uncertain prices");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.PRICE_CONFIDENCE_TOO_WIDE
+      );
+    }
+  });
+
+  it("should value a funding round's match in USD", async () => {
+    const pot = LAMPORTS_PER_SOL;
+    const now = getCurrentTimestamp();
+    const [roundPDA] = await findFundingRoundPDA(
+      authority.publicKey,
+      0,
+      program
+    );
+    const [participantPDA] = await findRoundParticipantPDA(
+      roundPDA,
+      campaignPDA,
+      program
+    );
+
+    await program.methods
+      .createFundingRound({
+        id: new anchor.BN(0),
+        pot: new anchor.BN(pot),
+        startTime: new anchor.BN(now - 60),
+        endTime: new anchor.BN(now + 15),
+        maxContributionPerDonor: new anchor.BN(LAMPORTS_PER_SOL),
+      })
+      .accounts({
+        admin: authority.publicKey,
+        fundingRound: roundPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    await program.methods
+      .registerRoundCampaign()
+      .accounts({
+        authority: authority.publicKey,
+        admin: authority.publicKey,
+        fundingRound: roundPDA,
+        campaign: campaignPDA,
+        campaignVault: vaultPDA,
+        roundParticipant: participantPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    await setMockPrice(solPrice, solPrice / 1000, getCurrentTimestamp());
+    const donationPDA = await donate(1, LAMPORTS_PER_SOL);
+    const [contributionPDA] = await findRoundContributionPDA(
+      roundPDA,
+      campaignPDA,
+      donor.publicKey,
+      program
+    );
+    await program.methods
+      .tallyRoundContribution()
+      .accounts({
+        payer: authority.publicKey,
+        fundingRound: roundPDA,
+        roundParticipant: participantPDA,
+        donation: donationPDA,
+        roundContribution: contributionPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    await sleep(17000);
+    await setMockPrice(solPrice, solPrice / 1000, getCurrentTimestamp());
+    const before = await program.account.campaign.fetch(campaignPDA);
+
+    // The campaign has a USD target, so its price feed follows its vault
+    await program.methods
+      .finalizeRound()
+      .accounts({
+        caller: authority.publicKey,
+        fundingRound: roundPDA,
+        admin: authority.publicKey,
+      })
+      .remainingAccounts([
+        { pubkey: participantPDA, isSigner: false, isWritable: true },
+        { pubkey: campaignPDA, isSigner: false, isWritable: true },
+        { pubkey: vaultPDA, isSigner: false, isWritable: true },
+        { pubkey: priceFeedPDA, isSigner: false, isWritable: false },
+      ])
+      .signers([authority])
+      .rpc();
+
+    // The whole pot goes to the only campaign, at $150 per SOL
+    const participant = await program.account.roundParticipant.fetch(
+      participantPDA
+    );
+    expect(participant.matchAmount.toNumber()).to.eq(pot);
+    expect(participant.matchUsdValue.toNumber()).to.eq(150 * 1_000_000);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.raisedAmount.toNumber()).to.eq(
+      before.raisedAmount.toNumber() + pot
+    );
+    expect(campaign.raisedUsd.toNumber()).to.eq(
+      before.raisedUsd.toNumber() + 150 * 1_000_000
+    );
+  });
+});
@@ -0,0 +1,184 @@
+// File: tests/11-all-or-nothing.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import {
+  setupTest,
+  ERROR_MESSAGES,
+  getCurrentTimestamp,
+  sleep,
+} from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  makeTestDonation,
+  findDonorPositionPDA,
+  findNextWithdrawalPDA,
+  findUserPDA,
+} from "./utils/helpers";
+
+describe("All-or-Nothing Campaigns", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donor: Keypair;
+
+  const donationAmount = 0.5 * LAMPORTS_PER_SOL;
+  const day = 24 * 60 * 60;
+
+  const claimRefund = async (
+    campaignPDA: PublicKey,
+    vaultPDA: PublicKey,
+    donationPDA: PublicKey
+  ) => {
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      donor.publicKey,
+      program
+    );
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+
+    await program.methods
+      .claimRefund()
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        donation: donationPDA,
+        donorPosition: donorPositionPDA,
+        campaignVault: vaultPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+  });
+
+  it("should block withdrawals while the goal is unmet", async () => {
+    const { campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      {
+        title: "Unfunded Goal",
+        targetAmount: 2 * LAMPORTS_PER_SOL,
+        fundingMode: { allOrNothing: {} },
+      }
+    );
+    await makeTestDonation(program, donor, campaignPDA, donationAmount, vaultPDA);
+
+    for (const status of [{ inProgress: {} }, { completed: {} }]) {
+      await program.methods
+        .updateCampaign({
+          title: null,
+          description: null,
+          imageUrl: null,
+          startDate: null,
+          endDate: null,
+          status,
+          isUrgent: null,
+          donationLimits: null,
+        })
+        .accounts({
+          authority: authority.publicKey,
+          campaign: campaignPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    try {
+      const [withdrawalPDA] = await findNextWithdrawalPDA(campaignPDA, program);
+      await program.methods
+        .withdrawFunds(new anchor.BN(donationAmount), "Program costs", null)
+        .accounts({
+          authority: authority.publicKey,
+          campaign: campaignPDA,
+          withdrawal: withdrawalPDA,
+          campaignVault: vaultPDA,
+          recipient: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      expect.fail("Should not release funds before the goal is reached");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.FUNDING_GOAL_NOT_REACHED
+      );
+    }
+  });
+
+  it("should refund donors once the campaign ends short of its goal", async () => {
+    const now = getCurrentTimestamp();
+    const { campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      {
+        title: "Short Lived Goal",
+        targetAmount: 2 * LAMPORTS_PER_SOL,
+        startDate: now - day + 4,
+        endDate: now + 4,
+        fundingMode: { allOrNothing: {} },
+      }
+    );
+    const donationPDA = await makeTestDonation(
+      program,
+      donor,
+      campaignPDA,
+      donationAmount,
+      vaultPDA
+    );
+
+    try {
+      await claimRefund(campaignPDA, vaultPDA, donationPDA);
+      expect.fail("Should not refund before the campaign ends");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.REFUND_NOT_AVAILABLE);
+    }
+
+    await sleep(6000);
+
+    const donorBalanceBefore = await connection.getBalance(donor.publicKey);
+    await claimRefund(campaignPDA, vaultPDA, donationPDA);
+    const donorBalanceAfter = await connection.getBalance(donor.publicKey);
+
+    expect(donorBalanceAfter).to.be.greaterThan(donorBalanceBefore);
+
+    const donation = await program.account.donation.fetch(donationPDA);
+    expect(donation.status).to.deep.equal({ refunded: {} });
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.raisedAmount.toNumber()).to.eq(0);
+
+    try {
+      await claimRefund(campaignPDA, vaultPDA, donationPDA);
+      expect.fail("Should not refund the same donation twice");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.DONATION_NOT_REFUNDABLE
+      );
+    }
+  });
+});
@@ -0,0 +1,232 @@
+// File: tests/12-campaign-cancellation.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import { setupTest, ERROR_MESSAGES } from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  makeTestDonation,
+  findDonorPositionPDA,
+  findNextWithdrawalPDA,
+  findUserPDA,
+} from "./utils/helpers";
+
+describe("Campaign Cancellation", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donorA: Keypair;
+  let donorB: Keypair;
+  let recipient: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+  let donationA: PublicKey;
+  let donationB: PublicKey;
+
+  const claimRefund = async (donor: Keypair, donationPDA: PublicKey) => {
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      donor.publicKey,
+      program
+    );
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+
+    await program.methods
+      .claimRefund()
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        donation: donationPDA,
+        donorPosition: donorPositionPDA,
+        campaignVault: vaultPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donorA = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donorB = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    recipient = await createAndFundAccount(connection, LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donorA);
+    await createTestUser(program, donorB);
+
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      {
+        title: "Cancelled Campaign",
+        targetAmount: 4 * LAMPORTS_PER_SOL,
+        beneficiaries: [recipient.publicKey],
+      }
+    ));
+
+    donationA = await makeTestDonation(
+      program,
+      donorA,
+      campaignPDA,
+      LAMPORTS_PER_SOL,
+      vaultPDA
+    );
+    donationB = await makeTestDonation(
+      program,
+      donorB,
+      campaignPDA,
+      3 * LAMPORTS_PER_SOL,
+      vaultPDA
+    );
+
+    // Release half of the funds before the campaign is abandoned
+    for (const status of [{ inProgress: {} }, { completed: {} }]) {
+      await program.methods
+        .updateCampaign({
+          title: null,
+          description: null,
+          imageUrl: null,
+          startDate: null,
+          endDate: null,
+          status,
+          isUrgent: null,
+          donationLimits: null,
+        })
+        .accounts({
+          authority: authority.publicKey,
+          campaign: campaignPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    const [withdrawalPDA] = await findNextWithdrawalPDA(campaignPDA, program);
+    await program.methods
+      .withdrawFunds(new anchor.BN(2 * LAMPORTS_PER_SOL), "Program costs", null)
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+        withdrawal: withdrawalPDA,
+        campaignVault: vaultPDA,
+        recipient: recipient.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+  });
+
+  it("should only let the campaign authority cancel", async () => {
+    try {
+      await program.methods
+        .cancelCampaign()
+        .accounts({
+          authority: donorA.publicKey,
+          campaign: campaignPDA,
+          campaignVault: vaultPDA,
+        })
+        .signers([donorA])
+        .rpc();
+
+      expect.fail("Should reject cancellation by a non-authority");
+    } catch (error) {
+      expect(error.toString()).to.include("A seeds constraint was violated");
+    }
+  });
+
+  it("should cancel the campaign and snapshot the vault balance", async () => {
+    await program.methods
+      .cancelCampaign()
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+        campaignVault: vaultPDA,
+      })
+      .signers([authority])
+      .rpc();
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.status).to.deep.equal({ cancelled: {} });
+    expect(campaign.refundPool.toNumber()).to.eq(
+      await connection.getBalance(vaultPDA)
+    );
+  });
+
+  it("should freeze updates once cancelled", async () => {
+    try {
+      await program.methods
+        .updateCampaign({
+          title: null,
+          description: "Back from the dead",
+          imageUrl: null,
+          startDate: null,
+          endDate: null,
+          status: null,
+          isUrgent: null,
+          donationLimits: null,
+        })
+        .accounts({
+          authority: authority.publicKey,
+          campaign: campaignPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
+      expect.fail("Should reject updates to a cancelled campaign");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.CAMPAIGN_CANCELLED);
+    }
+  });
+
+  it("should reject new donations once cancelled", async () => {
+    try {
+      await makeTestDonation(
+        program,
+        donorA,
+        campaignPDA,
+        LAMPORTS_PER_SOL,
+        vaultPDA
+      );
+      expect.fail("Should reject donations to a cancelled campaign");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.CAMPAIGN_NOT_ACTIVE);
+    }
+  });
+
+  it("should refund each donor a pro-rata share of the remaining funds", async () => {
+    const vaultBalance = await connection.getBalance(vaultPDA);
+
+    await claimRefund(donorA, donationA);
+    const refundA = vaultBalance - (await connection.getBalance(vaultPDA));
+    expect(refundA).to.eq(Math.floor(vaultBalance / 4));
+
+    // The last claimant receives whatever is left, so the vault is drained
+    await claimRefund(donorB, donationB);
+    expect(await connection.getBalance(vaultPDA)).to.eq(0);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.refundPool.toNumber()).to.eq(0);
+    expect(campaign.raisedAmount.toNumber()).to.eq(0);
+
+    const donation = await program.account.donation.fetch(donationB);
+    expect(donation.status).to.deep.equal({ refunded: {} });
+  });
+});
@@ -0,0 +1,128 @@
+// File: tests/13-expire-campaign.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import {
+  setupTest,
+  ERROR_MESSAGES,
+  getCurrentTimestamp,
+  sleep,
+} from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  makeTestDonation,
+} from "./utils/helpers";
+
+describe("Campaign Expiry", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donor: Keypair;
+  let keeper: Keypair;
+
+  const day = 24 * 60 * 60;
+  const expiryBounty = 5_000_000;
+
+  const expireCampaign = async (campaignPDA: PublicKey) => {
+    await program.methods
+      .expireCampaign()
+      .accounts({
+        caller: keeper.publicKey,
+        campaign: campaignPDA,
+      })
+      .signers([keeper])
+      .rpc();
+  };
+
+  // Campaigns that end a few seconds from now, so the crank can be exercised
+  const createEndingCampaign = async (title: string) => {
+    const now = getCurrentTimestamp();
+    return await createTestCampaignWithVault(program, authority, {
+      title,
+      targetAmount: LAMPORTS_PER_SOL,
+      startDate: now - day + 4,
+      endDate: now + 4,
+      expiryBounty,
+    });
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    keeper = await createAndFundAccount(connection, LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+  });
+
+  it("should settle campaigns as completed or expired and pay the bounty", async () => {
+    const funded = await createEndingCampaign("Funded Before End");
+    const unfunded = await createEndingCampaign("Unfunded Before End");
+
+    await makeTestDonation(
+      program,
+      donor,
+      funded.campaignPDA,
+      LAMPORTS_PER_SOL,
+      funded.vaultPDA
+    );
+
+    try {
+      await expireCampaign(funded.campaignPDA);
+      expect.fail("Should not settle a campaign before it ends");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.CAMPAIGN_NOT_ENDED);
+    }
+
+    await sleep(6000);
+
+    const keeperBalanceBefore = await connection.getBalance(keeper.publicKey);
+    await expireCampaign(funded.campaignPDA);
+    await expireCampaign(unfunded.campaignPDA);
+    const keeperBalanceAfter = await connection.getBalance(keeper.publicKey);
+
+    // Two bounties, less the fees for two transactions
+    expect(keeperBalanceAfter).to.be.greaterThan(
+      keeperBalanceBefore + 2 * expiryBounty - 20_000
+    );
+
+    const fundedAccount = await program.account.campaign.fetch(
+      funded.campaignPDA
+    );
+    expect(fundedAccount.status).to.deep.equal({ completed: {} });
+    expect(fundedAccount.expiryBounty.toNumber()).to.eq(0);
+
+    const unfundedAccount = await program.account.campaign.fetch(
+      unfunded.campaignPDA
+    );
+    expect(unfundedAccount.status).to.deep.equal({ expired: {} });
+  });
+
+  it("should not settle a campaign twice", async () => {
+    const { campaignPDA } = await createEndingCampaign("Settled Twice");
+    await sleep(6000);
+    await expireCampaign(campaignPDA);
+
+    try {
+      await expireCampaign(campaignPDA);
+      expect.fail("Should not settle a campaign twice");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.INVALID_STATUS_TRANSITION
+      );
+    }
+  });
+});
@@ -0,0 +1,202 @@
+// File: tests/14-milestones.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { createHash } from "crypto";
+import { Ripple } from "../target/types/ripple";
+import {
+  setupTest,
+  ERROR_MESSAGES,
+  getFutureTimestamp,
+} from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  makeTestDonation,
+  findNextWithdrawalPDA,
+} from "./utils/helpers";
+
+describe("Milestones", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donor: Keypair;
+  let recipient: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+
+  const evidenceUri = "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+  const evidenceHash = Array.from(
+    createHash("sha256").update("site survey report").digest()
+  );
+
+  const withdraw = async (amount: number) => {
+    const [withdrawalPDA] = await findNextWithdrawalPDA(campaignPDA, program);
+    await program.methods
+      .withdrawFunds(new anchor.BN(amount), "Program costs", null)
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+        withdrawal: withdrawalPDA,
+        campaignVault: vaultPDA,
+        recipient: recipient.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+  };
+
+  const completeMilestone = async (index: number) => {
+    await program.methods
+      .completeMilestone(index, evidenceUri, evidenceHash)
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+      })
+      .signers([authority])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    recipient = await createAndFundAccount(connection, LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      {
+        title: "Milestone Campaign",
+        targetAmount: 2 * LAMPORTS_PER_SOL,
+        beneficiaries: [recipient.publicKey],
+        milestones: [
+          {
+            description: "Site survey",
+            amount: { fixed: [new anchor.BN(0.5 * LAMPORTS_PER_SOL)] },
+            deadline: new anchor.BN(getFutureTimestamp(60)),
+          },
+          {
+            description: "Construction",
+            amount: { percentage: [5_000] },
+            deadline: new anchor.BN(getFutureTimestamp(80)),
+          },
+        ],
+      }
+    ));
+
+    await makeTestDonation(
+      program,
+      donor,
+      campaignPDA,
+      2 * LAMPORTS_PER_SOL,
+      vaultPDA
+    );
+
+    for (const status of [{ inProgress: {} }, { completed: {} }]) {
+      await program.methods
+        .updateCampaign({
+          title: null,
+          description: null,
+          imageUrl: null,
+          startDate: null,
+          endDate: null,
+          status,
+          isUrgent: null,
+          donationLimits: null,
+        })
+        .accounts({
+          authority: authority.publicKey,
+          campaign: campaignPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    }
+  });
+
+  it("should reject percentage milestones above 100%", async () => {
+    try {
+      await createTestCampaignWithVault(program, authority, {
+        title: "Overcommitted Milestones",
+        milestones: [
+          {
+            description: "Everything",
+            amount: { percentage: [10_000] },
+            deadline: new anchor.BN(getFutureTimestamp(60)),
+          },
+          {
+            description: "Everything again",
+            amount: { percentage: [1] },
+            deadline: new anchor.BN(getFutureTimestamp(60)),
+          },
+        ],
+      });
+      expect.fail("Should reject milestones releasing more than 100%");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.INVALID_MILESTONE);
+    }
+  });
+
+  it("should block withdrawals before any milestone is reached", async () => {
+    try {
+      await withdraw(0.1 * LAMPORTS_PER_SOL);
+      expect.fail("Should not release funds before a milestone is reached");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.MILESTONE_NOT_REACHED);
+    }
+  });
+
+  it("should record evidence and release the milestone's amount", async () => {
+    await completeMilestone(0);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    const milestone = campaign.milestones[0];
+    expect(milestone.completedAt).to.not.be.null;
+    expect(milestone.evidenceUri).to.eq(evidenceUri);
+    expect(Array.from(milestone.evidenceHash)).to.deep.equal(evidenceHash);
+
+    await withdraw(0.5 * LAMPORTS_PER_SOL);
+
+    try {
+      await withdraw(1);
+      expect.fail("Should cap withdrawals at the released amount");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.MILESTONE_NOT_REACHED);
+    }
+  });
+
+  it("should release a percentage of the raised amount", async () => {
+    await completeMilestone(1);
+    await withdraw(LAMPORTS_PER_SOL);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.withdrawnAmount.toNumber()).to.eq(1.5 * LAMPORTS_PER_SOL);
+  });
+
+  it("should not complete a milestone twice", async () => {
+    try {
+      await completeMilestone(0);
+      expect.fail("Should reject completing a milestone twice");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.MILESTONE_ALREADY_COMPLETED
+      );
+    }
+  });
+});
@@ -0,0 +1,301 @@
+// File: tests/15-milestone-voting.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import {
+  setupTest,
+  ERROR_MESSAGES,
+  getCurrentTimestamp,
+  getFutureTimestamp,
+} from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  findDonorPositionPDA,
+  findMatchingPoolPDA,
+  findMilestoneVotePDA,
+  findNextDonationPDA,
+  findUserPDA,
+  makeTestDonation,
+} from "./utils/helpers";
+
+describe("Milestone Voting", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donorA: Keypair;
+  let donorB: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+  let donationsA: PublicKey[];
+  let donationB: PublicKey;
+
+  const vote = async (
+    voter: Keypair,
+    approve: boolean,
+    donations: PublicKey[],
+    campaign: PublicKey = campaignPDA
+  ) => {
+    const [votePDA] = await findMilestoneVotePDA(
+      campaign,
+      0,
+      voter.publicKey,
+      program
+    );
+
+    await program.methods
+      .voteMilestone(0, approve)
+      .accounts({
+        voter: voter.publicKey,
+        campaign,
+        vote: votePDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(
+        donations.map((pubkey) => ({
+          pubkey,
+          isSigner: false,
+          isWritable: false,
+        }))
+      )
+      .signers([voter])
+      .rpc();
+
+    return votePDA;
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donorA = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donorB = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donorA);
+    await createTestUser(program, donorB);
+
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      {
+        title: "Voted Milestones",
+        targetAmount: 2 * LAMPORTS_PER_SOL,
+        milestones: [
+          {
+            description: "Phase one",
+            amount: { percentage: [5_000] },
+            deadline: new anchor.BN(getFutureTimestamp(60)),
+          },
+        ],
+        voting: {
+          votingPeriod: new anchor.BN(24 * 60 * 60),
+          quorumBps: 5_000,
+          approvalThresholdBps: 6_000,
+        },
+      }
+    ));
+
+    donationsA = [
+      await makeTestDonation(
+        program,
+        donorA,
+        campaignPDA,
+        LAMPORTS_PER_SOL,
+        vaultPDA
+      ),
+      await makeTestDonation(
+        program,
+        donorA,
+        campaignPDA,
+        0.5 * LAMPORTS_PER_SOL,
+        vaultPDA
+      ),
+    ];
+    donationB = await makeTestDonation(
+      program,
+      donorB,
+      campaignPDA,
+      0.5 * LAMPORTS_PER_SOL,
+      vaultPDA
+    );
+
+    await program.methods
+      .completeMilestone(0, "https://example.com/phase-one", Array(32).fill(1))
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+      })
+      .signers([authority])
+      .rpc();
+  });
+
+  it("should open a vote when the milestone is reported", async () => {
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.milestones[0].status).to.deep.equal({ voting: {} });
+    expect(campaign.milestones[0].votingEndsAt).to.not.be.null;
+  });
+
+  it("should reject votes weighted by someone else's donations", async () => {
+    try {
+      await vote(donorA, true, [donationB]);
+      expect.fail("Should reject donations made by another donor");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.INVALID_VOTE_WEIGHT);
+    }
+  });
+
+  it("should weight each vote by the donor's total donations", async () => {
+    const votePDA = await vote(donorA, true, donationsA);
+    await vote(donorB, false, [donationB]);
+
+    const voteAccount = await program.account.milestoneVote.fetch(votePDA);
+    expect(voteAccount.weight.toNumber()).to.eq(1.5 * LAMPORTS_PER_SOL);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.milestones[0].approveWeight.toNumber()).to.eq(
+      1.5 * LAMPORTS_PER_SOL
+    );
+    expect(campaign.milestones[0].rejectWeight.toNumber()).to.eq(
+      0.5 * LAMPORTS_PER_SOL
+    );
+  });
+
+  it("should not let a donor vote twice", async () => {
+    try {
+      await vote(donorB, true, [donationB]);
+      expect.fail("Should reject a second vote");
+    } catch (error) {
+      expect(error.toString()).to.include("already in use");
+    }
+  });
+
+  it("should not settle the vote while it is open", async () => {
+    try {
+      await program.methods
+        .finalizeMilestoneVote(0)
+        .accounts({
+          caller: donorB.publicKey,
+          campaign: campaignPDA,
+          campaignVault: vaultPDA,
+        })
+        .signers([donorB])
+        .rpc();
+      expect.fail("Should not settle an open vote");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.VOTING_STILL_OPEN);
+    }
+  });
+
+  it("should measure quorum against donors' own gifts, not matches", async () => {
+    const quorumBps = 6_000;
+    const { campaignPDA: matchedPDA, vaultPDA: matchedVault } =
+      await createTestCampaignWithVault(program, authority, {
+        title: "Matched Milestones",
+        targetAmount: 2 * LAMPORTS_PER_SOL,
+        milestones: [
+          {
+            description: "Phase one",
+            amount: { percentage: [5_000] },
+            deadline: new anchor.BN(getFutureTimestamp(60)),
+          },
+        ],
+        voting: {
+          votingPeriod: new anchor.BN(24 * 60 * 60),
+          quorumBps,
+          approvalThresholdBps: 6_000,
+        },
+      });
+
+    // A sponsor matches the campaign's donations 1:1
+    const sponsor = await createAndFundAccount(
+      connection,
+      10 * LAMPORTS_PER_SOL
+    );
+    const [poolPDA] = await findMatchingPoolPDA(sponsor.publicKey, 0, program);
+    const now = getCurrentTimestamp();
+    await program.methods
+      .createMatchingPool({
+        id: new anchor.BN(0),
+        categories: [],
+        campaigns: [matchedPDA],
+        matchRatioBps: 10_000,
+        perDonationCap: new anchor.BN(LAMPORTS_PER_SOL),
+        totalCap: new anchor.BN(LAMPORTS_PER_SOL),
+        startTime: new anchor.BN(now - 60),
+        endTime: new anchor.BN(now + 600),
+      })
+      .accounts({
+        sponsor: sponsor.publicKey,
+        matchingPool: poolPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([sponsor])
+      .rpc();
+
+    const [userPDA] = await findUserPDA(donorA.publicKey, program);
+    const [donationPDA] = await findNextDonationPDA(matchedPDA, program);
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      matchedPDA,
+      donorA.publicKey,
+      program
+    );
+    await program.methods
+      .donate(
+        new anchor.BN(LAMPORTS_PER_SOL),
+        { cryptoWallet: {} },
+        null,
+        null
+      )
+      .accounts({
+        donor: donorA.publicKey,
+        user: userPDA,
+        campaign: matchedPDA,
+        donation: donationPDA,
+        donorPosition: donorPositionPDA,
+        campaignVault: matchedVault,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        { pubkey: poolPDA, isSigner: false, isWritable: true },
+      ])
+      .signers([donorA])
+      .rpc();
+
+    await program.methods
+      .completeMilestone(0, "https://example.com/phase-one", Array(32).fill(1))
+      .accounts({
+        authority: authority.publicKey,
+        campaign: matchedPDA,
+      })
+      .signers([authority])
+      .rpc();
+    const votePDA = await vote(donorA, true, [donationPDA], matchedPDA);
+
+    const campaign = await program.account.campaign.fetch(matchedPDA);
+    expect(campaign.raisedAmount.toNumber()).to.eq(2 * LAMPORTS_PER_SOL);
+    expect(campaign.voteableAmount.toNumber()).to.eq(LAMPORTS_PER_SOL);
+
+    // The only donor voting is enough for quorum, even though their gift is
+    // only half of what was raised
+    const { weight } = await program.account.milestoneVote.fetch(votePDA);
+    expect(weight.toNumber() * 10_000).to.be.at.least(
+      campaign.voteableAmount.toNumber() * quorumBps
+    );
+    expect(weight.toNumber() * 10_000).to.be.below(
+      campaign.raisedAmount.toNumber() * quorumBps
+    );
+  });
+});
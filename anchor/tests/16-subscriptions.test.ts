@@ -0,0 +1,429 @@
+// File: tests/16-subscriptions.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import {
+  setupTest,
+  ERROR_MESSAGES,
+  getCurrentTimestamp,
+  getFutureTimestamp,
+} from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestMint,
+  createTestUser,
+  findNextCampaignPDA,
+  findCampaignVaultPDA,
+  findDonationPDA,
+  findNextDonationPDA,
+  findDonorPositionPDA,
+  findSubscriptionPDA,
+  findUserPDA,
+  fundTokenAccount,
+} from "./utils/helpers";
+
+describe("Subscriptions", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donor: Keypair;
+  let keeper: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+  let otherCampaignPDA: PublicKey;
+  let otherVaultPDA: PublicKey;
+
+  const amount = 0.1 * LAMPORTS_PER_SOL;
+  const day = 24 * 60 * 60;
+
+  const createSubscription = async (
+    id: number,
+    target: { campaign: [PublicKey] } | { organization: [PublicKey] },
+    escrowDeposit: number,
+    maxCount: number | null = null
+  ): Promise<PublicKey> => {
+    const [subscriptionPDA] = await findSubscriptionPDA(
+      donor.publicKey,
+      id,
+      program
+    );
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+
+    await program.methods
+      .createSubscription({
+        id: new anchor.BN(id),
+        target,
+        amount: new anchor.BN(amount),
+        interval: new anchor.BN(30 * day),
+        startAt: null,
+        maxCount,
+        escrowDeposit: new anchor.BN(escrowDeposit),
+      })
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        subscription: subscriptionPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc();
+
+    return subscriptionPDA;
+  };
+
+  const executeSubscription = async (
+    subscriptionPDA: PublicKey,
+    campaign: PublicKey,
+    vault: PublicKey
+  ): Promise<PublicKey> => {
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    const [donationPDA] = await findNextDonationPDA(campaign, program);
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaign,
+      donor.publicKey,
+      program
+    );
+
+    await program.methods
+      .executeSubscription()
+      .accounts({
+        keeper: keeper.publicKey,
+        subscription: subscriptionPDA,
+        user: userPDA,
+        campaign,
+        donation: donationPDA,
+        donorPosition: donorPositionPDA,
+        campaignVault: vault,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([keeper])
+      .rpc();
+
+    return donationPDA;
+  };
+
+  const setStatus = async (
+    subscriptionPDA: PublicKey,
+    status: { active: {} } | { paused: {} }
+  ) => {
+    await program.methods
+      .setSubscriptionStatus(status)
+      .accounts({
+        donor: donor.publicKey,
+        subscription: subscriptionPDA,
+      })
+      .signers([donor])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    keeper = await createAndFundAccount(connection, LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      { title: "Monthly Giving" }
+    ));
+    ({ campaignPDA: otherCampaignPDA, vaultPDA: otherVaultPDA } =
+      await createTestCampaignWithVault(program, authority, {
+        title: "Other Campaign",
+      }));
+  });
+
+  it("should donate from escrow when the subscription is due", async () => {
+    const subscriptionPDA = await createSubscription(
+      0,
+      { campaign: [campaignPDA] },
+      2 * amount,
+      2
+    );
+
+    const vaultBalanceBefore = await connection.getBalance(vaultPDA);
+    const donationPDA = await executeSubscription(
+      subscriptionPDA,
+      campaignPDA,
+      vaultPDA
+    );
+
+    expect(await connection.getBalance(vaultPDA)).to.eq(
+      vaultBalanceBefore + amount
+    );
+
+    const donation = await program.account.donation.fetch(donationPDA);
+    expect(donation.donor.toBase58()).to.eq(donor.publicKey.toBase58());
+    expect(donation.amount.toNumber()).to.eq(amount);
+    expect(donation.status).to.deep.equal({ completed: {} });
+
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    const user = await program.account.user.fetch(userPDA);
+    expect(user.totalDonations.toNumber()).to.eq(amount);
+    expect(user.campaignsSupported).to.eq(1);
+
+    const subscription = await program.account.subscription.fetch(
+      subscriptionPDA
+    );
+    expect(subscription.executedCount).to.eq(1);
+    expect(subscription.escrowBalance.toNumber()).to.eq(amount);
+    expect(subscription.nextDue.toNumber()).to.be.greaterThan(
+      getFutureTimestamp(29)
+    );
+  });
+
+  it("should not execute a subscription before it is due", async () => {
+    const [subscriptionPDA] = await findSubscriptionPDA(
+      donor.publicKey,
+      0,
+      program
+    );
+
+    try {
+      await executeSubscription(subscriptionPDA, campaignPDA, vaultPDA);
+      expect.fail("Should not execute before the next due date");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.SUBSCRIPTION_NOT_DUE);
+    }
+  });
+
+  it("should only donate to the subscription's target", async () => {
+    const subscriptionPDA = await createSubscription(
+      1,
+      { campaign: [campaignPDA] },
+      amount
+    );
+
+    try {
+      await executeSubscription(subscriptionPDA, otherCampaignPDA, otherVaultPDA);
+      expect.fail("Should reject a campaign the donor did not subscribe to");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.INVALID_SUBSCRIPTION_TARGET
+      );
+    }
+  });
+
+  it("should donate to any campaign of a subscribed organization", async () => {
+    const subscriptionPDA = await createSubscription(
+      2,
+      { organization: [authority.publicKey] },
+      amount
+    );
+
+    await executeSubscription(subscriptionPDA, otherCampaignPDA, otherVaultPDA);
+
+    const campaign = await program.account.campaign.fetch(otherCampaignPDA);
+    expect(campaign.raisedAmount.toNumber()).to.eq(amount);
+  });
+
+  it("should let the donor pause and resume a subscription", async () => {
+    const subscriptionPDA = await createSubscription(
+      3,
+      { campaign: [campaignPDA] },
+      amount
+    );
+
+    await setStatus(subscriptionPDA, { paused: {} });
+    try {
+      await executeSubscription(subscriptionPDA, campaignPDA, vaultPDA);
+      expect.fail("Should not execute a paused subscription");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.SUBSCRIPTION_NOT_ACTIVE
+      );
+    }
+
+    await setStatus(subscriptionPDA, { active: {} });
+    await executeSubscription(subscriptionPDA, campaignPDA, vaultPDA);
+
+    const subscription = await program.account.subscription.fetch(
+      subscriptionPDA
+    );
+    expect(subscription.executedCount).to.eq(1);
+  });
+
+  it("should return unspent escrow when the donor cancels", async () => {
+    const [subscriptionPDA] = await findSubscriptionPDA(
+      donor.publicKey,
+      1,
+      program
+    );
+    const subscriptionBalance = await connection.getBalance(subscriptionPDA);
+    const donorBalanceBefore = await connection.getBalance(donor.publicKey);
+
+    await program.methods
+      .cancelSubscription()
+      .accounts({
+        donor: donor.publicKey,
+        subscription: subscriptionPDA,
+      })
+      .signers([donor])
+      .rpc();
+
+    expect(await connection.getAccountInfo(subscriptionPDA)).to.be.null;
+    // The donor pays the transaction fee out of the returned lamports
+    expect(await connection.getBalance(donor.publicKey)).to.be.greaterThan(
+      donorBalanceBefore + subscriptionBalance - 10_000
+    );
+  });
+
+  it("should draw token donations through a delegate approval", async () => {
+    const title = "Token Monthly Giving";
+    const mint = await createTestMint(connection, authority);
+    const donorTokenAccount = await fundTokenAccount(
+      connection,
+      authority,
+      mint,
+      donor.publicKey,
+      10 * amount
+    );
+    const [tokenCampaignPDA] = await findNextCampaignPDA(
+      authority.publicKey,
+      program
+    );
+    const [tokenVaultPDA] = await findCampaignVaultPDA(
+      tokenCampaignPDA,
+      program
+    );
+    const vaultTokenAccount = getAssociatedTokenAddressSync(
+      mint,
+      tokenVaultPDA,
+      true
+    );
+    const [authorityUserPDA] = await findUserPDA(authority.publicKey, program);
+
+    await program.methods
+      .createCampaign({
+        title,
+        description: "Raising in tokens every month",
+        category: { healthcare: {} },
+        organizationName: "Test Organization",
+        targetAmount: new anchor.BN(10 * amount),
+        startDate: new anchor.BN(getCurrentTimestamp() - 60),
+        endDate: new anchor.BN(getFutureTimestamp(30)),
+        imageUrl: "https://example.com/image.jpg",
+        isUrgent: false,
+        allowUnsafeMintExtensions: false,
+        usdTarget: null,
+        fundingMode: { keepItAll: {} },
+        expiryBounty: new anchor.BN(0),
+        milestones: [],
+        voting: null,
+        beneficiaries: [authority.publicKey],
+        approvals: null,
+        donationLimits: { hardCap: null, maxPerDonor: null },
+      })
+      .accounts({
+        authority: authority.publicKey,
+        user: authorityUserPDA,
+        campaign: tokenCampaignPDA,
+        campaignVault: tokenVaultPDA,
+        acceptedMint: mint,
+        vaultTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    const [subscriptionPDA] = await findSubscriptionPDA(
+      donor.publicKey,
+      4,
+      program
+    );
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+
+    await program.methods
+      .createSubscription({
+        id: new anchor.BN(4),
+        target: { campaign: [tokenCampaignPDA] },
+        amount: new anchor.BN(amount),
+        interval: new anchor.BN(30 * day),
+        startAt: null,
+        maxCount: 3,
+        escrowDeposit: new anchor.BN(0),
+      })
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        subscription: subscriptionPDA,
+        mint,
+        donorTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc();
+
+    let tokenAccount = await getAccount(connection, donorTokenAccount);
+    expect(tokenAccount.delegate.toBase58()).to.eq(subscriptionPDA.toBase58());
+    expect(Number(tokenAccount.delegatedAmount)).to.eq(3 * amount);
+
+    const [donationPDA] = await findDonationPDA(tokenCampaignPDA, 0, program);
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      tokenCampaignPDA,
+      donor.publicKey,
+      program
+    );
+    await program.methods
+      .executeSubscription()
+      .accounts({
+        keeper: keeper.publicKey,
+        subscription: subscriptionPDA,
+        user: userPDA,
+        campaign: tokenCampaignPDA,
+        donation: donationPDA,
+        donorPosition: donorPositionPDA,
+        campaignVault: tokenVaultPDA,
+        mint,
+        donorTokenAccount,
+        vaultTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([keeper])
+      .rpc();
+
+    const vault = await getAccount(connection, vaultTokenAccount);
+    expect(Number(vault.amount)).to.eq(amount);
+
+    // Cancelling revokes whatever is left of the approval
+    await program.methods
+      .cancelSubscription()
+      .accounts({
+        donor: donor.publicKey,
+        subscription: subscriptionPDA,
+        donorTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([donor])
+      .rpc();
+
+    tokenAccount = await getAccount(connection, donorTokenAccount);
+    expect(tokenAccount.delegate).to.be.null;
+  });
+});
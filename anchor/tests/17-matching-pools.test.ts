@@ -0,0 +1,248 @@
+// File: tests/17-matching-pools.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import {
+  setupTest,
+  ERROR_MESSAGES,
+  getCurrentTimestamp,
+  sleep,
+} from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  findNextDonationPDA,
+  findDonorPositionPDA,
+  findMatchingPoolPDA,
+  findUserPDA,
+} from "./utils/helpers";
+
+describe("Matching Pools", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let sponsor: Keypair;
+  let authority: Keypair;
+  let donor: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+  let poolPDA: PublicKey;
+  let firstDonation: PublicKey;
+
+  const perDonationCap = 0.5 * LAMPORTS_PER_SOL;
+  const totalCap = 0.8 * LAMPORTS_PER_SOL;
+
+  const donateWithMatch = async (
+    campaign: PublicKey,
+    vault: PublicKey,
+    amount: number
+  ): Promise<PublicKey> => {
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    const [donationPDA] = await findNextDonationPDA(campaign, program);
+
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaign,
+      donor.publicKey,
+      program
+    );
+
+    await program.methods
+      .donate(new anchor.BN(amount), { cryptoWallet: {} }, null, null)
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        campaign,
+        donation: donationPDA,
+        donorPosition: donorPositionPDA,
+        campaignVault: vault,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        { pubkey: poolPDA, isSigner: false, isWritable: true },
+      ])
+      .signers([donor])
+      .rpc();
+
+    return donationPDA;
+  };
+
+  const reclaim = async () => {
+    await program.methods
+      .reclaimMatchingPool()
+      .accounts({
+        sponsor: sponsor.publicKey,
+        matchingPool: poolPDA,
+      })
+      .signers([sponsor])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    sponsor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    authority = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      { title: "Matched Clinic", category: { healthcare: {} } }
+    ));
+  });
+
+  it("should create a pool funded with its total cap", async () => {
+    [poolPDA] = await findMatchingPoolPDA(sponsor.publicKey, 0, program);
+    const now = getCurrentTimestamp();
+
+    await program.methods
+      .createMatchingPool({
+        id: new anchor.BN(0),
+        categories: [{ healthcare: {} }],
+        campaigns: [],
+        matchRatioBps: 10_000,
+        perDonationCap: new anchor.BN(perDonationCap),
+        totalCap: new anchor.BN(totalCap),
+        startTime: new anchor.BN(now - 60),
+        endTime: new anchor.BN(now + 15),
+      })
+      .accounts({
+        sponsor: sponsor.publicKey,
+        matchingPool: poolPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([sponsor])
+      .rpc();
+
+    const pool = await program.account.matchingPool.fetch(poolPDA);
+    expect(pool.totalCap.toNumber()).to.eq(totalCap);
+    expect(pool.matchedTotal.toNumber()).to.eq(0);
+  });
+
+  it("should match a donation up to the per-donation cap", async () => {
+    const vaultBalanceBefore = await connection.getBalance(vaultPDA);
+    firstDonation = await donateWithMatch(campaignPDA, vaultPDA, LAMPORTS_PER_SOL);
+
+    expect(await connection.getBalance(vaultPDA)).to.eq(
+      vaultBalanceBefore + LAMPORTS_PER_SOL + perDonationCap
+    );
+
+    const donation = await program.account.donation.fetch(firstDonation);
+    expect(donation.amount.toNumber()).to.eq(LAMPORTS_PER_SOL);
+    expect(donation.matchedAmount.toNumber()).to.eq(perDonationCap);
+    expect(donation.matchingPool.toBase58()).to.eq(poolPDA.toBase58());
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.raisedAmount.toNumber()).to.eq(
+      LAMPORTS_PER_SOL + perDonationCap
+    );
+  });
+
+  it("should stop matching once the total cap is used up", async () => {
+    const donationPDA = await donateWithMatch(
+      campaignPDA,
+      vaultPDA,
+      0.4 * LAMPORTS_PER_SOL
+    );
+
+    const donation = await program.account.donation.fetch(donationPDA);
+    expect(donation.matchedAmount.toNumber()).to.eq(totalCap - perDonationCap);
+
+    const pool = await program.account.matchingPool.fetch(poolPDA);
+    expect(pool.matchedTotal.toNumber()).to.eq(totalCap);
+    expect(pool.matchedCount).to.eq(2);
+  });
+
+  it("should reject donations to ineligible campaigns", async () => {
+    const { campaignPDA: schoolPDA, vaultPDA: schoolVaultPDA } =
+      await createTestCampaignWithVault(program, authority, {
+        title: "Unmatched School",
+        category: { education: {} },
+      });
+
+    try {
+      await donateWithMatch(schoolPDA, schoolVaultPDA, LAMPORTS_PER_SOL);
+      expect.fail("Should not match a campaign outside the pool's rules");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.MATCHING_POOL_NOT_ELIGIBLE
+      );
+    }
+  });
+
+  it("should not let the sponsor reclaim funds during the window", async () => {
+    try {
+      await reclaim();
+      expect.fail("Should not reclaim before the window ends");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.MATCHING_POOL_ACTIVE);
+    }
+  });
+
+  it("should return the matched share of a refund to the pool", async () => {
+    await program.methods
+      .cancelCampaign()
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+        campaignVault: vaultPDA,
+      })
+      .signers([authority])
+      .rpc();
+
+    const poolBalanceBefore = await connection.getBalance(poolPDA);
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      donor.publicKey,
+      program
+    );
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    await program.methods
+      .claimRefund()
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        donation: firstDonation,
+        donorPosition: donorPositionPDA,
+        campaignVault: vaultPDA,
+        matchingPool: poolPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc();
+
+    expect(await connection.getBalance(poolPDA)).to.eq(
+      poolBalanceBefore + perDonationCap
+    );
+    const pool = await program.account.matchingPool.fetch(poolPDA);
+    expect(pool.matchedTotal.toNumber()).to.eq(totalCap - perDonationCap);
+  });
+
+  it("should let the sponsor reclaim what is left after the window", async () => {
+    await sleep(15000);
+
+    const sponsorBalanceBefore = await connection.getBalance(sponsor.publicKey);
+    await reclaim();
+
+    expect(await connection.getBalance(sponsor.publicKey)).to.be.greaterThan(
+      sponsorBalanceBefore + perDonationCap - 10_000
+    );
+    const pool = await program.account.matchingPool.fetch(poolPDA);
+    expect(pool.totalCap.toNumber()).to.eq(pool.matchedTotal.toNumber());
+  });
+});
@@ -0,0 +1,452 @@
+// File: tests/18-quadratic-funding.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import {
+  setupTest,
+  ERROR_MESSAGES,
+  getCurrentTimestamp,
+  sleep,
+} from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  makeTestDonation,
+  findDonorPositionPDA,
+  findFundingRoundPDA,
+  findRoundContributionPDA,
+  findRoundParticipantPDA,
+  findUserPDA,
+} from "./utils/helpers";
+
+describe("Quadratic Funding Rounds", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let admin: Keypair;
+  let authority: Keypair;
+  let smallDonors: Keypair[];
+  let whale: Keypair;
+
+  let roundPDA: PublicKey;
+  const campaigns: { campaignPDA: PublicKey; vaultPDA: PublicKey }[] = [];
+  const donations: { donor: Keypair; campaign: number; donation: PublicKey }[] =
+    [];
+
+  const pot = LAMPORTS_PER_SOL;
+  const perDonorCap = LAMPORTS_PER_SOL;
+  const smallDonation = 0.25 * LAMPORTS_PER_SOL;
+
+  const tally = async (donor: Keypair, campaign: number, donation: PublicKey) => {
+    const { campaignPDA } = campaigns[campaign];
+    const [participantPDA] = await findRoundParticipantPDA(
+      roundPDA,
+      campaignPDA,
+      program
+    );
+    const [contributionPDA] = await findRoundContributionPDA(
+      roundPDA,
+      campaignPDA,
+      donor.publicKey,
+      program
+    );
+
+    await program.methods
+      .tallyRoundContribution()
+      .accounts({
+        payer: admin.publicKey,
+        fundingRound: roundPDA,
+        roundParticipant: participantPDA,
+        donation,
+        roundContribution: contributionPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+  };
+
+  const returnMatch = async (campaign: number) => {
+    const { campaignPDA, vaultPDA } = campaigns[campaign];
+    const [participantPDA] = await findRoundParticipantPDA(
+      roundPDA,
+      campaignPDA,
+      program
+    );
+
+    await program.methods
+      .returnRoundMatch()
+      .accounts({
+        caller: authority.publicKey,
+        fundingRound: roundPDA,
+        admin: admin.publicKey,
+        roundParticipant: participantPDA,
+        campaign: campaignPDA,
+        campaignVault: vaultPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+  };
+
+  const register = async (
+    campaignPDA: PublicKey,
+    vaultPDA: PublicKey,
+    roundAdmin: Keypair = admin
+  ) => {
+    const [participantPDA] = await findRoundParticipantPDA(
+      roundPDA,
+      campaignPDA,
+      program
+    );
+
+    await program.methods
+      .registerRoundCampaign()
+      .accounts({
+        authority: authority.publicKey,
+        admin: roundAdmin.publicKey,
+        fundingRound: roundPDA,
+        campaign: campaignPDA,
+        campaignVault: vaultPDA,
+        roundParticipant: participantPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority, roundAdmin])
+      .rpc();
+  };
+
+  const finalize = async () => {
+    const remainingAccounts = [];
+    for (const { campaignPDA, vaultPDA } of campaigns) {
+      const [participantPDA] = await findRoundParticipantPDA(
+        roundPDA,
+        campaignPDA,
+        program
+      );
+      remainingAccounts.push(
+        { pubkey: participantPDA, isSigner: false, isWritable: true },
+        { pubkey: campaignPDA, isSigner: false, isWritable: true },
+        { pubkey: vaultPDA, isSigner: false, isWritable: true }
+      );
+    }
+
+    await program.methods
+      .finalizeRound()
+      .accounts({
+        caller: admin.publicKey,
+        fundingRound: roundPDA,
+        admin: admin.publicKey,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([admin])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    admin = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    authority = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    whale = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    smallDonors = [];
+    for (let i = 0; i < 3; i++) {
+      smallDonors.push(await createAndFundAccount(connection, LAMPORTS_PER_SOL));
+    }
+
+    await createTestUser(program, authority);
+    await createTestUser(program, whale);
+    for (const donor of smallDonors) {
+      await createTestUser(program, donor);
+    }
+
+    campaigns.push(
+      await createTestCampaignWithVault(program, authority, {
+        title: "Community Garden",
+      }),
+      await createTestCampaignWithVault(program, authority, {
+        title: "Whale Favourite",
+      })
+    );
+  });
+
+  it("should create a round and register campaigns", async () => {
+    [roundPDA] = await findFundingRoundPDA(admin.publicKey, 0, program);
+    const now = getCurrentTimestamp();
+
+    await program.methods
+      .createFundingRound({
+        id: new anchor.BN(0),
+        pot: new anchor.BN(pot),
+        startTime: new anchor.BN(now - 60),
+        endTime: new anchor.BN(now + 30),
+        maxContributionPerDonor: new anchor.BN(perDonorCap),
+      })
+      .accounts({
+        admin: admin.publicKey,
+        fundingRound: roundPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    for (const { campaignPDA, vaultPDA } of campaigns) {
+      await register(campaignPDA, vaultPDA);
+    }
+
+    const round = await program.account.fundingRound.fetch(roundPDA);
+    expect(round.campaignCount).to.eq(2);
+  });
+
+  it("should only register campaigns the admin signs off on", async () => {
+    const stranger = await createAndFundAccount(connection);
+    const { campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      { title: "Uninvited" }
+    );
+
+    try {
+      await register(campaignPDA, vaultPDA, stranger);
+      expect.fail("Should not register a campaign without the admin");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.INVALID_AUTHORITY);
+    }
+  });
+
+  it("should let the admin remove a campaign for good", async () => {
+    const { campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      { title: "Withdrawn Entry" }
+    );
+    await register(campaignPDA, vaultPDA);
+    const [participantPDA] = await findRoundParticipantPDA(
+      roundPDA,
+      campaignPDA,
+      program
+    );
+
+    await program.methods
+      .removeRoundCampaign()
+      .accounts({
+        admin: admin.publicKey,
+        fundingRound: roundPDA,
+        roundParticipant: participantPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    const round = await program.account.fundingRound.fetch(roundPDA);
+    expect(round.campaignCount).to.eq(2);
+    const participant = await program.account.roundParticipant.fetch(
+      participantPDA
+    );
+    expect(participant.removed).to.be.true;
+
+    try {
+      await register(campaignPDA, vaultPDA);
+      expect.fail("Should not register a removed campaign again");
+    } catch (error) {
+      expect(error.toString()).to.include("already in use");
+    }
+  });
+
+  it("should tally contributions with a per-donor cap", async () => {
+    const { campaignPDA: gardenPDA, vaultPDA: gardenVault } = campaigns[0];
+    const { campaignPDA: whalePDA, vaultPDA: whaleVault } = campaigns[1];
+
+    for (const donor of smallDonors) {
+      donations.push({
+        donor,
+        campaign: 0,
+        donation: await makeTestDonation(
+          program,
+          donor,
+          gardenPDA,
+          smallDonation,
+          gardenVault
+        ),
+      });
+    }
+    donations.push({
+      donor: whale,
+      campaign: 1,
+      donation: await makeTestDonation(
+        program,
+        whale,
+        whalePDA,
+        2 * LAMPORTS_PER_SOL,
+        whaleVault
+      ),
+    });
+
+    for (const { donor, campaign, donation } of donations) {
+      await tally(donor, campaign, donation);
+    }
+
+    const [whaleParticipant] = await findRoundParticipantPDA(
+      roundPDA,
+      whalePDA,
+      program
+    );
+    const participant = await program.account.roundParticipant.fetch(
+      whaleParticipant
+    );
+    expect(participant.contributions.toNumber()).to.eq(perDonorCap);
+    expect(participant.contributors).to.eq(1);
+  });
+
+  it("should not tally the same donation twice", async () => {
+    const { donor, campaign, donation } = donations[0];
+    try {
+      await tally(donor, campaign, donation);
+      expect.fail("Should reject a donation that was already tallied");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.DONATION_ALREADY_TALLIED
+      );
+    }
+  });
+
+  it("should not finalize before the round ends", async () => {
+    try {
+      await finalize();
+      expect.fail("Should not finalize an open round");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.ROUND_NOT_ENDED);
+    }
+  });
+
+  it("should split the pot quadratically between campaigns", async () => {
+    await sleep(30000);
+
+    const balancesBefore = await Promise.all(
+      campaigns.map(({ vaultPDA }) => connection.getBalance(vaultPDA))
+    );
+    await finalize();
+
+    // Many small donors outweigh a single large one
+    const sqrt = (value: number) => BigInt(Math.floor(Math.sqrt(value)));
+    const scores = [
+      (sqrt(smallDonation) * BigInt(smallDonors.length)) ** 2n,
+      sqrt(perDonorCap) ** 2n,
+    ];
+    const totalScore = scores[0] + scores[1];
+
+    for (let i = 0; i < campaigns.length; i++) {
+      const expected = Number((BigInt(pot) * scores[i]) / totalScore);
+      const balance = await connection.getBalance(campaigns[i].vaultPDA);
+      expect(balance - balancesBefore[i]).to.eq(expected);
+    }
+
+    const round = await program.account.fundingRound.fetch(roundPDA);
+    expect(round.finalized).to.be.true;
+    expect(round.distributed.toNumber()).to.be.greaterThan(pot * 0.69);
+  });
+
+  it("should count the match towards the campaign's raised amount", async () => {
+    const { campaignPDA } = campaigns[0];
+    const [participantPDA] = await findRoundParticipantPDA(
+      roundPDA,
+      campaignPDA,
+      program
+    );
+    const participant = await program.account.roundParticipant.fetch(
+      participantPDA
+    );
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+
+    expect(participant.matchAmount.toNumber()).to.be.greaterThan(0);
+    expect(campaign.raisedAmount.toNumber()).to.eq(
+      smallDonation * smallDonors.length + participant.matchAmount.toNumber()
+    );
+  });
+
+  it("should keep the match of a campaign that is not refunding", async () => {
+    try {
+      await returnMatch(0);
+      expect.fail("Should not return the match of an active campaign");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.ROUND_MATCH_NOT_RETURNABLE
+      );
+    }
+  });
+
+  it("should return a cancelled campaign's match to the admin", async () => {
+    const { campaignPDA, vaultPDA } = campaigns[1];
+    const [participantPDA] = await findRoundParticipantPDA(
+      roundPDA,
+      campaignPDA,
+      program
+    );
+    const { matchAmount } = await program.account.roundParticipant.fetch(
+      participantPDA
+    );
+
+    await program.methods
+      .cancelCampaign()
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+        campaignVault: vaultPDA,
+      })
+      .signers([authority])
+      .rpc();
+
+    const adminBefore = await connection.getBalance(admin.publicKey);
+    await returnMatch(1);
+    expect(await connection.getBalance(admin.publicKey)).to.eq(
+      adminBefore + matchAmount.toNumber()
+    );
+
+    const participant = await program.account.roundParticipant.fetch(
+      participantPDA
+    );
+    expect(participant.matchAmount.toNumber()).to.eq(0);
+
+    // The donor gets back exactly what they gave, not a share of the match
+    const { donation } = donations.find(({ campaign }) => campaign === 1);
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      whale.publicKey,
+      program
+    );
+    const [userPDA] = await findUserPDA(whale.publicKey, program);
+    const vaultBefore = await connection.getBalance(vaultPDA);
+    await program.methods
+      .claimRefund()
+      .accounts({
+        donor: whale.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        donation,
+        donorPosition: donorPositionPDA,
+        campaignVault: vaultPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([whale])
+      .rpc();
+    expect(vaultBefore - (await connection.getBalance(vaultPDA))).to.eq(
+      2 * LAMPORTS_PER_SOL
+    );
+  });
+
+  it("should not return the same match twice", async () => {
+    try {
+      await returnMatch(1);
+      expect.fail("Should not return a match that was already returned");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.NO_ROUND_MATCH);
+    }
+  });
+});
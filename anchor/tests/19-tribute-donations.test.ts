@@ -0,0 +1,170 @@
+// File: tests/19-tribute-donations.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import { setupTest, ERROR_MESSAGES } from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  fetchCpiEvents,
+  findNextDonationPDA,
+  findDonorPositionPDA,
+  findUserPDA,
+} from "./utils/helpers";
+
+describe("Tribute Donations", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donor: Keypair;
+  let honoree: Keypair;
+  let bystander: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+
+  const donateInHonorOf = async (
+    tribute: { honoree: PublicKey; dedication: string },
+    honoreeUser: PublicKey | null
+  ) => {
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    const [donationPDA] = await findNextDonationPDA(campaignPDA, program);
+
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      donor.publicKey,
+      program
+    );
+
+    const signature = await program.methods
+      .donate(
+        new anchor.BN(LAMPORTS_PER_SOL),
+        { cryptoWallet: {} },
+        tribute,
+        null
+      )
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        donation: donationPDA,
+        donorPosition: donorPositionPDA,
+        campaignVault: vaultPDA,
+        honoreeUser,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc({ commitment: "confirmed" });
+
+    return { donationPDA, events: await fetchCpiEvents(program, signature) };
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    honoree = await createAndFundAccount(connection);
+    bystander = await createAndFundAccount(connection);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+    await createTestUser(program, honoree, "Grandma");
+    await createTestUser(program, bystander);
+
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      { title: "Memorial Library" }
+    ));
+  });
+
+  it("should record the dedication and credit the donor by default", async () => {
+    const { donationPDA, events } = await donateInHonorOf(
+      { honoree: honoree.publicKey, dedication: "For Grandma's 90th" },
+      null
+    );
+
+    const donation = await program.account.donation.fetch(donationPDA);
+    expect(donation.honoree.toBase58()).to.eq(honoree.publicKey.toBase58());
+    expect(donation.dedication).to.eq("For Grandma's 90th");
+
+    const [donorUserPDA] = await findUserPDA(donor.publicKey, program);
+    const donorUser = await program.account.user.fetch(donorUserPDA);
+    expect(donorUser.totalDonations.toNumber()).to.eq(LAMPORTS_PER_SOL);
+
+    const tribute = events.find((event) => event.name === "tributeDonation");
+    expect(tribute, "tributeDonation event").to.not.be.undefined;
+    expect(tribute.data.honoree.toBase58()).to.eq(
+      honoree.publicKey.toBase58()
+    );
+    expect(tribute.data.creditedUser.toBase58()).to.eq(
+      donorUserPDA.toBase58()
+    );
+  });
+
+  it("should credit the honoree's profile when it is passed", async () => {
+    const [honoreeUserPDA] = await findUserPDA(honoree.publicKey, program);
+    await donateInHonorOf(
+      { honoree: honoree.publicKey, dedication: "In loving memory" },
+      honoreeUserPDA
+    );
+
+    const honoreeUser = await program.account.user.fetch(honoreeUserPDA);
+    expect(honoreeUser.totalDonations.toNumber()).to.eq(LAMPORTS_PER_SOL);
+
+    // The campaign is one the donor supports, whoever the gift is credited to
+    expect(honoreeUser.campaignsSupported).to.eq(0);
+    const [donorUserPDA] = await findUserPDA(donor.publicKey, program);
+    const donorUser = await program.account.user.fetch(donorUserPDA);
+    expect(donorUser.totalDonations.toNumber()).to.eq(LAMPORTS_PER_SOL);
+    expect(donorUser.campaignsSupported).to.eq(1);
+  });
+
+  it("should reject a profile that doesn't belong to the honoree", async () => {
+    const [bystanderUserPDA] = await findUserPDA(bystander.publicKey, program);
+    try {
+      await donateInHonorOf(
+        { honoree: honoree.publicKey, dedication: "Wrong profile" },
+        bystanderUserPDA
+      );
+      expect.fail("Should not credit someone other than the honoree");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.INVALID_HONOREE);
+    }
+  });
+
+  it("should store a dedication at the length limit", async () => {
+    const dedication = "a".repeat(200);
+    const { donationPDA } = await donateInHonorOf(
+      { honoree: honoree.publicKey, dedication },
+      null
+    );
+
+    const donation = await program.account.donation.fetch(donationPDA);
+    expect(donation.dedication).to.eq(dedication);
+  });
+
+  it("should reject dedications over the length limit", async () => {
+    try {
+      await donateInHonorOf(
+        { honoree: honoree.publicKey, dedication: "a".repeat(201) },
+        null
+      );
+      expect.fail("Should reject an over-long dedication");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.DEDICATION_TOO_LONG);
+    }
+  });
+});
@@ -0,0 +1,147 @@
+// File: tests/20-anonymous-donations.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { createHash, randomBytes } from "crypto";
+import { Ripple } from "../target/types/ripple";
+import { setupTest, ERROR_MESSAGES } from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  fetchCpiEvents,
+  findDonorPositionPDA,
+  findNextDonationPDA,
+  findUserPDA,
+} from "./utils/helpers";
+
+describe("Anonymous Donations", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donor: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+  let donationPDA: PublicKey;
+
+  const salt = randomBytes(32);
+
+  const commitTo = (donorKey: PublicKey, salt: Buffer) =>
+    createHash("sha256").update(donorKey.toBuffer()).update(salt).digest();
+
+  const reveal = async (salt: Buffer) => {
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      donor.publicKey,
+      program
+    );
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+
+    const signature = await program.methods
+      .revealDonation([...salt])
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        donation: donationPDA,
+        campaign: campaignPDA,
+        donorPosition: donorPositionPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc({ commitment: "confirmed" });
+
+    return await fetchCpiEvents(program, signature);
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      { title: "Quiet Giving" }
+    ));
+  });
+
+  it("should store a commitment instead of the donor", async () => {
+    const commitment = commitTo(donor.publicKey, salt);
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    [donationPDA] = await findNextDonationPDA(campaignPDA, program);
+
+    const signature = await program.methods
+      .donate(
+        new anchor.BN(LAMPORTS_PER_SOL),
+        { cryptoWallet: {} },
+        null,
+        [...commitment]
+      )
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        donation: donationPDA,
+        donorPosition: null,
+        campaignVault: vaultPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc({ commitment: "confirmed" });
+
+    const donation = await program.account.donation.fetch(donationPDA);
+    expect(donation.donor.equals(PublicKey.default)).to.be.true;
+    expect(Buffer.from(donation.donorCommitment).equals(commitment)).to.be
+      .true;
+
+    // The gift counts towards the campaign but not the donor's public profile
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.raisedAmount.toNumber()).to.eq(LAMPORTS_PER_SOL);
+    const user = await program.account.user.fetch(userPDA);
+    expect(user.totalDonations.toNumber()).to.eq(0);
+    expect(user.campaignsSupported).to.eq(0);
+
+    const events = await fetchCpiEvents(program, signature);
+    const received = events.find((event) => event.name === "donationReceived");
+    expect(received.data.donor.equals(PublicKey.default)).to.be.true;
+  });
+
+  it("should reject a salt that doesn't match the commitment", async () => {
+    try {
+      await reveal(randomBytes(32));
+      expect.fail("Should not reveal with the wrong salt");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.INVALID_COMMITMENT);
+    }
+  });
+
+  it("should attach the donation to the donor once the salt is revealed", async () => {
+    const events = await reveal(salt);
+
+    const donation = await program.account.donation.fetch(donationPDA);
+    expect(donation.donor.toBase58()).to.eq(donor.publicKey.toBase58());
+    expect(donation.donorCommitment).to.be.null;
+
+    const revealed = events.find((event) => event.name === "donationRevealed");
+    expect(revealed, "donationRevealed event").to.not.be.undefined;
+    expect(revealed.data.donor.toBase58()).to.eq(donor.publicKey.toBase58());
+
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    const user = await program.account.user.fetch(userPDA);
+    expect(user.campaignsSupported).to.eq(1);
+  });
+});
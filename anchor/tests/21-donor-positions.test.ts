@@ -0,0 +1,160 @@
+// File: tests/21-donor-positions.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import { setupTest } from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  makeTestDonation,
+  findDonorPositionPDA,
+  findUserPDA,
+} from "./utils/helpers";
+
+describe("Donor Positions", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let regular: Keypair;
+  let newcomer: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+  const regularDonations: PublicKey[] = [];
+
+  const claimRefund = async (donor: Keypair, donationPDA: PublicKey) => {
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      donor.publicKey,
+      program
+    );
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+
+    await program.methods
+      .claimRefund()
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        donation: donationPDA,
+        donorPosition: donorPositionPDA,
+        campaignVault: vaultPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection);
+    regular = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    newcomer = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, regular);
+    await createTestUser(program, newcomer);
+
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      { title: "Neighbourhood Pantry" }
+    ));
+  });
+
+  it("should count a repeat donor once", async () => {
+    for (const amount of [LAMPORTS_PER_SOL, 0.5 * LAMPORTS_PER_SOL]) {
+      regularDonations.push(
+        await makeTestDonation(program, regular, campaignPDA, amount, vaultPDA)
+      );
+    }
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.donorsCount).to.eq(1);
+    expect(campaign.donationCount.toNumber()).to.eq(2);
+
+    const [userPDA] = await findUserPDA(regular.publicKey, program);
+    const user = await program.account.user.fetch(userPDA);
+    expect(user.campaignsSupported).to.eq(1);
+  });
+
+  it("should keep the donor's running totals", async () => {
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      regular.publicKey,
+      program
+    );
+    const position = await program.account.donorPosition.fetch(
+      donorPositionPDA
+    );
+
+    expect(position.campaign.toBase58()).to.eq(campaignPDA.toBase58());
+    expect(position.donor.toBase58()).to.eq(regular.publicKey.toBase58());
+    expect(position.totalAmount.toNumber()).to.eq(1.5 * LAMPORTS_PER_SOL);
+    expect(position.donationCount).to.eq(2);
+    expect(position.lastDonationAt.toNumber()).to.be.at.least(
+      position.firstDonationAt.toNumber()
+    );
+  });
+
+  it("should count each new donor", async () => {
+    await makeTestDonation(
+      program,
+      newcomer,
+      campaignPDA,
+      LAMPORTS_PER_SOL,
+      vaultPDA
+    );
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.donorsCount).to.eq(2);
+    expect(campaign.donationCount.toNumber()).to.eq(3);
+  });
+
+  it("should only drop a donor once all their gifts are refunded", async () => {
+    await program.methods
+      .cancelCampaign()
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+        campaignVault: vaultPDA,
+      })
+      .signers([authority])
+      .rpc();
+
+    await claimRefund(regular, regularDonations[0]);
+    let campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.donorsCount).to.eq(2);
+
+    await claimRefund(regular, regularDonations[1]);
+    campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.donorsCount).to.eq(1);
+
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      regular.publicKey,
+      program
+    );
+    const position = await program.account.donorPosition.fetch(
+      donorPositionPDA
+    );
+    expect(position.donationCount).to.eq(0);
+    expect(position.totalAmount.toNumber()).to.eq(0);
+
+    const [userPDA] = await findUserPDA(regular.publicKey, program);
+    const user = await program.account.user.fetch(userPDA);
+    expect(user.campaignsSupported).to.eq(0);
+  });
+});
@@ -0,0 +1,145 @@
+// File: tests/22-scheduled-campaigns.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import {
+  setupTest,
+  ERROR_MESSAGES,
+  getCurrentTimestamp,
+  getFutureTimestamp,
+  sleep,
+} from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  makeTestDonation,
+} from "./utils/helpers";
+
+describe("Scheduled Campaigns", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donor: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+
+  const day = 24 * 60 * 60;
+
+  const reschedule = async (startDate: number) => {
+    await program.methods
+      .updateCampaign({
+        title: null,
+        description: null,
+        imageUrl: null,
+        startDate: new anchor.BN(startDate),
+        endDate: null,
+        status: null,
+        isUrgent: null,
+        donationLimits: null,
+      })
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+  });
+
+  it("should schedule a campaign that starts in the future", async () => {
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      { title: "Winter Coat Drive", startDate: getCurrentTimestamp() + 60 }
+    ));
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.status).to.deep.equal({ scheduled: {} });
+  });
+
+  it("should reject donations before the start date", async () => {
+    try {
+      await makeTestDonation(
+        program,
+        donor,
+        campaignPDA,
+        LAMPORTS_PER_SOL,
+        vaultPDA
+      );
+      expect.fail("Should not accept donations before the campaign starts");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.CAMPAIGN_NOT_STARTED);
+    }
+  });
+
+  it("should let the organizer move the start date", async () => {
+    const startDate = getCurrentTimestamp() + 8;
+    await reschedule(startDate);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.startDate.toNumber()).to.eq(startDate);
+    expect(campaign.status).to.deep.equal({ scheduled: {} });
+  });
+
+  it("should reject start dates too far in the past or future", async () => {
+    for (const startDate of [
+      getCurrentTimestamp() - 2 * day,
+      getFutureTimestamp(181),
+    ]) {
+      try {
+        await createTestCampaignWithVault(program, authority, {
+          title: "Out Of Range",
+          startDate,
+          endDate: startDate + 30 * day,
+        });
+        expect.fail("Should reject an out-of-range start date");
+      } catch (error) {
+        expect(error.toString()).to.include(ERROR_MESSAGES.INVALID_START_DATE);
+      }
+    }
+  });
+
+  it("should open for donations once the start date arrives", async () => {
+    await sleep(10000);
+
+    await makeTestDonation(
+      program,
+      donor,
+      campaignPDA,
+      LAMPORTS_PER_SOL,
+      vaultPDA
+    );
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.status).to.deep.equal({ active: {} });
+    expect(campaign.raisedAmount.toNumber()).to.eq(LAMPORTS_PER_SOL);
+  });
+
+  it("should not move the start date once the campaign has started", async () => {
+    try {
+      await reschedule(getCurrentTimestamp() + 60);
+      expect.fail("Should not reschedule a started campaign");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.CAMPAIGN_ALREADY_STARTED
+      );
+    }
+  });
+});
@@ -0,0 +1,247 @@
+// File: tests/23-pause-resume.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import {
+  setupTest,
+  ERROR_MESSAGES,
+  getCurrentTimestamp,
+  sleep,
+} from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  fetchCpiEvents,
+  findDonorPositionPDA,
+  findUserPDA,
+  makeTestDonation,
+} from "./utils/helpers";
+
+describe("Pause and Resume", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donor: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+
+  const pause = async (reason: string, campaign: PublicKey = campaignPDA) => {
+    const signature = await program.methods
+      .pauseCampaign(reason)
+      .accounts({
+        authority: authority.publicKey,
+        campaign,
+      })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+
+    return await fetchCpiEvents(program, signature);
+  };
+
+  const resume = async (
+    extendEndDate: boolean,
+    campaign: PublicKey = campaignPDA
+  ) => {
+    const signature = await program.methods
+      .resumeCampaign(extendEndDate)
+      .accounts({
+        authority: authority.publicKey,
+        campaign,
+      })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+
+    return await fetchCpiEvents(program, signature);
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      { title: "Flood Relief" }
+    ));
+  });
+
+  it("should pause a campaign and record the reason", async () => {
+    const events = await pause("Waiting on beneficiary paperwork");
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.status).to.deep.equal({ paused: {} });
+    expect(campaign.pauseCount).to.eq(1);
+    expect(campaign.pausedAt).to.not.be.null;
+
+    const paused = events.find((event) => event.name === "campaignPaused");
+    expect(paused, "campaignPaused event").to.not.be.undefined;
+    expect(paused.data.reason).to.eq("Waiting on beneficiary paperwork");
+  });
+
+  it("should reject donations while paused", async () => {
+    try {
+      await makeTestDonation(
+        program,
+        donor,
+        campaignPDA,
+        LAMPORTS_PER_SOL,
+        vaultPDA
+      );
+      expect.fail("Should not accept donations while paused");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.CAMPAIGN_PAUSED);
+    }
+  });
+
+  it("should extend the end date by the paused time on resume", async () => {
+    await sleep(2000);
+    const before = await program.account.campaign.fetch(campaignPDA);
+
+    const events = await resume(true);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.status).to.deep.equal({ active: {} });
+    expect(campaign.pausedAt).to.be.null;
+    expect(campaign.totalPausedDuration.toNumber()).to.be.greaterThan(0);
+    expect(campaign.endDate.toNumber()).to.eq(
+      before.endDate.toNumber() + campaign.totalPausedDuration.toNumber()
+    );
+
+    const resumed = events.find((event) => event.name === "campaignResumed");
+    expect(resumed.data.pausedDuration.toNumber()).to.eq(
+      campaign.totalPausedDuration.toNumber()
+    );
+  });
+
+  it("should accept donations again once resumed", async () => {
+    await makeTestDonation(
+      program,
+      donor,
+      campaignPDA,
+      LAMPORTS_PER_SOL,
+      vaultPDA
+    );
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.raisedAmount.toNumber()).to.eq(LAMPORTS_PER_SOL);
+  });
+
+  it("should keep the end date when not asked to extend it", async () => {
+    await pause("Media enquiry");
+    const before = await program.account.campaign.fetch(campaignPDA);
+
+    await resume(false);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.endDate.toNumber()).to.eq(before.endDate.toNumber());
+    expect(campaign.pauseCount).to.eq(2);
+  });
+
+  it("should not resume a campaign that isn't paused", async () => {
+    try {
+      await resume(false);
+      expect.fail("Should not resume an active campaign");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.CAMPAIGN_NOT_PAUSED);
+    }
+  });
+
+  it("should pause a scheduled campaign once its start date passes", async () => {
+    const { campaignPDA: scheduledPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      { title: "Spring Planting", startDate: getCurrentTimestamp() + 5 }
+    );
+
+    try {
+      await pause("Not open yet", scheduledPDA);
+      expect.fail("Should not pause a campaign before it starts");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.CAMPAIGN_NOT_ACTIVE);
+    }
+
+    await sleep(7000);
+    await pause("Supplier delay", scheduledPDA);
+
+    const campaign = await program.account.campaign.fetch(scheduledPDA);
+    expect(campaign.status).to.deep.equal({ paused: {} });
+    expect(campaign.pauseCount).to.eq(1);
+  });
+
+  it("should hold refunds while a campaign is paused past its end date", async () => {
+    const day = 24 * 60 * 60;
+    const now = getCurrentTimestamp();
+    const { campaignPDA: shortPDA, vaultPDA: shortVaultPDA } =
+      await createTestCampaignWithVault(program, authority, {
+        title: "Well Repairs",
+        targetAmount: 2 * LAMPORTS_PER_SOL,
+        startDate: now - day + 4,
+        endDate: now + 4,
+        fundingMode: { allOrNothing: {} },
+      });
+    const donationPDA = await makeTestDonation(
+      program,
+      donor,
+      shortPDA,
+      LAMPORTS_PER_SOL,
+      shortVaultPDA
+    );
+
+    await pause("Auditing the contractor", shortPDA);
+    await sleep(6000);
+
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      shortPDA,
+      donor.publicKey,
+      program
+    );
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    try {
+      await program.methods
+        .claimRefund()
+        .accounts({
+          donor: donor.publicKey,
+          user: userPDA,
+          campaign: shortPDA,
+          donation: donationPDA,
+          donorPosition: donorPositionPDA,
+          campaignVault: shortVaultPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([donor])
+        .rpc();
+      expect.fail("Should not refund a campaign that may still resume");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.REFUND_NOT_AVAILABLE);
+    }
+
+    await resume(true, shortPDA);
+
+    const campaign = await program.account.campaign.fetch(shortPDA);
+    expect(campaign.status).to.deep.equal({ active: {} });
+    expect(campaign.endDate.toNumber()).to.be.greaterThan(
+      getCurrentTimestamp()
+    );
+    expect(campaign.raisedAmount.toNumber()).to.eq(LAMPORTS_PER_SOL);
+
+    const donation = await program.account.donation.fetch(donationPDA);
+    expect(donation.status).to.not.deep.equal({ refunded: {} });
+  });
+});
@@ -0,0 +1,214 @@
+// File: tests/24-authority-transfer.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import { setupTest, ERROR_MESSAGES } from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  findCampaignRolePDA,
+  findNextWithdrawalPDA,
+  makeTestDonation,
+} from "./utils/helpers";
+
+describe("Campaign Authority Transfer", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let founder: Keypair;
+  let successor: Keypair;
+  let stranger: Keypair;
+  let treasurer: Keypair;
+  let donor: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+
+  const donationAmount = LAMPORTS_PER_SOL;
+  const recipient = Keypair.generate();
+
+  const setStatus = async (
+    authority: Keypair,
+    status: { inProgress: {} } | { completed: {} }
+  ) => {
+    await program.methods
+      .updateCampaign({
+        title: null,
+        description: null,
+        imageUrl: null,
+        startDate: null,
+        endDate: null,
+        status,
+        isUrgent: null,
+        donationLimits: null,
+      })
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+  };
+
+  const accept = async (newAuthority: Keypair) => {
+    await program.methods
+      .acceptCampaignAuthority()
+      .accounts({
+        newAuthority: newAuthority.publicKey,
+        campaign: campaignPDA,
+      })
+      .signers([newAuthority])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    founder = await createAndFundAccount(connection);
+    successor = await createAndFundAccount(connection);
+    stranger = await createAndFundAccount(connection);
+    treasurer = await createAndFundAccount(connection);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, founder);
+    await createTestUser(program, donor);
+
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      founder,
+      { title: "School Roof Repair", beneficiaries: [recipient.publicKey] }
+    ));
+    await makeTestDonation(
+      program,
+      donor,
+      campaignPDA,
+      donationAmount,
+      vaultPDA
+    );
+
+    const [treasurerRole] = await findCampaignRolePDA(
+      campaignPDA,
+      treasurer.publicKey,
+      program
+    );
+    await program.methods
+      .grantCampaignRole(treasurer.publicKey, { treasurer: {} })
+      .accounts({
+        authority: founder.publicKey,
+        campaign: campaignPDA,
+        authorityRole: null,
+        campaignRole: treasurerRole,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([founder])
+      .rpc();
+  });
+
+  it("should record the proposed authority", async () => {
+    await program.methods
+      .proposeCampaignAuthority(successor.publicKey)
+      .accounts({
+        authority: founder.publicKey,
+        campaign: campaignPDA,
+      })
+      .signers([founder])
+      .rpc();
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.pendingAuthority.toBase58()).to.eq(
+      successor.publicKey.toBase58()
+    );
+    expect(campaign.authority.toBase58()).to.eq(founder.publicKey.toBase58());
+  });
+
+  it("should only let the proposed authority accept", async () => {
+    try {
+      await accept(stranger);
+      expect.fail("Should not let someone else take the campaign");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.NOT_PENDING_AUTHORITY);
+    }
+  });
+
+  it("should hand the campaign over once accepted", async () => {
+    await accept(successor);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.authority.toBase58()).to.eq(successor.publicKey.toBase58());
+    expect(campaign.pendingAuthority).to.be.null;
+  });
+
+  it("should no longer accept the previous authority", async () => {
+    try {
+      await setStatus(founder, { inProgress: {} });
+      expect.fail("Should reject the previous authority");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.INVALID_AUTHORITY);
+    }
+  });
+
+  it("should let the new authority manage and withdraw from the same vault", async () => {
+    await setStatus(successor, { inProgress: {} });
+    await setStatus(successor, { completed: {} });
+
+    const [withdrawalPDA] = await findNextWithdrawalPDA(campaignPDA, program);
+    await program.methods
+      .withdrawFunds(new anchor.BN(donationAmount), "Roofing materials", null)
+      .accounts({
+        authority: successor.publicKey,
+        campaign: campaignPDA,
+        withdrawal: withdrawalPDA,
+        campaignVault: vaultPDA,
+        recipient: recipient.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([successor])
+      .rpc();
+
+    expect(await connection.getBalance(recipient.publicKey)).to.eq(
+      donationAmount
+    );
+  });
+
+  it("should not honor roles granted by the previous authority", async () => {
+    const [treasurerRole] = await findCampaignRolePDA(
+      campaignPDA,
+      treasurer.publicKey,
+      program
+    );
+    const role = await program.account.campaignRole.fetch(treasurerRole);
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(role.epoch).to.be.lessThan(campaign.roleEpoch);
+
+    try {
+      const [withdrawalPDA] = await findNextWithdrawalPDA(campaignPDA, program);
+      await program.methods
+        .withdrawFunds(new anchor.BN(1), "Gutters", null)
+        .accounts({
+          authority: treasurer.publicKey,
+          campaign: campaignPDA,
+          campaignRole: treasurerRole,
+          withdrawal: withdrawalPDA,
+          campaignVault: vaultPDA,
+          recipient: recipient.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([treasurer])
+        .rpc();
+      expect.fail("Should not let a lapsed treasurer withdraw");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.INVALID_AUTHORITY);
+    }
+  });
+});
@@ -0,0 +1,215 @@
+// File: tests/25-campaign-roles.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import { setupTest, ERROR_MESSAGES } from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  findCampaignRolePDA,
+  findNextWithdrawalPDA,
+  makeTestDonation,
+} from "./utils/helpers";
+
+describe("Campaign Roles", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let editor: Keypair;
+  let treasurer: Keypair;
+  let donor: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+
+  const donationAmount = LAMPORTS_PER_SOL;
+  const recipient = Keypair.generate();
+
+  const rolePDA = async (member: Keypair) =>
+    (await findCampaignRolePDA(campaignPDA, member.publicKey, program))[0];
+
+  const grant = async (
+    granter: Keypair,
+    member: Keypair,
+    role: Record<string, {}>,
+    granterRole: PublicKey | null = null
+  ) => {
+    await program.methods
+      .grantCampaignRole(member.publicKey, role)
+      .accounts({
+        authority: granter.publicKey,
+        campaign: campaignPDA,
+        authorityRole: granterRole,
+        campaignRole: await rolePDA(member),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([granter])
+      .rpc();
+  };
+
+  const update = async (
+    signer: Keypair,
+    campaignRole: PublicKey | null,
+    changes: { description?: string; status?: Record<string, {}> }
+  ) => {
+    await program.methods
+      .updateCampaign({
+        title: null,
+        description: changes.description ?? null,
+        imageUrl: null,
+        startDate: null,
+        endDate: null,
+        status: changes.status ?? null,
+        isUrgent: null,
+        donationLimits: null,
+      })
+      .accounts({
+        authority: signer.publicKey,
+        campaign: campaignPDA,
+        campaignRole,
+        campaignAuthority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection);
+    editor = await createAndFundAccount(connection);
+    treasurer = await createAndFundAccount(connection);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      { title: "Community Garden", beneficiaries: [recipient.publicKey] }
+    ));
+    await makeTestDonation(
+      program,
+      donor,
+      campaignPDA,
+      donationAmount,
+      vaultPDA
+    );
+  });
+
+  it("should record a role granted by the authority", async () => {
+    await grant(authority, editor, { editor: {} });
+    await grant(authority, treasurer, { treasurer: {} });
+
+    const role = await program.account.campaignRole.fetch(
+      await rolePDA(editor)
+    );
+    expect(role.campaign.toBase58()).to.eq(campaignPDA.toBase58());
+    expect(role.member.toBase58()).to.eq(editor.publicKey.toBase58());
+    expect(role.role).to.deep.equal({ editor: {} });
+    expect(role.grantedBy.toBase58()).to.eq(authority.publicKey.toBase58());
+  });
+
+  it("should let an editor update the campaign's details", async () => {
+    await update(editor, await rolePDA(editor), {
+      description: "Now with raised beds",
+    });
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.description).to.eq("Now with raised beds");
+  });
+
+  it("should not let an editor change the campaign's status", async () => {
+    try {
+      await update(editor, await rolePDA(editor), {
+        status: { inProgress: {} },
+      });
+      expect.fail("Should not let an editor change the status");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.INVALID_AUTHORITY);
+    }
+  });
+
+  it("should only let the authority grant the admin role", async () => {
+    const admin = await createAndFundAccount(connection);
+    await grant(authority, admin, { admin: {} });
+
+    const deputy = await createAndFundAccount(connection);
+    try {
+      await grant(admin, deputy, { admin: {} }, await rolePDA(admin));
+      expect.fail("Should not let an admin appoint another admin");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.INVALID_AUTHORITY);
+    }
+
+    await grant(admin, deputy, { statusManager: {} }, await rolePDA(admin));
+    const role = await program.account.campaignRole.fetch(
+      await rolePDA(deputy)
+    );
+    expect(role.role).to.deep.equal({ statusManager: {} });
+    expect(role.grantedBy.toBase58()).to.eq(admin.publicKey.toBase58());
+  });
+
+  it("should let a treasurer withdraw but not an editor", async () => {
+    await update(authority, null, { status: { inProgress: {} } });
+    await update(authority, null, { status: { completed: {} } });
+
+    const withdraw = async (signer: Keypair) => {
+      const [withdrawalPDA] = await findNextWithdrawalPDA(campaignPDA, program);
+      await program.methods
+        .withdrawFunds(new anchor.BN(donationAmount), "Seeds and soil", null)
+        .accounts({
+          authority: signer.publicKey,
+          campaign: campaignPDA,
+          campaignRole: await rolePDA(signer),
+          withdrawal: withdrawalPDA,
+          campaignVault: vaultPDA,
+          recipient: recipient.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc();
+    };
+
+    try {
+      await withdraw(editor);
+      expect.fail("Should not let an editor withdraw");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.INVALID_AUTHORITY);
+    }
+
+    await withdraw(treasurer);
+    expect(await connection.getBalance(recipient.publicKey)).to.eq(
+      donationAmount
+    );
+  });
+
+  it("should close a revoked role", async () => {
+    const editorRole = await rolePDA(editor);
+    await program.methods
+      .revokeCampaignRole()
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+        authorityRole: null,
+        campaignRole: editorRole,
+      })
+      .signers([authority])
+      .rpc();
+
+    expect(await connection.getAccountInfo(editorRole)).to.be.null;
+  });
+});
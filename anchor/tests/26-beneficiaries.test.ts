@@ -0,0 +1,212 @@
+// File: tests/26-beneficiaries.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import { setupTest, ERROR_MESSAGES, getCurrentTimestamp } from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  fetchCpiEvents,
+  findNextWithdrawalPDA,
+  makeTestDonation,
+} from "./utils/helpers";
+
+describe("Campaign Beneficiaries", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donor: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+
+  const charity = Keypair.generate();
+  const supplier = Keypair.generate();
+  const replacement = Keypair.generate();
+  const changeDelay = 7 * 24 * 60 * 60;
+
+  const propose = async (beneficiaries: PublicKey[] | null) => {
+    const signature = await program.methods
+      .proposeBeneficiaryChange(beneficiaries)
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+      })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+
+    return await fetchCpiEvents(program, signature);
+  };
+
+  const withdraw = async (recipient: PublicKey, amount: number) => {
+    const [withdrawalPDA] = await findNextWithdrawalPDA(campaignPDA, program);
+    await program.methods
+      .withdrawFunds(new anchor.BN(amount), "Food parcels", null)
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+        withdrawal: withdrawalPDA,
+        campaignVault: vaultPDA,
+        recipient,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+  });
+
+  it("should record the beneficiaries declared at creation", async () => {
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      {
+        title: "Food Bank Restock",
+        beneficiaries: [charity.publicKey, supplier.publicKey],
+      }
+    ));
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.beneficiaries.map((key) => key.toBase58())).to.deep.equal([
+      charity.publicKey.toBase58(),
+      supplier.publicKey.toBase58(),
+    ]);
+    expect(campaign.pendingBeneficiaries).to.be.null;
+  });
+
+  it("should reject an empty or duplicated beneficiary list", async () => {
+    for (const beneficiaries of [[], [charity.publicKey, charity.publicKey]]) {
+      try {
+        await createTestCampaignWithVault(program, authority, {
+          title: "Bad Beneficiaries",
+          beneficiaries,
+        });
+        expect.fail("Should reject an invalid beneficiary list");
+      } catch (error) {
+        expect(error.toString()).to.include(
+          ERROR_MESSAGES.INVALID_BENEFICIARIES
+        );
+      }
+    }
+  });
+
+  it("should only withdraw to a declared beneficiary", async () => {
+    await makeTestDonation(
+      program,
+      donor,
+      campaignPDA,
+      LAMPORTS_PER_SOL,
+      vaultPDA
+    );
+    for (const status of [{ inProgress: {} }, { completed: {} }]) {
+      await program.methods
+        .updateCampaign({
+          title: null,
+          description: null,
+          imageUrl: null,
+          startDate: null,
+          endDate: null,
+          status,
+          isUrgent: null,
+          donationLimits: null,
+        })
+        .accounts({
+          authority: authority.publicKey,
+          campaign: campaignPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    try {
+      await withdraw(authority.publicKey, LAMPORTS_PER_SOL);
+      expect.fail("Should not pay out to an undeclared address");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.RECIPIENT_NOT_BENEFICIARY
+      );
+    }
+
+    await withdraw(supplier.publicKey, LAMPORTS_PER_SOL);
+    expect(await connection.getBalance(supplier.publicKey)).to.eq(
+      LAMPORTS_PER_SOL
+    );
+  });
+
+  it("should hold a proposed change for the waiting period", async () => {
+    const before = getCurrentTimestamp();
+    const events = await propose([replacement.publicKey]);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    const pending = campaign.pendingBeneficiaries;
+    expect(pending.beneficiaries[0].toBase58()).to.eq(
+      replacement.publicKey.toBase58()
+    );
+    expect(pending.effectiveAt.toNumber()).to.be.at.least(
+      before + changeDelay - 60
+    );
+    expect(campaign.beneficiaries).to.have.lengthOf(2);
+
+    const proposed = events.find(
+      (event) => event.name === "beneficiaryChangeProposed"
+    );
+    expect(proposed, "beneficiaryChangeProposed event").to.not.be.undefined;
+    expect(proposed.data.effectiveAt.toNumber()).to.eq(
+      pending.effectiveAt.toNumber()
+    );
+  });
+
+  it("should not apply a change before its waiting period ends", async () => {
+    try {
+      await program.methods
+        .applyBeneficiaryChange()
+        .accounts({
+          caller: donor.publicKey,
+          campaign: campaignPDA,
+        })
+        .signers([donor])
+        .rpc();
+      expect.fail("Should not apply the change early");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.BENEFICIARY_CHANGE_NOT_READY
+      );
+    }
+  });
+
+  it("should let the authority withdraw a proposed change", async () => {
+    await propose(null);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.pendingBeneficiaries).to.be.null;
+
+    try {
+      await propose(null);
+      expect.fail("Should not cancel when nothing is pending");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.NO_PENDING_BENEFICIARY_CHANGE
+      );
+    }
+  });
+});
@@ -0,0 +1,265 @@
+// File: tests/27-withdrawal-approvals.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import { setupTest, ERROR_MESSAGES } from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  findNextWithdrawalPDA,
+  findNextWithdrawalProposalPDA,
+  findWithdrawalPDA,
+  makeTestDonation,
+} from "./utils/helpers";
+
+describe("Withdrawal Approvals", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let treasurer: Keypair;
+  let director: Keypair;
+  let trustee: Keypair;
+  let donor: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+  let proposalPDA: PublicKey;
+
+  const recipient = Keypair.generate();
+  const amount = 0.6 * LAMPORTS_PER_SOL;
+
+  const propose = async (proposer: Keypair): Promise<PublicKey> => {
+    const [withdrawalProposalPDA] = await findNextWithdrawalProposalPDA(
+      campaignPDA,
+      program
+    );
+    await program.methods
+      .proposeWithdrawal(new anchor.BN(amount), "Shelter beds", null)
+      .accounts({
+        proposer: proposer.publicKey,
+        campaign: campaignPDA,
+        withdrawalProposal: withdrawalProposalPDA,
+        recipient: recipient.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([proposer])
+      .rpc();
+
+    return withdrawalProposalPDA;
+  };
+
+  const approve = async (approver: Keypair) => {
+    await program.methods
+      .approveWithdrawal()
+      .accounts({
+        approver: approver.publicKey,
+        campaign: campaignPDA,
+        withdrawalProposal: proposalPDA,
+      })
+      .signers([approver])
+      .rpc();
+  };
+
+  const execute = async (executor: Keypair) => {
+    const [withdrawalPDA] = await findNextWithdrawalPDA(campaignPDA, program);
+    await program.methods
+      .executeWithdrawal()
+      .accounts({
+        executor: executor.publicKey,
+        campaign: campaignPDA,
+        withdrawalProposal: proposalPDA,
+        proposer: treasurer.publicKey,
+        withdrawal: withdrawalPDA,
+        campaignVault: vaultPDA,
+        recipient: recipient.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([executor])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection);
+    treasurer = await createAndFundAccount(connection);
+    director = await createAndFundAccount(connection);
+    trustee = await createAndFundAccount(connection);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      {
+        title: "Winter Shelter",
+        beneficiaries: [recipient.publicKey],
+        approvals: {
+          signers: [
+            treasurer.publicKey,
+            director.publicKey,
+            trustee.publicKey,
+          ],
+          threshold: 2,
+        },
+      }
+    ));
+    await makeTestDonation(
+      program,
+      donor,
+      campaignPDA,
+      LAMPORTS_PER_SOL,
+      vaultPDA
+    );
+
+    for (const status of [{ inProgress: {} }, { completed: {} }]) {
+      await program.methods
+        .updateCampaign({
+          title: null,
+          description: null,
+          imageUrl: null,
+          startDate: null,
+          endDate: null,
+          status,
+          isUrgent: null,
+          donationLimits: null,
+        })
+        .accounts({
+          authority: authority.publicKey,
+          campaign: campaignPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    }
+  });
+
+  it("should reject a threshold the signers can't meet", async () => {
+    try {
+      await createTestCampaignWithVault(program, authority, {
+        title: "Unreachable Threshold",
+        approvals: { signers: [treasurer.publicKey], threshold: 2 },
+      });
+      expect.fail("Should reject a threshold above the number of signers");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.INVALID_APPROVAL_CONFIG
+      );
+    }
+  });
+
+  it("should not let the authority withdraw directly", async () => {
+    const [withdrawalPDA] = await findNextWithdrawalPDA(campaignPDA, program);
+    try {
+      await program.methods
+        .withdrawFunds(new anchor.BN(amount), "Shelter beds", null)
+        .accounts({
+          authority: authority.publicKey,
+          campaign: campaignPDA,
+          withdrawal: withdrawalPDA,
+          campaignVault: vaultPDA,
+          recipient: recipient.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      expect.fail("Should require approval from the signers");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.APPROVAL_REQUIRED);
+    }
+  });
+
+  it("should only accept proposals from a signer", async () => {
+    try {
+      await propose(authority);
+      expect.fail("Should not accept a proposal from outside the signer set");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.NOT_APPROVER);
+    }
+  });
+
+  it("should count the proposer's approval", async () => {
+    proposalPDA = await propose(treasurer);
+
+    const proposal = await program.account.withdrawalProposal.fetch(
+      proposalPDA
+    );
+    expect(proposal.amount.toNumber()).to.eq(amount);
+    expect(proposal.recipient.toBase58()).to.eq(recipient.publicKey.toBase58());
+    expect(proposal.approvals.map((key) => key.toBase58())).to.deep.equal([
+      treasurer.publicKey.toBase58(),
+    ]);
+    expect(proposal.expiresAt.toNumber()).to.be.greaterThan(
+      proposal.createdAt.toNumber()
+    );
+  });
+
+  it("should not execute below the threshold", async () => {
+    try {
+      await execute(treasurer);
+      expect.fail("Should need a second approval");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.APPROVAL_THRESHOLD_NOT_MET
+      );
+    }
+  });
+
+  it("should not count the same signer twice", async () => {
+    try {
+      await approve(treasurer);
+      expect.fail("Should not approve twice");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.ALREADY_APPROVED);
+    }
+  });
+
+  it("should not clear out a proposal before it expires", async () => {
+    try {
+      await program.methods
+        .expireWithdrawalProposal()
+        .accounts({
+          caller: donor.publicKey,
+          withdrawalProposal: proposalPDA,
+          proposer: treasurer.publicKey,
+        })
+        .signers([donor])
+        .rpc();
+      expect.fail("Should keep a live proposal");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.WITHDRAWAL_PROPOSAL_NOT_EXPIRED
+      );
+    }
+  });
+
+  it("should pay out once enough signers approve", async () => {
+    await approve(director);
+    await execute(trustee);
+
+    expect(await connection.getBalance(recipient.publicKey)).to.eq(amount);
+    expect(await connection.getAccountInfo(proposalPDA)).to.be.null;
+
+    const [withdrawalPDA] = await findWithdrawalPDA(campaignPDA, 0, program);
+    const withdrawal = await program.account.withdrawal.fetch(withdrawalPDA);
+    expect(withdrawal.amount.toNumber()).to.eq(amount);
+    expect(withdrawal.purpose).to.eq("Shelter beds");
+    expect(withdrawal.authority.toBase58()).to.eq(trustee.publicKey.toBase58());
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.withdrawnAmount.toNumber()).to.eq(amount);
+  });
+});
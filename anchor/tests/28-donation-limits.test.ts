@@ -0,0 +1,219 @@
+// File: tests/28-donation-limits.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { randomBytes } from "crypto";
+import { Ripple } from "../target/types/ripple";
+import { setupTest, ERROR_MESSAGES, TEST_CONSTANTS } from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaignWithVault,
+  createTestUser,
+  findNextDonationPDA,
+  findUserPDA,
+  makeTestDonation,
+} from "./utils/helpers";
+
+describe("Donation Limits", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donorA: Keypair;
+  let donorB: Keypair;
+  let donorC: Keypair;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+
+  const hardCap = 1.5 * LAMPORTS_PER_SOL;
+  const maxPerDonor = LAMPORTS_PER_SOL;
+
+  const setLimits = async (hardCap: number, maxPerDonor: number) => {
+    await program.methods
+      .updateCampaign({
+        title: null,
+        description: null,
+        imageUrl: null,
+        startDate: null,
+        endDate: null,
+        status: null,
+        isUrgent: null,
+        donationLimits: {
+          hardCap: new anchor.BN(hardCap),
+          maxPerDonor: new anchor.BN(maxPerDonor),
+        },
+      })
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+  };
+
+  const donate = (donor: Keypair, amount: number) =>
+    makeTestDonation(program, donor, campaignPDA, amount, vaultPDA);
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection);
+    donorA = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donorB = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donorC = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    for (const donor of [donorA, donorB, donorC]) {
+      await createTestUser(program, donor);
+    }
+  });
+
+  it("should reject a hard cap below the target", async () => {
+    try {
+      await createTestCampaignWithVault(program, authority, {
+        title: "Undersized Cap",
+        targetAmount: LAMPORTS_PER_SOL,
+        donationLimits: {
+          hardCap: new anchor.BN(0.5 * LAMPORTS_PER_SOL),
+          maxPerDonor: null,
+        },
+      });
+      expect.fail("Should not cap a campaign below its target");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.INVALID_DONATION_LIMITS
+      );
+    }
+  });
+
+  it("should record the limits on the campaign", async () => {
+    ({ campaignPDA, vaultPDA } = await createTestCampaignWithVault(
+      program,
+      authority,
+      {
+        title: "Village Well",
+        targetAmount: LAMPORTS_PER_SOL,
+        donationLimits: {
+          hardCap: new anchor.BN(hardCap),
+          maxPerDonor: new anchor.BN(maxPerDonor),
+        },
+      }
+    ));
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.donationLimits.hardCap.toNumber()).to.eq(hardCap);
+    expect(campaign.donationLimits.maxPerDonor.toNumber()).to.eq(maxPerDonor);
+  });
+
+  it("should stop a donor at the per-donor limit", async () => {
+    await donate(donorA, 0.6 * LAMPORTS_PER_SOL);
+    await donate(donorA, 0.4 * LAMPORTS_PER_SOL);
+
+    try {
+      await donate(donorA, 0.1 * LAMPORTS_PER_SOL);
+      expect.fail("Should not take the donor over their limit");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.DONOR_LIMIT_EXCEEDED);
+    }
+  });
+
+  it("should reject anonymous donations while a per-donor limit is set", async () => {
+    const [userPDA] = await findUserPDA(donorC.publicKey, program);
+    const [donationPDA] = await findNextDonationPDA(campaignPDA, program);
+
+    try {
+      await program.methods
+        .donate(
+          new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          { cryptoWallet: {} },
+          null,
+          [...randomBytes(32)]
+        )
+        .accounts({
+          donor: donorC.publicKey,
+          user: userPDA,
+          campaign: campaignPDA,
+          donation: donationPDA,
+          donorPosition: null,
+          campaignVault: vaultPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([donorC])
+        .rpc();
+      expect.fail("Should not accept a gift that can't be tied to a donor");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.ANONYMOUS_DONATION_NOT_ALLOWED
+      );
+    }
+  });
+
+  it("should accept only what fits under the hard cap", async () => {
+    const vaultBalanceBefore = await connection.getBalance(vaultPDA);
+    const donationPDA = await donate(donorB, LAMPORTS_PER_SOL);
+
+    const donation = await program.account.donation.fetch(donationPDA);
+    expect(donation.amount.toNumber()).to.eq(0.5 * LAMPORTS_PER_SOL);
+    expect(await connection.getBalance(vaultPDA)).to.eq(
+      vaultBalanceBefore + 0.5 * LAMPORTS_PER_SOL
+    );
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.raisedAmount.toNumber()).to.eq(hardCap);
+  });
+
+  it("should reject donations once the hard cap is reached", async () => {
+    try {
+      await donate(donorC, 0.1 * LAMPORTS_PER_SOL);
+      expect.fail("Should not accept donations past the hard cap");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.HARD_CAP_REACHED);
+    }
+  });
+
+  it("should not lower the hard cap below what has been raised", async () => {
+    try {
+      await setLimits(LAMPORTS_PER_SOL, maxPerDonor);
+      expect.fail("Should not cap the campaign below its raised amount");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.INVALID_DONATION_LIMITS
+      );
+    }
+  });
+
+  it("should take donations again once the hard cap is raised", async () => {
+    await setLimits(2 * LAMPORTS_PER_SOL, maxPerDonor);
+    await donate(donorC, 0.1 * LAMPORTS_PER_SOL);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.raisedAmount.toNumber()).to.eq(
+      hardCap + 0.1 * LAMPORTS_PER_SOL
+    );
+  });
+
+  it("should treat room below the minimum donation as a full cap", async () => {
+    const raised = hardCap + 0.1 * LAMPORTS_PER_SOL;
+    await setLimits(
+      raised + TEST_CONSTANTS.MIN_DONATION_AMOUNT / 2,
+      maxPerDonor
+    );
+
+    try {
+      await donate(donorC, 0.1 * LAMPORTS_PER_SOL);
+      expect.fail("Should not trim a donation below the minimum");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.HARD_CAP_REACHED);
+    }
+  });
+});
@@ -0,0 +1,209 @@
+// File: tests/29-legacy-migration.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import * as fs from "fs";
+import * as path from "path";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import { setupTest, ERROR_MESSAGES } from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestCampaign,
+  findCampaignVaultPDA,
+  findUserPDA,
+} from "./utils/helpers";
+
+// Loaded into the test validator from Anchor.toml; see tests/fixtures
+const fixture = (name: string) =>
+  JSON.parse(
+    fs.readFileSync(path.join(__dirname, "fixtures", `${name}.json`), "utf8")
+  );
+
+describe("Legacy Migration", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  const authority = Keypair.fromSecretKey(
+    Uint8Array.from(fixture("legacy-authority"))
+  );
+  const title = "Legacy Appeal";
+  const raisedAmount = LAMPORTS_PER_SOL / 2;
+
+  let userPDA: PublicKey;
+  let campaignPDA: PublicKey;
+  let legacyVault: PublicKey;
+  let vaultPDA: PublicKey;
+
+  const migrateUser = async () => {
+    await program.methods
+      .migrateUser()
+      .accounts({
+        authority: authority.publicKey,
+        user: userPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+  };
+
+  const migrate = async (signer: Keypair) => {
+    await program.methods
+      .migrateCampaign()
+      .accounts({
+        authority: signer.publicKey,
+        campaign: campaignPDA,
+        legacyVault,
+        campaignVault: vaultPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+  };
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    const signature = await connection.requestAirdrop(
+      authority.publicKey,
+      10 * LAMPORTS_PER_SOL
+    );
+    await connection.confirmTransaction(signature);
+
+    [userPDA] = await findUserPDA(authority.publicKey, program);
+    [campaignPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("campaign"),
+        Buffer.from(title),
+        authority.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    [legacyVault] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("campaign"),
+        Buffer.from(title),
+        authority.publicKey.toBuffer(),
+        Buffer.from("vault"),
+      ],
+      program.programId
+    );
+    [vaultPDA] = await findCampaignVaultPDA(campaignPDA, program);
+
+    expect(fixture("legacy-user").pubkey).to.eq(userPDA.toBase58());
+    expect(fixture("legacy-campaign").pubkey).to.eq(campaignPDA.toBase58());
+    expect(fixture("legacy-vault").pubkey).to.eq(legacyVault.toBase58());
+  });
+
+  it("should not decode a legacy campaign before it is migrated", async () => {
+    try {
+      await program.account.campaign.fetch(campaignPDA);
+      expect.fail("Should not decode the legacy layout as a campaign");
+    } catch (error) {
+      expect(error.toString()).to.not.include("Should not decode");
+    }
+  });
+
+  it("should only let the campaign's authority migrate it", async () => {
+    const stranger = await createAndFundAccount(connection);
+    try {
+      await migrate(stranger);
+      expect.fail("Should not let a stranger migrate the campaign");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.INVALID_AUTHORITY);
+    }
+  });
+
+  it("should rewrite the campaign and move its funds", async () => {
+    await migrate(authority);
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.authority.toBase58()).to.eq(
+      authority.publicKey.toBase58()
+    );
+    expect(campaign.title).to.eq(title);
+    expect(campaign.category).to.deep.equal({ education: {} });
+    expect(campaign.targetAmount.toNumber()).to.eq(2 * LAMPORTS_PER_SOL);
+    expect(campaign.raisedAmount.toNumber()).to.eq(raisedAmount);
+    expect(campaign.donorsCount).to.eq(2);
+    expect(campaign.donationCount.toNumber()).to.eq(0);
+    expect(campaign.status).to.deep.equal({ active: {} });
+    expect(campaign.beneficiaries.map((key) => key.toBase58())).to.deep.equal(
+      [authority.publicKey.toBase58()]
+    );
+
+    expect(await connection.getBalance(legacyVault)).to.eq(0);
+    expect(await connection.getBalance(vaultPDA)).to.eq(raisedAmount);
+
+    const info = await connection.getAccountInfo(campaignPDA);
+    expect(info.data.length).to.be.greaterThan(
+      fixture("legacy-campaign").account.space
+    );
+    expect(info.lamports).to.be.at.least(
+      await connection.getMinimumBalanceForRentExemption(info.data.length)
+    );
+  });
+
+  it("should not migrate a campaign twice", async () => {
+    try {
+      await migrate(authority);
+      expect.fail("Should not migrate the campaign again");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.CAMPAIGN_ALREADY_MIGRATED
+      );
+    }
+  });
+
+  it("should not let a legacy profile create campaigns", async () => {
+    try {
+      await createTestCampaign(program, authority);
+      expect.fail("Should not create a campaign from a legacy profile");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.USER_NOT_MIGRATED);
+    }
+  });
+
+  it("should migrate a legacy profile and keep its history", async () => {
+    await migrateUser();
+
+    const user = await program.account.user.fetch(userPDA);
+    expect(user.authority.toBase58()).to.eq(authority.publicKey.toBase58());
+    expect(user.name).to.eq("Legacy Organizer");
+    expect(user.totalDonations.toNumber()).to.eq(LAMPORTS_PER_SOL);
+    expect(user.campaignsSupported).to.eq(1);
+    expect(user.badges).to.have.length(1);
+    expect(user.badges[0].badgeType).to.deep.equal({ bronze: {} });
+    expect(user.campaignsCreated.toNumber()).to.eq(0);
+
+    const info = await connection.getAccountInfo(userPDA);
+    expect(info.data.length).to.be.greaterThan(
+      fixture("legacy-user").account.space
+    );
+  });
+
+  it("should let a migrated profile create campaigns", async () => {
+    const newCampaignPDA = await createTestCampaign(program, authority);
+
+    const campaign = await program.account.campaign.fetch(newCampaignPDA);
+    expect(campaign.id.toNumber()).to.eq(0);
+    const user = await program.account.user.fetch(userPDA);
+    expect(user.campaignsCreated.toNumber()).to.eq(1);
+  });
+
+  it("should not migrate a profile twice", async () => {
+    try {
+      await migrateUser();
+      expect.fail("Should not migrate the profile again");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.USER_ALREADY_MIGRATED);
+    }
+  });
+});
@@ -9,6 +9,8 @@ import {
   findUserPDA,
   createTestUser,
   createTestCampaign,
+  findCampaignVaultPDA,
+  findDonorPositionPDA,
 } from "./utils/helpers";
 
 describe("Donation Flow", () => {
@@ -41,14 +43,9 @@ describe("Donation Flow", () => {
       const campaign = await program.account.campaign.fetch(campaignPDA);
 
       // Find campaign vault PDA
-      const [campaignVaultPDA] = await PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("campaign"),
-          Buffer.from(campaign.title),
-          campaign.authority.toBuffer(),
-          Buffer.from("vault"),
-        ],
-        program.programId
+      const [campaignVaultPDA] = await findCampaignVaultPDA(
+        campaignPDA,
+        program
       );
 
       // Find donation PDA
@@ -56,23 +53,25 @@ describe("Donation Flow", () => {
         [
           Buffer.from("donation"),
           campaignPDA.toBuffer(),
-          donor.publicKey.toBuffer(),
-          Buffer.from(campaign.donorsCount.toString()),
+          campaign.donationCount.toArrayLike(Buffer, "le", 8),
         ],
         program.programId
       );
 
+      const [donorPositionPDA] = await findDonorPositionPDA(
+        campaignPDA,
+        donor.publicKey,
+        program
+      );
+
       await program.methods
-        .donate(
-          new anchor.BN(donationAmount),
-          { cryptoWallet: {} },
-          campaign.donorsCount.toString()
-        )
+        .donate(new anchor.BN(donationAmount), { cryptoWallet: {} }, null, null)
         .accounts({
           donor: donor.publicKey,
           user: userPDA,
           campaign: campaignPDA,
           donation: donationPDA,
+          donorPosition: donorPositionPDA,
           campaignVault: campaignVaultPDA,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -94,14 +93,9 @@ describe("Donation Flow", () => {
       const campaign = await program.account.campaign.fetch(campaignPDA);
 
       // Find campaign vault PDA
-      const [campaignVaultPDA] = await PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("campaign"),
-          Buffer.from(campaign.title),
-          campaign.authority.toBuffer(),
-          Buffer.from("vault"),
-        ],
-        program.programId
+      const [campaignVaultPDA] = await findCampaignVaultPDA(
+        campaignPDA,
+        program
       );
 
       // Find donation PDA
@@ -109,23 +103,25 @@ describe("Donation Flow", () => {
         [
           Buffer.from("donation"),
           campaignPDA.toBuffer(),
-          donor.publicKey.toBuffer(),
-          Buffer.from(campaign.donorsCount.toString()),
+          campaign.donationCount.toArrayLike(Buffer, "le", 8),
         ],
         program.programId
       );
 
+      const [donorPositionPDA] = await findDonorPositionPDA(
+        campaignPDA,
+        donor.publicKey,
+        program
+      );
+
       await program.methods
-        .donate(
-          new anchor.BN(donationAmount),
-          { cryptoWallet: {} },
-          campaign.donorsCount.toString()
-        )
+        .donate(new anchor.BN(donationAmount), { cryptoWallet: {} }, null, null)
         .accounts({
           donor: donor.publicKey,
           user: userPDA,
           campaign: campaignPDA,
           donation: donationPDA,
+          donorPosition: donorPositionPDA,
           campaignVault: campaignVaultPDA,
           systemProgram: anchor.web3.SystemProgram.programId,
         })
@@ -134,7 +130,12 @@ describe("Donation Flow", () => {
 
       const updatedCampaign = await program.account.campaign.fetch(campaignPDA);
       expect(updatedCampaign.raisedAmount.toNumber()).to.eq(donationAmount * 2);
-      expect(updatedCampaign.donorsCount).to.eq(2);
+      expect(updatedCampaign.donorsCount).to.eq(1);
+      expect(updatedCampaign.donationCount.toNumber()).to.eq(2);
+
+      // Donations are numbered in the order the campaign received them
+      const donation = await program.account.donation.fetch(donationPDA);
+      expect(donation.index.toNumber()).to.eq(1);
 
       // Verify the total funds in vault
       const vaultBalance = await connection.getBalance(campaignVaultPDA);
@@ -146,7 +147,7 @@ describe("Donation Flow", () => {
       const userAccount = await program.account.user.fetch(userPDA);
 
       expect(userAccount.totalDonations.toNumber()).to.eq(LAMPORTS_PER_SOL * 2);
-      expect(userAccount.campaignsSupported).to.eq(2); // This would be fixed in a separate update
+      expect(userAccount.campaignsSupported).to.eq(1);
     });
   });
 
@@ -167,37 +168,39 @@ describe("Donation Flow", () => {
         const campaign = await program.account.campaign.fetch(campaignPDA);
 
         // Find campaign vault PDA
-        const [campaignVaultPDA] = await PublicKey.findProgramAddressSync(
-          [
-            Buffer.from("campaign"),
-            Buffer.from(campaign.title),
-            campaign.authority.toBuffer(),
-            Buffer.from("vault"),
-          ],
-          program.programId
+        const [campaignVaultPDA] = await findCampaignVaultPDA(
+          campaignPDA,
+          program
         );
 
         const [donationPDA] = await PublicKey.findProgramAddressSync(
           [
             Buffer.from("donation"),
             campaignPDA.toBuffer(),
-            poorDonor.publicKey.toBuffer(),
-            Buffer.from(campaign.donorsCount.toString()),
+            campaign.donationCount.toArrayLike(Buffer, "le", 8),
           ],
           program.programId
         );
 
+        const [donorPositionPDA] = await findDonorPositionPDA(
+          campaignPDA,
+          poorDonor.publicKey,
+          program
+        );
+
         await program.methods
           .donate(
             new anchor.BN(LAMPORTS_PER_SOL),
             { cryptoWallet: {} },
-            campaign.donorsCount.toString()
+            null,
+            null
           )
           .accounts({
             donor: poorDonor.publicKey,
             user: userPDA,
             campaign: campaignPDA,
             donation: donationPDA,
+            donorPosition: donorPositionPDA,
             campaignVault: campaignVaultPDA,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
@@ -216,37 +219,39 @@ describe("Donation Flow", () => {
         const campaign = await program.account.campaign.fetch(campaignPDA);
 
         // Find campaign vault PDA
-        const [campaignVaultPDA] = await PublicKey.findProgramAddressSync(
-          [
-            Buffer.from("campaign"),
-            Buffer.from(campaign.title),
-            campaign.authority.toBuffer(),
-            Buffer.from("vault"),
-          ],
-          program.programId
+        const [campaignVaultPDA] = await findCampaignVaultPDA(
+          campaignPDA,
+          program
         );
 
         const [donationPDA] = await PublicKey.findProgramAddressSync(
           [
             Buffer.from("donation"),
             campaignPDA.toBuffer(),
-            donor.publicKey.toBuffer(),
-            Buffer.from(campaign.donorsCount.toString()),
+            campaign.donationCount.toArrayLike(Buffer, "le", 8),
           ],
           program.programId
         );
 
+        const [donorPositionPDA] = await findDonorPositionPDA(
+          campaignPDA,
+          donor.publicKey,
+          program
+        );
+
         await program.methods
           .donate(
             new anchor.BN(TEST_CONSTANTS.MIN_DONATION_AMOUNT - 1),
             { cryptoWallet: {} },
-            campaign.donorsCount.toString()
+            null,
+            null
           )
           .accounts({
             donor: donor.publicKey,
             user: userPDA,
             campaign: campaignPDA,
             donation: donationPDA,
+            donorPosition: donorPositionPDA,
             campaignVault: campaignVaultPDA,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
@@ -271,11 +276,14 @@ describe("Donation Flow", () => {
       // First transition to InProgress
       await program.methods
         .updateCampaign({
+          title: null,
           description: null,
           imageUrl: null,
+          startDate: null,
           endDate: null,
           status: { inProgress: {} },
           isUrgent: null,
+          donationLimits: null,
         })
         .accounts({
           authority: campaignCreator.publicKey,
@@ -288,11 +296,14 @@ describe("Donation Flow", () => {
       // Then transition to Completed
       await program.methods
         .updateCampaign({
+          title: null,
           description: null,
           imageUrl: null,
+          startDate: null,
           endDate: null,
           status: { completed: {} },
           isUrgent: null,
+          donationLimits: null,
         })
         .accounts({
           authority: campaignCreator.publicKey,
@@ -307,33 +318,39 @@ describe("Donation Flow", () => {
         const campaign = await program.account.campaign.fetch(inactiveCampaign);
 
         // Find campaign vault PDA
-        const [campaignVaultPDA] = await PublicKey.findProgramAddressSync(
-          [
-            Buffer.from("campaign"),
-            Buffer.from(campaign.title),
-            campaign.authority.toBuffer(),
-            Buffer.from("vault"),
-          ],
-          program.programId
+        const [campaignVaultPDA] = await findCampaignVaultPDA(
+          inactiveCampaign,
+          program
         );
 
         const [donationPDA] = await PublicKey.findProgramAddressSync(
           [
             Buffer.from("donation"),
             inactiveCampaign.toBuffer(),
-            donor.publicKey.toBuffer(),
-            Buffer.from("0"),
+            new anchor.BN(0).toArrayLike(Buffer, "le", 8),
           ],
           program.programId
         );
 
+        const [donorPositionPDA] = await findDonorPositionPDA(
+          inactiveCampaign,
+          donor.publicKey,
+          program
+        );
+
         await program.methods
-          .donate(new anchor.BN(LAMPORTS_PER_SOL), { cryptoWallet: {} }, "0")
+          .donate(
+            new anchor.BN(LAMPORTS_PER_SOL),
+            { cryptoWallet: {} },
+            null,
+            null
+          )
           .accounts({
             donor: donor.publicKey,
             user: userPDA,
             campaign: inactiveCampaign,
             donation: donationPDA,
+            donorPosition: donorPositionPDA,
             campaignVault: campaignVaultPDA,
             systemProgram: anchor.web3.SystemProgram.programId,
           })
@@ -14,6 +14,7 @@ import {
   findUserPDA,
   createTestUser,
   createTestCampaign,
+  findCampaignVaultPDA,
   makeTestDonation,
   verifyBadgeAward,
 } from "./utils/helpers";
@@ -43,19 +44,8 @@ describe("Badge System", () => {
     await createTestUser(program, campaignCreator);
     campaignPDA = await createTestCampaign(program, campaignCreator);
 
-    // Fetch campaign account to get required data for vault PDA
-    const campaign = await program.account.campaign.fetch(campaignPDA);
-
     // Find campaign vault PDA
-    [campaignVaultPDA] = await PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("campaign"),
-        Buffer.from(campaign.title),
-        campaign.authority.toBuffer(),
-        Buffer.from("vault"),
-      ],
-      program.programId
-    );
+    [campaignVaultPDA] = await findCampaignVaultPDA(campaignPDA, program);
 
     userPDA = (await findUserPDA(donor.publicKey, program))[0];
   });
@@ -171,15 +161,9 @@ describe("Badge System", () => {
       );
 
       // Get new campaign vault PDA
-      const newCampaign = await program.account.campaign.fetch(newCampaignPDA);
-      const [newCampaignVaultPDA] = await PublicKey.findProgramAddressSync(
-        [
-          Buffer.from("campaign"),
-          Buffer.from(newCampaign.title),
-          newCampaign.authority.toBuffer(),
-          Buffer.from("vault"),
-        ],
-        program.programId
+      const [newCampaignVaultPDA] = await findCampaignVaultPDA(
+        newCampaignPDA,
+        program
       );
 
       // Make minimum required donations
@@ -22,6 +22,7 @@ import {
   createTestCampaignWithVault,
   makeTestDonation,
   findCampaignVaultPDA,
+  findNextWithdrawalPDA,
 } from "./utils/helpers";
 
 describe("Fund Management", () => {
@@ -62,6 +63,7 @@ describe("Fund Management", () => {
       await createTestCampaignWithVault(program, campaignAuthority, {
         targetAmount: campaignTarget,
         daysToRun: 30,
+        beneficiaries: [recipientAccount.publicKey],
       });
 
     campaignPDA = newCampaignPDA;
@@ -117,11 +119,14 @@ describe("Fund Management", () => {
       // Transition to InProgress
       await program.methods
         .updateCampaign({
+          title: null,
           description: null,
           imageUrl: null,
+          startDate: null,
           endDate: null,
           status: { inProgress: {} },
           isUrgent: null,
+          donationLimits: null,
         })
         .accounts({
           authority: campaignAuthority.publicKey,
@@ -134,11 +139,14 @@ describe("Fund Management", () => {
       // Transition to Completed
       await program.methods
         .updateCampaign({
+          title: null,
           description: null,
           imageUrl: null,
+          startDate: null,
           endDate: null,
           status: { completed: {} },
           isUrgent: null,
+          donationLimits: null,
         })
         .accounts({
           authority: campaignAuthority.publicKey,
@@ -153,11 +161,13 @@ describe("Fund Management", () => {
         recipientAccount.publicKey
       );
 
+      const [withdrawalPDA] = await findNextWithdrawalPDA(campaignPDA, program);
       await program.methods
-        .withdrawFunds(new anchor.BN(donation1Amount))
+        .withdrawFunds(new anchor.BN(donation1Amount), "Program costs", null)
         .accounts({
           authority: campaignAuthority.publicKey,
           campaign: campaignPDA,
+          withdrawal: withdrawalPDA,
           campaignVault: vaultPDA,
           recipient: recipientAccount.publicKey,
           systemProgram: SystemProgram.programId,
@@ -190,11 +200,14 @@ describe("Fund Management", () => {
       // Complete campaign
       await program.methods
         .updateCampaign({
+          title: null,
           description: null,
           imageUrl: null,
+          startDate: null,
           endDate: null,
           status: { inProgress: {} },
           isUrgent: null,
+          donationLimits: null,
         })
         .accounts({
           authority: campaignAuthority.publicKey,
@@ -206,11 +219,14 @@ describe("Fund Management", () => {
 
       await program.methods
         .updateCampaign({
+          title: null,
           description: null,
           imageUrl: null,
+          startDate: null,
           endDate: null,
           status: { completed: {} },
           isUrgent: null,
+          donationLimits: null,
         })
         .accounts({
           authority: campaignAuthority.publicKey,
@@ -221,13 +237,20 @@ describe("Fund Management", () => {
         .rpc();
 
       const partialAmount = donation1Amount / 2;
+      const receiptHash = Array(32).fill(7);
       const initialVaultBalance = await connection.getBalance(vaultPDA);
 
+      const [withdrawalPDA] = await findNextWithdrawalPDA(campaignPDA, program);
       await program.methods
-        .withdrawFunds(new anchor.BN(partialAmount))
+        .withdrawFunds(
+          new anchor.BN(partialAmount),
+          "Medical supplies",
+          receiptHash
+        )
         .accounts({
           authority: campaignAuthority.publicKey,
           campaign: campaignPDA,
+          withdrawal: withdrawalPDA,
           campaignVault: vaultPDA,
           recipient: recipientAccount.publicKey,
           systemProgram: SystemProgram.programId,
@@ -237,6 +260,20 @@ describe("Fund Management", () => {
 
       const finalVaultBalance = await connection.getBalance(vaultPDA);
       expect(finalVaultBalance).to.equal(initialVaultBalance - partialAmount);
+
+      // The withdrawal is recorded in the campaign's ledger
+      const withdrawal = await program.account.withdrawal.fetch(withdrawalPDA);
+      expect(withdrawal.sequence.toNumber()).to.equal(0);
+      expect(withdrawal.recipient.toBase58()).to.equal(
+        recipientAccount.publicKey.toBase58()
+      );
+      expect(withdrawal.amount.toNumber()).to.equal(partialAmount);
+      expect(withdrawal.purpose).to.equal("Medical supplies");
+      expect(withdrawal.receiptHash).to.deep.equal(receiptHash);
+
+      const campaign = await program.account.campaign.fetch(campaignPDA);
+      expect(campaign.withdrawnAmount.toNumber()).to.equal(partialAmount);
+      expect(campaign.withdrawalCount.toNumber()).to.equal(1);
     });
   });
 
@@ -253,11 +290,16 @@ describe("Fund Management", () => {
       );
 
       try {
+        const [withdrawalPDA] = await findNextWithdrawalPDA(
+          campaignPDA,
+          program
+        );
         await program.methods
-          .withdrawFunds(new anchor.BN(donation1Amount))
+          .withdrawFunds(new anchor.BN(donation1Amount), "Program costs", null)
           .accounts({
             authority: campaignAuthority.publicKey,
             campaign: campaignPDA,
+            withdrawal: withdrawalPDA,
             campaignVault: vaultPDA,
             recipient: recipientAccount.publicKey,
             systemProgram: SystemProgram.programId,
@@ -285,11 +327,14 @@ describe("Fund Management", () => {
       // Complete campaign
       await program.methods
         .updateCampaign({
+          title: null,
           description: null,
           imageUrl: null,
+          startDate: null,
           endDate: null,
           status: { inProgress: {} },
           isUrgent: null,
+          donationLimits: null,
         })
         .accounts({
           authority: campaignAuthority.publicKey,
@@ -301,11 +346,14 @@ describe("Fund Management", () => {
 
       await program.methods
         .updateCampaign({
+          title: null,
           description: null,
           imageUrl: null,
+          startDate: null,
           endDate: null,
           status: { completed: {} },
           isUrgent: null,
+          donationLimits: null,
         })
         .accounts({
           authority: campaignAuthority.publicKey,
@@ -316,11 +364,16 @@ describe("Fund Management", () => {
         .rpc();
 
       try {
+        const [withdrawalPDA] = await findNextWithdrawalPDA(
+          campaignPDA,
+          program
+        );
         await program.methods
-          .withdrawFunds(new anchor.BN(donation1Amount))
+          .withdrawFunds(new anchor.BN(donation1Amount), "Program costs", null)
           .accounts({
             authority: donor1.publicKey,
             campaign: campaignPDA,
+            withdrawal: withdrawalPDA,
             campaignVault: vaultPDA,
             recipient: recipientAccount.publicKey,
             systemProgram: SystemProgram.programId,
@@ -349,11 +402,14 @@ describe("Fund Management", () => {
       // Complete campaign
       await program.methods
         .updateCampaign({
+          title: null,
           description: null,
           imageUrl: null,
+          startDate: null,
           endDate: null,
           status: { inProgress: {} },
           isUrgent: null,
+          donationLimits: null,
         })
         .accounts({
           authority: campaignAuthority.publicKey,
@@ -365,11 +421,14 @@ describe("Fund Management", () => {
 
       await program.methods
         .updateCampaign({
+          title: null,
           description: null,
           imageUrl: null,
+          startDate: null,
           endDate: null,
           status: { completed: {} },
           isUrgent: null,
+          donationLimits: null,
         })
         .accounts({
           authority: campaignAuthority.publicKey,
@@ -381,11 +440,16 @@ describe("Fund Management", () => {
 
       try {
         const excessAmount = donation1Amount * 2;
+        const [withdrawalPDA] = await findNextWithdrawalPDA(
+          campaignPDA,
+          program
+        );
         await program.methods
-          .withdrawFunds(new anchor.BN(excessAmount))
+          .withdrawFunds(new anchor.BN(excessAmount), "Program costs", null)
           .accounts({
             authority: campaignAuthority.publicKey,
             campaign: campaignPDA,
+            withdrawal: withdrawalPDA,
             campaignVault: vaultPDA,
             recipient: recipientAccount.publicKey,
             systemProgram: SystemProgram.programId,
@@ -19,10 +19,12 @@ import {
 import {
   createAndFundAccount,
   findUserPDA,
-  findCampaignPDA,
+  findNextCampaignPDA,
+  findCampaignVaultPDA,
   createTestUser,
   createTestCampaignWithVault,
   makeTestDonation,
+  findNextWithdrawalPDA,
 } from "./utils/helpers";
 
 describe("Edge Cases", () => {
@@ -49,29 +51,37 @@ describe("Edge Cases", () => {
   describe("Campaign Edge Cases", () => {
     it("should fail to create campaign with exact minimum duration minus 1 second", async () => {
       try {
-        const startDate = getCurrentTimestamp();
+        const startDate = getCurrentTimestamp() - 60;
         const endDate = startDate + TEST_CONSTANTS.MIN_CAMPAIGN_DURATION - 1;
         const title = `TC${Date.now()}`;
 
         const [userPDA] = await findUserPDA(authority.publicKey, program);
-        const [campaignPDA] = await findCampaignPDA(
-          title,
+        const [campaignPDA] = await findNextCampaignPDA(
           authority.publicKey,
           program
         );
 
         await program.methods
-          .createCampaign(
+          .createCampaign({
             title,
-            "Description",
-            { healthcare: {} },
-            "Test Org",
-            new anchor.BN(TEST_CONSTANTS.MIN_CAMPAIGN_TARGET),
-            new anchor.BN(startDate),
-            new anchor.BN(endDate),
-            "image.jpg",
-            false
-          )
+            description: "Description",
+            category: { healthcare: {} },
+            organizationName: "Test Org",
+            targetAmount: new anchor.BN(TEST_CONSTANTS.MIN_CAMPAIGN_TARGET),
+            startDate: new anchor.BN(startDate),
+            endDate: new anchor.BN(endDate),
+            imageUrl: "image.jpg",
+            isUrgent: false,
+            allowUnsafeMintExtensions: false,
+            usdTarget: null,
+            fundingMode: { keepItAll: {} },
+            expiryBounty: new anchor.BN(0),
+            milestones: [],
+            voting: null,
+            beneficiaries: [authority.publicKey],
+            approvals: null,
+            donationLimits: { hardCap: null, maxPerDonor: null },
+          })
           .accounts({
             authority: authority.publicKey,
             user: userPDA,
@@ -89,29 +99,37 @@ describe("Edge Cases", () => {
 
     it("should fail to create campaign with exact maximum duration plus 1 second", async () => {
       try {
-        const startDate = getCurrentTimestamp();
+        const startDate = getCurrentTimestamp() - 60;
         const endDate = startDate + TEST_CONSTANTS.MAX_CAMPAIGN_DURATION + 1;
         const title = `TC${Date.now()}`;
 
         const [userPDA] = await findUserPDA(authority.publicKey, program);
-        const [campaignPDA] = await findCampaignPDA(
-          title,
+        const [campaignPDA] = await findNextCampaignPDA(
           authority.publicKey,
           program
         );
 
         await program.methods
-          .createCampaign(
+          .createCampaign({
             title,
-            "Description",
-            { healthcare: {} },
-            "Test Org",
-            new anchor.BN(TEST_CONSTANTS.MIN_CAMPAIGN_TARGET),
-            new anchor.BN(startDate),
-            new anchor.BN(endDate),
-            "image.jpg",
-            false
-          )
+            description: "Description",
+            category: { healthcare: {} },
+            organizationName: "Test Org",
+            targetAmount: new anchor.BN(TEST_CONSTANTS.MIN_CAMPAIGN_TARGET),
+            startDate: new anchor.BN(startDate),
+            endDate: new anchor.BN(endDate),
+            imageUrl: "image.jpg",
+            isUrgent: false,
+            allowUnsafeMintExtensions: false,
+            usdTarget: null,
+            fundingMode: { keepItAll: {} },
+            expiryBounty: new anchor.BN(0),
+            milestones: [],
+            voting: null,
+            beneficiaries: [authority.publicKey],
+            approvals: null,
+            donationLimits: { hardCap: null, maxPerDonor: null },
+          })
           .accounts({
             authority: authority.publicKey,
             user: userPDA,
@@ -131,24 +149,32 @@ describe("Edge Cases", () => {
       try {
         const title = `TC${Date.now()}`;
         const [userPDA] = await findUserPDA(authority.publicKey, program);
-        const [campaignPDA] = await findCampaignPDA(
-          title,
+        const [campaignPDA] = await findNextCampaignPDA(
           authority.publicKey,
           program
         );
 
         await program.methods
-          .createCampaign(
+          .createCampaign({
             title,
-            "Description",
-            { healthcare: {} },
-            "Test Org",
-            new anchor.BN(TEST_CONSTANTS.MIN_CAMPAIGN_TARGET - 1),
-            new anchor.BN(getCurrentTimestamp()),
-            new anchor.BN(getFutureTimestamp(30)),
-            "image.jpg",
-            false
-          )
+            description: "Description",
+            category: { healthcare: {} },
+            organizationName: "Test Org",
+            targetAmount: new anchor.BN(TEST_CONSTANTS.MIN_CAMPAIGN_TARGET - 1),
+            startDate: new anchor.BN(getCurrentTimestamp() - 60),
+            endDate: new anchor.BN(getFutureTimestamp(30)),
+            imageUrl: "image.jpg",
+            isUrgent: false,
+            allowUnsafeMintExtensions: false,
+            usdTarget: null,
+            fundingMode: { keepItAll: {} },
+            expiryBounty: new anchor.BN(0),
+            milestones: [],
+            voting: null,
+            beneficiaries: [authority.publicKey],
+            approvals: null,
+            donationLimits: { hardCap: null, maxPerDonor: null },
+          })
           .accounts({
             authority: authority.publicKey,
             user: userPDA,
@@ -227,24 +253,32 @@ describe("Edge Cases", () => {
 
         // Create campaign with explicit start and end dates
         const [userPDA] = await findUserPDA(authority.publicKey, program);
-        const [campaignPDA] = await findCampaignPDA(
-          title,
+        const [campaignPDA] = await findNextCampaignPDA(
           authority.publicKey,
           program
         );
 
         await program.methods
-          .createCampaign(
+          .createCampaign({
             title,
-            "Description",
-            { healthcare: {} },
-            "Test Org",
-            new anchor.BN(TEST_CONSTANTS.MIN_CAMPAIGN_TARGET),
-            new anchor.BN(startDate),
-            new anchor.BN(endDate),
-            "image.jpg",
-            false
-          )
+            description: "Description",
+            category: { healthcare: {} },
+            organizationName: "Test Org",
+            targetAmount: new anchor.BN(TEST_CONSTANTS.MIN_CAMPAIGN_TARGET),
+            startDate: new anchor.BN(startDate),
+            endDate: new anchor.BN(endDate),
+            imageUrl: "image.jpg",
+            isUrgent: false,
+            allowUnsafeMintExtensions: false,
+            usdTarget: null,
+            fundingMode: { keepItAll: {} },
+            expiryBounty: new anchor.BN(0),
+            milestones: [],
+            voting: null,
+            beneficiaries: [authority.publicKey],
+            approvals: null,
+            donationLimits: { hardCap: null, maxPerDonor: null },
+          })
           .accounts({
             authority: authority.publicKey,
             user: userPDA,
@@ -254,16 +288,8 @@ describe("Edge Cases", () => {
           .signers([authority])
           .rpc();
 
-        // Find vault PDA using the campaign title and authority after campaign is created
-        const [vaultPDA] = await PublicKey.findProgramAddressSync(
-          [
-            Buffer.from("campaign"),
-            Buffer.from(title),
-            authority.publicKey.toBuffer(),
-            Buffer.from("vault"),
-          ],
-          program.programId
-        );
+        // Find the vault PDA of the campaign that was just created
+        const [vaultPDA] = await findCampaignVaultPDA(campaignPDA, program);
 
         // Wait for 3 seconds to ensure campaign has ended
         await new Promise((resolve) => setTimeout(resolve, 3000));
@@ -304,11 +330,14 @@ describe("Edge Cases", () => {
       try {
         await program.methods
           .updateCampaign({
+            title: null,
             description: null,
             imageUrl: null,
+            startDate: null,
             endDate: null,
             status: { completed: {} },
             isUrgent: null,
+            donationLimits: null,
           })
           .accounts({
             authority: authority.publicKey,
@@ -326,11 +355,14 @@ describe("Edge Cases", () => {
       // Try to transition back from InProgress to Active
       await program.methods
         .updateCampaign({
+          title: null,
           description: null,
           imageUrl: null,
+          startDate: null,
           endDate: null,
           status: { inProgress: {} },
           isUrgent: null,
+          donationLimits: null,
         })
         .accounts({
           authority: authority.publicKey,
@@ -343,11 +375,14 @@ describe("Edge Cases", () => {
       try {
         await program.methods
           .updateCampaign({
+            title: null,
             description: null,
             imageUrl: null,
+            startDate: null,
             endDate: null,
             status: { active: {} },
             isUrgent: null,
+            donationLimits: null,
           })
           .accounts({
             authority: authority.publicKey,
@@ -386,11 +421,14 @@ describe("Edge Cases", () => {
         // Complete campaign
         await program.methods
           .updateCampaign({
+            title: null,
             description: null,
             imageUrl: null,
+            startDate: null,
             endDate: null,
             status: { inProgress: {} },
             isUrgent: null,
+            donationLimits: null,
           })
           .accounts({
             authority: authority.publicKey,
@@ -402,11 +440,14 @@ describe("Edge Cases", () => {
 
         await program.methods
           .updateCampaign({
+            title: null,
             description: null,
             imageUrl: null,
+            startDate: null,
             endDate: null,
             status: { completed: {} },
             isUrgent: null,
+            donationLimits: null,
           })
           .accounts({
             authority: authority.publicKey,
@@ -417,11 +458,20 @@ describe("Edge Cases", () => {
           .rpc();
 
         // Try to withdraw more than balance
+        const [withdrawalPDA] = await findNextWithdrawalPDA(
+          campaignPDA,
+          program
+        );
         await program.methods
-          .withdrawFunds(new anchor.BN(TEST_CONSTANTS.MIN_DONATION_AMOUNT + 1))
+          .withdrawFunds(
+            new anchor.BN(TEST_CONSTANTS.MIN_DONATION_AMOUNT + 1),
+            "Program costs",
+            null
+          )
           .accounts({
             authority: authority.publicKey,
             campaign: campaignPDA,
+            withdrawal: withdrawalPDA,
             campaignVault: vaultPDA,
             recipient: authority.publicKey,
             systemProgram: SystemProgram.programId,
@@ -454,11 +504,20 @@ describe("Edge Cases", () => {
         );
 
         // Try to withdraw
+        const [withdrawalPDA] = await findNextWithdrawalPDA(
+          campaignPDA,
+          program
+        );
         await program.methods
-          .withdrawFunds(new anchor.BN(TEST_CONSTANTS.MIN_DONATION_AMOUNT))
+          .withdrawFunds(
+            new anchor.BN(TEST_CONSTANTS.MIN_DONATION_AMOUNT),
+            "Program costs",
+            null
+          )
           .accounts({
             authority: authority.publicKey,
             campaign: campaignPDA,
+            withdrawal: withdrawalPDA,
             campaignVault: vaultPDA,
             recipient: authority.publicKey,
             systemProgram: SystemProgram.programId,
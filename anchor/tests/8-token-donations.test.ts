@@ -0,0 +1,324 @@
+// File: tests/8-token-donations.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotent,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import {
+  setupTest,
+  ERROR_MESSAGES,
+  getCurrentTimestamp,
+  getFutureTimestamp,
+} from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestMint,
+  createTestUser,
+  findNextCampaignPDA,
+  findCampaignVaultPDA,
+  findDonationPDA,
+  findDonorPositionPDA,
+  findUserPDA,
+  fundTokenAccount,
+  findNextWithdrawalPDA,
+} from "./utils/helpers";
+
+describe("Token Donations", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donor: Keypair;
+  let recipient: Keypair;
+  let mint: PublicKey;
+  let donorTokenAccount: PublicKey;
+
+  let campaignPDA: PublicKey;
+  let vaultPDA: PublicKey;
+  let vaultTokenAccount: PublicKey;
+
+  const title = "USDC Campaign";
+  const targetAmount = 1_000_000_000; // 1,000 tokens with 6 decimals
+  const donationAmount = 250_000_000;
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    recipient = await createAndFundAccount(connection, LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+
+    mint = await createTestMint(connection, authority);
+    donorTokenAccount = await fundTokenAccount(
+      connection,
+      authority,
+      mint,
+      donor.publicKey,
+      donationAmount * 4
+    );
+
+    [campaignPDA] = await findNextCampaignPDA(authority.publicKey, program);
+    [vaultPDA] = await findCampaignVaultPDA(campaignPDA, program);
+    vaultTokenAccount = getAssociatedTokenAddressSync(mint, vaultPDA, true);
+  });
+
+  it("should create a campaign that accepts an SPL mint", async () => {
+    const [userPDA] = await findUserPDA(authority.publicKey, program);
+
+    await program.methods
+      .createCampaign({
+        title,
+        description: "Raising in tokens",
+        category: { healthcare: {} },
+        organizationName: "Test Organization",
+        targetAmount: new anchor.BN(targetAmount),
+        startDate: new anchor.BN(getCurrentTimestamp() - 60),
+        endDate: new anchor.BN(getFutureTimestamp(30)),
+        imageUrl: "https://example.com/image.jpg",
+        isUrgent: false,
+        allowUnsafeMintExtensions: false,
+        usdTarget: null,
+        fundingMode: { keepItAll: {} },
+        expiryBounty: new anchor.BN(0),
+        milestones: [],
+        voting: null,
+        beneficiaries: [recipient.publicKey],
+        approvals: null,
+        donationLimits: { hardCap: null, maxPerDonor: null },
+      })
+      .accounts({
+        authority: authority.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        campaignVault: vaultPDA,
+        acceptedMint: mint,
+        vaultTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.acceptedMint.toBase58()).to.eq(mint.toBase58());
+
+    const vault = await getAccount(connection, vaultTokenAccount);
+    expect(vault.owner.toBase58()).to.eq(vaultPDA.toBase58());
+    expect(Number(vault.amount)).to.eq(0);
+  });
+
+  it("should move donated tokens into the vault token account", async () => {
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    const [donationPDA] = await findDonationPDA(campaignPDA, 0, program);
+
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      donor.publicKey,
+      program
+    );
+
+    await program.methods
+      .donate(new anchor.BN(donationAmount), { cryptoWallet: {} }, null, null)
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        donation: donationPDA,
+        donorPosition: donorPositionPDA,
+        campaignVault: vaultPDA,
+        mint,
+        donorTokenAccount,
+        vaultTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc();
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.raisedAmount.toNumber()).to.eq(donationAmount);
+
+    const donation = await program.account.donation.fetch(donationPDA);
+    expect(donation.mint.toBase58()).to.eq(mint.toBase58());
+    expect(donation.amount.toNumber()).to.eq(donationAmount);
+
+    const vault = await getAccount(connection, vaultTokenAccount);
+    expect(Number(vault.amount)).to.eq(donationAmount);
+
+    // Token donations don't count towards the lamport-denominated badge totals
+    const user = await program.account.user.fetch(userPDA);
+    expect(user.totalDonations.toNumber()).to.eq(0);
+  });
+
+  it("should reject a donation without token accounts", async () => {
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    const [donationPDA] = await findDonationPDA(campaignPDA, 1, program);
+
+    try {
+      const [donorPositionPDA] = await findDonorPositionPDA(
+        campaignPDA,
+        donor.publicKey,
+        program
+      );
+
+      await program.methods
+        .donate(new anchor.BN(donationAmount), { cryptoWallet: {} }, null, null)
+        .accounts({
+          donor: donor.publicKey,
+          user: userPDA,
+          campaign: campaignPDA,
+          donation: donationPDA,
+          donorPosition: donorPositionPDA,
+          campaignVault: vaultPDA,
+          mint: null,
+          donorTokenAccount: null,
+          vaultTokenAccount: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([donor])
+        .rpc();
+
+      expect.fail("Should require token accounts for a token campaign");
+    } catch (error) {
+      expect(error.toString()).to.include(
+        ERROR_MESSAGES.MISSING_TOKEN_ACCOUNTS
+      );
+    }
+  });
+
+  it("should pay out tokens on withdrawal", async () => {
+    for (const status of [{ inProgress: {} }, { completed: {} }]) {
+      await program.methods
+        .updateCampaign({
+          title: null,
+          description: null,
+          imageUrl: null,
+          startDate: null,
+          endDate: null,
+          status,
+          isUrgent: null,
+          donationLimits: null,
+        })
+        .accounts({
+          authority: authority.publicKey,
+          campaign: campaignPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    const recipientTokenAccount = await fundTokenAccount(
+      connection,
+      authority,
+      mint,
+      recipient.publicKey,
+      0
+    );
+
+    const [withdrawalPDA] = await findNextWithdrawalPDA(campaignPDA, program);
+    await program.methods
+      .withdrawFunds(new anchor.BN(donationAmount), "Program costs", null)
+      .accounts({
+        authority: authority.publicKey,
+        campaign: campaignPDA,
+        withdrawal: withdrawalPDA,
+        campaignVault: vaultPDA,
+        recipient: recipient.publicKey,
+        mint,
+        vaultTokenAccount,
+        recipientTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    const vault = await getAccount(connection, vaultTokenAccount);
+    expect(Number(vault.amount)).to.eq(0);
+
+    const recipientAccount = await getAccount(connection, recipientTokenAccount);
+    expect(Number(recipientAccount.amount)).to.eq(donationAmount);
+  });
+
+  it("should reuse a vault token account created ahead of the campaign", async () => {
+    const [userPDA] = await findUserPDA(authority.publicKey, program);
+    const [nextCampaignPDA] = await findNextCampaignPDA(
+      authority.publicKey,
+      program
+    );
+    const [nextVaultPDA] = await findCampaignVaultPDA(nextCampaignPDA, program);
+
+    // Anyone can derive the next vault and create its token account first
+    const nextVaultTokenAccount = await createAssociatedTokenAccountIdempotent(
+      connection,
+      donor,
+      mint,
+      nextVaultPDA,
+      {},
+      TOKEN_PROGRAM_ID,
+      ASSOCIATED_TOKEN_PROGRAM_ID,
+      true
+    );
+
+    await program.methods
+      .createCampaign({
+        title: "Second USDC Campaign",
+        description: "Raising in tokens again",
+        category: { healthcare: {} },
+        organizationName: "Test Organization",
+        targetAmount: new anchor.BN(targetAmount),
+        startDate: new anchor.BN(getCurrentTimestamp() - 60),
+        endDate: new anchor.BN(getFutureTimestamp(30)),
+        imageUrl: "https://example.com/image.jpg",
+        isUrgent: false,
+        allowUnsafeMintExtensions: false,
+        usdTarget: null,
+        fundingMode: { keepItAll: {} },
+        expiryBounty: new anchor.BN(0),
+        milestones: [],
+        voting: null,
+        beneficiaries: [recipient.publicKey],
+        approvals: null,
+        donationLimits: { hardCap: null, maxPerDonor: null },
+      })
+      .accounts({
+        authority: authority.publicKey,
+        user: userPDA,
+        campaign: nextCampaignPDA,
+        campaignVault: nextVaultPDA,
+        acceptedMint: mint,
+        vaultTokenAccount: nextVaultTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    const campaign = await program.account.campaign.fetch(nextCampaignPDA);
+    expect(campaign.acceptedMint.toBase58()).to.eq(mint.toBase58());
+
+    const vault = await getAccount(connection, nextVaultTokenAccount);
+    expect(vault.owner.toBase58()).to.eq(nextVaultPDA.toBase58());
+  });
+});
@@ -0,0 +1,264 @@
+// File: tests/9-token-2022.test.ts
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  getAccount,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import { Ripple } from "../target/types/ripple";
+import {
+  setupTest,
+  ERROR_MESSAGES,
+  getCurrentTimestamp,
+  getFutureTimestamp,
+} from "./utils/setup";
+import {
+  createAndFundAccount,
+  createTestUser,
+  createToken2022Mint,
+  findNextCampaignPDA,
+  findCampaignVaultPDA,
+  findDonationPDA,
+  findDonorPositionPDA,
+  findUserPDA,
+  fundTokenAccount,
+} from "./utils/helpers";
+
+describe("Token-2022 Donations", () => {
+  let program: Program<Ripple>;
+  let connection: anchor.web3.Connection;
+
+  let authority: Keypair;
+  let donor: Keypair;
+
+  before(async () => {
+    const ctx = await setupTest();
+    program = ctx.program;
+    connection = ctx.connection;
+
+    authority = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+    donor = await createAndFundAccount(connection, 10 * LAMPORTS_PER_SOL);
+
+    await createTestUser(program, authority);
+    await createTestUser(program, donor);
+  });
+
+  const createTokenCampaign = async (
+    title: string,
+    mint: PublicKey,
+    allowUnsafeMintExtensions: boolean,
+    maxPerDonor: number | null = null
+  ) => {
+    const [userPDA] = await findUserPDA(authority.publicKey, program);
+    const [campaignPDA] = await findNextCampaignPDA(
+      authority.publicKey,
+      program
+    );
+    const [vaultPDA] = await findCampaignVaultPDA(campaignPDA, program);
+    const vaultTokenAccount = getAssociatedTokenAddressSync(
+      mint,
+      vaultPDA,
+      true,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    await program.methods
+      .createCampaign({
+        title,
+        description: "Raising in a Token-2022 mint",
+        category: { healthcare: {} },
+        organizationName: "Test Organization",
+        targetAmount: new anchor.BN(1_000_000_000),
+        startDate: new anchor.BN(getCurrentTimestamp() - 60),
+        endDate: new anchor.BN(getFutureTimestamp(30)),
+        imageUrl: "https://example.com/image.jpg",
+        isUrgent: false,
+        allowUnsafeMintExtensions,
+        usdTarget: null,
+        fundingMode: { keepItAll: {} },
+        expiryBounty: new anchor.BN(0),
+        milestones: [],
+        voting: null,
+        beneficiaries: [authority.publicKey],
+        approvals: null,
+        donationLimits: {
+          hardCap: null,
+          maxPerDonor: maxPerDonor === null ? null : new anchor.BN(maxPerDonor),
+        },
+      })
+      .accounts({
+        authority: authority.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        campaignVault: vaultPDA,
+        acceptedMint: mint,
+        vaultTokenAccount,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    return { campaignPDA, vaultPDA, vaultTokenAccount };
+  };
+
+  it("should credit only the net amount received for transfer-fee mints", async () => {
+    // 1% transfer fee
+    const mint = await createToken2022Mint(connection, authority, {
+      transferFeeBasisPoints: 100,
+    });
+    const donorTokenAccount = await fundTokenAccount(
+      connection,
+      authority,
+      mint,
+      donor.publicKey,
+      1_000_000_000,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const { campaignPDA, vaultPDA, vaultTokenAccount } =
+      await createTokenCampaign("Fee Campaign", mint, false);
+
+    const donationAmount = 100_000_000;
+    const expectedNet = donationAmount - donationAmount / 100;
+
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    const [donationPDA] = await findDonationPDA(campaignPDA, 0, program);
+
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      donor.publicKey,
+      program
+    );
+
+    await program.methods
+      .donate(new anchor.BN(donationAmount), { cryptoWallet: {} }, null, null)
+      .accounts({
+        donor: donor.publicKey,
+        user: userPDA,
+        campaign: campaignPDA,
+        donation: donationPDA,
+        donorPosition: donorPositionPDA,
+        campaignVault: vaultPDA,
+        mint,
+        donorTokenAccount,
+        vaultTokenAccount,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([donor])
+      .rpc();
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.raisedAmount.toNumber()).to.eq(expectedNet);
+
+    const donation = await program.account.donation.fetch(donationPDA);
+    expect(donation.amount.toNumber()).to.eq(expectedNet);
+
+    const vault = await getAccount(
+      connection,
+      vaultTokenAccount,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(Number(vault.amount)).to.eq(expectedNet);
+  });
+
+  it("should hold donors to their limit on what the vault received", async () => {
+    // 1% transfer fee
+    const mint = await createToken2022Mint(connection, authority, {
+      transferFeeBasisPoints: 100,
+    });
+    const donorTokenAccount = await fundTokenAccount(
+      connection,
+      authority,
+      mint,
+      donor.publicKey,
+      1_000_000_000,
+      TOKEN_2022_PROGRAM_ID
+    );
+    const donationAmount = 100_000_000;
+    const expectedNet = donationAmount - donationAmount / 100;
+    const { campaignPDA, vaultPDA, vaultTokenAccount } =
+      await createTokenCampaign("Fee Limit Campaign", mint, false, expectedNet);
+
+    const [userPDA] = await findUserPDA(donor.publicKey, program);
+    const [donorPositionPDA] = await findDonorPositionPDA(
+      campaignPDA,
+      donor.publicKey,
+      program
+    );
+    const donate = async (amount: number, index: number) => {
+      const [donationPDA] = await findDonationPDA(campaignPDA, index, program);
+      await program.methods
+        .donate(new anchor.BN(amount), { cryptoWallet: {} }, null, null)
+        .accounts({
+          donor: donor.publicKey,
+          user: userPDA,
+          campaign: campaignPDA,
+          donation: donationPDA,
+          donorPosition: donorPositionPDA,
+          campaignVault: vaultPDA,
+          mint,
+          donorTokenAccount,
+          vaultTokenAccount,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([donor])
+        .rpc();
+    };
+
+    // The gross amount is over the limit, but the fee brings it down to it
+    await donate(donationAmount, 0);
+
+    const position = await program.account.donorPosition.fetch(
+      donorPositionPDA
+    );
+    expect(position.totalAmount.toNumber()).to.eq(expectedNet);
+
+    try {
+      await donate(2_000_000, 1);
+      expect.fail("Should not take the donor over their limit");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.DONOR_LIMIT_EXCEEDED);
+    }
+  });
+
+  it("should reject a permanent-delegate mint without opt-in", async () => {
+    const mint = await createToken2022Mint(connection, authority, {
+      permanentDelegate: authority.publicKey,
+    });
+
+    try {
+      await createTokenCampaign("Delegate Campaign", mint, false);
+      expect.fail("Should reject a mint with a permanent delegate");
+    } catch (error) {
+      expect(error.toString()).to.include(ERROR_MESSAGES.UNSAFE_MINT_EXTENSION);
+    }
+  });
+
+  it("should accept a permanent-delegate mint when the campaign opts in", async () => {
+    const mint = await createToken2022Mint(connection, authority, {
+      permanentDelegate: authority.publicKey,
+    });
+
+    const { campaignPDA } = await createTokenCampaign(
+      "Delegate Opt In",
+      mint,
+      true
+    );
+
+    const campaign = await program.account.campaign.fetch(campaignPDA);
+    expect(campaign.allowUnsafeMintExtensions).to.eq(true);
+  });
+});
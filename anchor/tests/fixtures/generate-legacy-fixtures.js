@@ -0,0 +1,288 @@
+// File: tests/fixtures/generate-legacy-fixtures.js
+// Writes the accounts that 29-legacy-migration.test.ts loads into the test
+// validator: a user profile, campaign and vault in the layouts used before
+// campaign addresses were derived from a per-authority counter, owned by a
+// fixed authority.
+//
+// Run with `node tests/fixtures/generate-legacy-fixtures.js` from anchor/.
+// Depends on nothing but Node so it can run before dependencies are installed.
+const crypto = require("crypto");
+const fs = require("fs");
+const path = require("path");
+
+const PROGRAM_ID = "BHhjYYFgpQjUDx4RL7ge923gZeJ3vyQScHBwYDCFSkd7";
+const SYSTEM_PROGRAM_ID = "11111111111111111111111111111111";
+const LAMPORTS_PER_SOL = 1_000_000_000;
+
+const LEGACY_CAMPAIGN = {
+  title: "Legacy Appeal",
+  description: "Raised before campaign addresses used a counter",
+  category: 1, // Education
+  organizationName: "Test Organization",
+  targetAmount: 2 * LAMPORTS_PER_SOL,
+  raisedAmount: LAMPORTS_PER_SOL / 2,
+  donorsCount: 2,
+  startDate: 1735689600, // 2025-01-01
+  endDate: 1743465600, // 2025-04-01
+  status: 0, // Active
+  imageUrl: "https://example.com/image.jpg",
+  isUrgent: false,
+};
+// 8-byte discriminator plus the legacy `Campaign::INIT_SPACE`
+const LEGACY_CAMPAIGN_SPACE = 8 + 1488;
+
+const LEGACY_USER = {
+  name: "Legacy Organizer",
+  email: "",
+  avatarUrl: "",
+  totalDonations: LAMPORTS_PER_SOL,
+  campaignsSupported: 1,
+  impactMetrics: [0, 0, 0, 0],
+  badges: [
+    {
+      badgeType: 2, // Bronze
+      description: "Donated 1 SOL",
+      imageUrl: "https://example.com/bronze.png",
+      dateEarned: 1735689600,
+    },
+  ],
+  rank: 0,
+};
+// 8-byte discriminator plus the legacy `User::INIT_SPACE`
+const LEGACY_USER_SPACE = 8 + 2548;
+
+// --- base58 ---
+
+const ALPHABET = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const encodeBase58 = (bytes) => {
+  let value = BigInt("0x" + (Buffer.from(bytes).toString("hex") || "0"));
+  let out = "";
+  while (value > 0n) {
+    out = ALPHABET[Number(value % 58n)] + out;
+    value /= 58n;
+  }
+  for (const byte of bytes) {
+    if (byte !== 0) break;
+    out = "1" + out;
+  }
+  return out;
+};
+
+const decodeBase58 = (text) => {
+  let value = 0n;
+  for (const char of text) {
+    value = value * 58n + BigInt(ALPHABET.indexOf(char));
+  }
+  const hex = value === 0n ? "" : value.toString(16).padStart(64, "0");
+  const bytes = Buffer.from(hex, "hex");
+  const zeros = text.length - text.replace(/^1+/, "").length;
+  return Buffer.concat([Buffer.alloc(zeros), bytes]).subarray(-32);
+};
+
+// --- program addresses ---
+
+const P = 2n ** 255n - 19n;
+const modPow = (base, exp) => {
+  let result = 1n;
+  base %= P;
+  while (exp > 0n) {
+    if (exp & 1n) result = (result * base) % P;
+    base = (base * base) % P;
+    exp >>= 1n;
+  }
+  return result;
+};
+const D = (((-121665n * modPow(121666n, P - 2n)) % P) + P) % P;
+
+// Whether `bytes` decompress to an ed25519 point, i.e. could have a private key
+const isOnCurve = (bytes) => {
+  const le = Buffer.from(bytes).reverse();
+  le[0] &= 0x7f;
+  const y = BigInt("0x" + le.toString("hex"));
+  if (y >= P) return false;
+  const y2 = (y * y) % P;
+  const u = (y2 - 1n + P) % P;
+  const v = (D * y2 + 1n) % P;
+  const x2 = (u * modPow(v, P - 2n)) % P;
+  return x2 === 0n || modPow(x2, (P - 1n) / 2n) === 1n;
+};
+
+const findProgramAddress = (seeds, programId) => {
+  for (let bump = 255; bump >= 0; bump--) {
+    const hash = crypto
+      .createHash("sha256")
+      .update(
+        Buffer.concat([
+          ...seeds,
+          Buffer.from([bump]),
+          decodeBase58(programId),
+          Buffer.from("ProgramDerivedAddress"),
+        ])
+      )
+      .digest();
+    if (!isOnCurve(hash)) return [encodeBase58(hash), bump];
+  }
+  throw new Error("No viable bump");
+};
+
+// --- borsh ---
+
+const u8 = (value) => Buffer.from([value]);
+const u32 = (value) => {
+  const buf = Buffer.alloc(4);
+  buf.writeUInt32LE(value);
+  return buf;
+};
+const u64 = (value) => {
+  const buf = Buffer.alloc(8);
+  buf.writeBigUInt64LE(BigInt(value));
+  return buf;
+};
+const i64 = (value) => {
+  const buf = Buffer.alloc(8);
+  buf.writeBigInt64LE(BigInt(value));
+  return buf;
+};
+const string = (value) =>
+  Buffer.concat([u32(Buffer.byteLength(value)), Buffer.from(value)]);
+const discriminator = (account) =>
+  crypto
+    .createHash("sha256")
+    .update(`account:${account}`)
+    .digest()
+    .subarray(0, 8);
+
+// --- fixtures ---
+
+const authoritySeed = crypto
+  .createHash("sha256")
+  .update("ripple legacy authority")
+  .digest();
+const authorityPublicKey = crypto
+  .createPublicKey(
+    crypto.createPrivateKey({
+      key: Buffer.concat([
+        Buffer.from("302e020100300506032b657004220420", "hex"),
+        authoritySeed,
+      ]),
+      format: "der",
+      type: "pkcs8",
+    })
+  )
+  .export({ format: "der", type: "spki" })
+  .subarray(-32);
+const authority = encodeBase58(authorityPublicKey);
+
+const [user, userBump] = findProgramAddress(
+  [Buffer.from("user"), authorityPublicKey],
+  PROGRAM_ID
+);
+const [campaign, campaignBump] = findProgramAddress(
+  [
+    Buffer.from("campaign"),
+    Buffer.from(LEGACY_CAMPAIGN.title),
+    authorityPublicKey,
+  ],
+  PROGRAM_ID
+);
+const [vault] = findProgramAddress(
+  [
+    Buffer.from("campaign"),
+    Buffer.from(LEGACY_CAMPAIGN.title),
+    authorityPublicKey,
+    Buffer.from("vault"),
+  ],
+  PROGRAM_ID
+);
+
+const userData = Buffer.alloc(LEGACY_USER_SPACE);
+Buffer.concat([
+  discriminator("User"),
+  authorityPublicKey,
+  string(LEGACY_USER.name),
+  authorityPublicKey,
+  string(LEGACY_USER.email),
+  string(LEGACY_USER.avatarUrl),
+  u64(LEGACY_USER.totalDonations),
+  u32(LEGACY_USER.campaignsSupported),
+  ...LEGACY_USER.impactMetrics.map(u32),
+  u32(LEGACY_USER.badges.length),
+  ...LEGACY_USER.badges.flatMap((badge) => [
+    u8(badge.badgeType),
+    string(badge.description),
+    string(badge.imageUrl),
+    i64(badge.dateEarned),
+  ]),
+  u32(LEGACY_USER.rank),
+  u8(userBump),
+]).copy(userData);
+
+const campaignData = Buffer.alloc(LEGACY_CAMPAIGN_SPACE);
+Buffer.concat([
+  discriminator("Campaign"),
+  authorityPublicKey,
+  string(LEGACY_CAMPAIGN.title),
+  string(LEGACY_CAMPAIGN.description),
+  u8(LEGACY_CAMPAIGN.category),
+  string(LEGACY_CAMPAIGN.organizationName),
+  u64(LEGACY_CAMPAIGN.targetAmount),
+  u64(LEGACY_CAMPAIGN.raisedAmount),
+  u32(LEGACY_CAMPAIGN.donorsCount),
+  i64(LEGACY_CAMPAIGN.startDate),
+  i64(LEGACY_CAMPAIGN.endDate),
+  u8(LEGACY_CAMPAIGN.status),
+  string(LEGACY_CAMPAIGN.imageUrl),
+  u8(LEGACY_CAMPAIGN.isUrgent ? 1 : 0),
+  u8(campaignBump),
+]).copy(campaignData);
+
+// Rent exemption for `space` bytes at the default rent rate
+const rentExempt = (space) => (128 + space) * 3480 * 2;
+
+const accountFixture = (pubkey, lamports, data, owner) => ({
+  pubkey,
+  account: {
+    lamports,
+    data: [data.toString("base64"), "base64"],
+    owner,
+    executable: false,
+    rentEpoch: 0,
+    space: data.length,
+  },
+});
+
+const write = (name, value) =>
+  fs.writeFileSync(
+    path.join(__dirname, name),
+    JSON.stringify(value, null, 2) + "\n"
+  );
+
+write(
+  "legacy-authority.json",
+  Array.from(Buffer.concat([authoritySeed, authorityPublicKey]))
+);
+write(
+  "legacy-user.json",
+  accountFixture(user, rentExempt(LEGACY_USER_SPACE), userData, PROGRAM_ID)
+);
+write(
+  "legacy-campaign.json",
+  accountFixture(
+    campaign,
+    rentExempt(LEGACY_CAMPAIGN_SPACE),
+    campaignData,
+    PROGRAM_ID
+  )
+);
+write(
+  "legacy-vault.json",
+  accountFixture(
+    vault,
+    LEGACY_CAMPAIGN.raisedAmount,
+    Buffer.alloc(0),
+    SYSTEM_PROGRAM_ID
+  )
+);
+
+console.log({ authority, user, campaign, vault });
@@ -6,7 +6,21 @@ import {
   PublicKey,
   SystemProgram,
   LAMPORTS_PER_SOL,
+  Transaction,
+  sendAndConfirmTransaction,
 } from "@solana/web3.js";
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createInitializeMintInstruction,
+  createInitializePermanentDelegateInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMint,
+  getMintLen,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
 import { Ripple } from "../../target/types/ripple";
 import { getCurrentTimestamp, getFutureTimestamp } from "./setup";
 
@@ -22,49 +36,249 @@ export const findUserPDA = async (
 };
 
 export const findCampaignPDA = async (
-  title: string,
   authority: PublicKey,
+  campaignId: number,
   program: Program<Ripple>
 ): Promise<[PublicKey, number]> => {
   return await PublicKey.findProgramAddressSync(
-    [Buffer.from("campaign"), Buffer.from(title), authority.toBuffer()],
+    [
+      Buffer.from("campaign"),
+      authority.toBuffer(),
+      new anchor.BN(campaignId).toArrayLike(Buffer, "le", 8),
+    ],
     program.programId
   );
 };
 
-export const findCampaignVaultPDA = async (
-  title: string,
+// Address the authority's next campaign will be created at
+export const findNextCampaignPDA = async (
   authority: PublicKey,
   program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  const [userPDA] = await findUserPDA(authority, program);
+  const user = await program.account.user.fetch(userPDA);
+  return await findCampaignPDA(
+    authority,
+    user.campaignsCreated.toNumber(),
+    program
+  );
+};
+
+export const findCampaignVaultPDA = async (
+  campaign: PublicKey,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddressSync(
+    [Buffer.from("vault"), campaign.toBuffer()],
+    program.programId
+  );
+};
+
+export const findCampaignRolePDA = async (
+  campaign: PublicKey,
+  member: PublicKey,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddressSync(
+    [Buffer.from("campaign_role"), campaign.toBuffer(), member.toBuffer()],
+    program.programId
+  );
+};
+
+export const findDonationPDA = async (
+  campaign: PublicKey,
+  index: number,
+  program: Program<Ripple>
 ): Promise<[PublicKey, number]> => {
   return await PublicKey.findProgramAddressSync(
     [
-      Buffer.from("campaign"),
-      Buffer.from(title),
-      authority.toBuffer(),
-      Buffer.from("vault"),
+      Buffer.from("donation"),
+      campaign.toBuffer(),
+      new anchor.BN(index).toArrayLike(Buffer, "le", 8),
     ],
     program.programId
   );
 };
 
-export const findDonationPDA = async (
+// Address the campaign's next donation will be created at
+export const findNextDonationPDA = async (
+  campaign: PublicKey,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  const campaignAccount = await program.account.campaign.fetch(campaign);
+  return await findDonationPDA(
+    campaign,
+    campaignAccount.donationCount.toNumber(),
+    program
+  );
+};
+
+export const findDonorPositionPDA = async (
   campaign: PublicKey,
   donor: PublicKey,
-  countInString: string,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddressSync(
+    [Buffer.from("donor_position"), campaign.toBuffer(), donor.toBuffer()],
+    program.programId
+  );
+};
+
+export const findMilestoneVotePDA = async (
+  campaign: PublicKey,
+  index: number,
+  voter: PublicKey,
   program: Program<Ripple>
 ): Promise<[PublicKey, number]> => {
   return await PublicKey.findProgramAddressSync(
     [
-      Buffer.from("donation"),
+      Buffer.from("milestone_vote"),
       campaign.toBuffer(),
+      Buffer.from([index]),
+      voter.toBuffer(),
+    ],
+    program.programId
+  );
+};
+
+export const findWithdrawalPDA = async (
+  campaign: PublicKey,
+  sequence: number,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("withdrawal"),
+      campaign.toBuffer(),
+      new anchor.BN(sequence).toArrayLike(Buffer, "le", 8),
+    ],
+    program.programId
+  );
+};
+
+// The withdrawal PDA the campaign's next withdrawal will be recorded at
+export const findNextWithdrawalPDA = async (
+  campaign: PublicKey,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  const campaignAccount = await program.account.campaign.fetch(campaign);
+  return await findWithdrawalPDA(
+    campaign,
+    campaignAccount.withdrawalCount.toNumber(),
+    program
+  );
+};
+
+export const findWithdrawalProposalPDA = async (
+  campaign: PublicKey,
+  index: number,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("withdrawal_proposal"),
+      campaign.toBuffer(),
+      new anchor.BN(index).toArrayLike(Buffer, "le", 8),
+    ],
+    program.programId
+  );
+};
+
+export const findNextWithdrawalProposalPDA = async (
+  campaign: PublicKey,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  const campaignAccount = await program.account.campaign.fetch(campaign);
+  return await findWithdrawalProposalPDA(
+    campaign,
+    campaignAccount.withdrawalProposalCount.toNumber(),
+    program
+  );
+};
+
+export const findSubscriptionPDA = async (
+  donor: PublicKey,
+  id: number,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("subscription"),
       donor.toBuffer(),
-      Buffer.from(countInString),
+      new anchor.BN(id).toArrayLike(Buffer, "le", 8),
+    ],
+    program.programId
+  );
+};
+
+export const findMatchingPoolPDA = async (
+  sponsor: PublicKey,
+  id: number,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("matching_pool"),
+      sponsor.toBuffer(),
+      new anchor.BN(id).toArrayLike(Buffer, "le", 8),
+    ],
+    program.programId
+  );
+};
+
+export const findFundingRoundPDA = async (
+  admin: PublicKey,
+  id: number,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("funding_round"),
+      admin.toBuffer(),
+      new anchor.BN(id).toArrayLike(Buffer, "le", 8),
     ],
     program.programId
   );
 };
 
+export const findRoundParticipantPDA = async (
+  round: PublicKey,
+  campaign: PublicKey,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddressSync(
+    [Buffer.from("round_participant"), round.toBuffer(), campaign.toBuffer()],
+    program.programId
+  );
+};
+
+export const findRoundContributionPDA = async (
+  round: PublicKey,
+  campaign: PublicKey,
+  donor: PublicKey,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("round_contribution"),
+      round.toBuffer(),
+      campaign.toBuffer(),
+      donor.toBuffer(),
+    ],
+    program.programId
+  );
+};
+
+export const findMockPriceFeedPDA = async (
+  authority: PublicKey,
+  program: Program<Ripple>
+): Promise<[PublicKey, number]> => {
+  return await PublicKey.findProgramAddressSync(
+    [Buffer.from("mock_price_feed"), authority.toBuffer()],
+    program.programId
+  );
+};
+
 export const createAndFundAccount = async (
   connection: anchor.web3.Connection,
   lamports: number = 100 * LAMPORTS_PER_SOL
@@ -114,6 +328,27 @@ export type CampaignCreationParams = {
   daysToRun?: number;
   imageUrl?: string;
   isUrgent?: boolean;
+  startDate?: number;
+  endDate?: number;
+  fundingMode?: { keepItAll: {} } | { allOrNothing: {} };
+  expiryBounty?: number;
+  milestones?: {
+    description: string;
+    amount: { fixed: [anchor.BN] } | { percentage: [number] };
+    deadline: anchor.BN;
+  }[];
+  voting?: {
+    votingPeriod: anchor.BN;
+    quorumBps: number;
+    approvalThresholdBps: number;
+  } | null;
+  // Defaults to the authority
+  beneficiaries?: PublicKey[];
+  approvals?: { signers: PublicKey[]; threshold: number } | null;
+  donationLimits?: {
+    hardCap: anchor.BN | null;
+    maxPerDonor: anchor.BN | null;
+  };
 };
 
 export const createTestCampaign = async (
@@ -130,31 +365,45 @@ export const createTestCampaign = async (
     daysToRun: 30,
     imageUrl: "https://example.com/image.jpg",
     isUrgent: false,
+    fundingMode: { keepItAll: {} },
+    expiryBounty: 0,
+    milestones: [],
+    voting: null,
+    beneficiaries: [authority.publicKey],
+    approvals: null,
+    donationLimits: { hardCap: null, maxPerDonor: null },
     ...params,
   };
 
-  const startDate = getCurrentTimestamp();
-  const endDate = getFutureTimestamp(defaultParams.daysToRun);
+  // Backdated a little so the campaign is open even if the validator's clock lags
+  const startDate = defaultParams.startDate ?? getCurrentTimestamp() - 60;
+  const endDate =
+    defaultParams.endDate ?? getFutureTimestamp(defaultParams.daysToRun);
 
   const [userPDA] = await findUserPDA(authority.publicKey, program);
-  const [campaignPDA] = await findCampaignPDA(
-    defaultParams.title,
-    authority.publicKey,
-    program
-  );
+  const [campaignPDA] = await findNextCampaignPDA(authority.publicKey, program);
 
   await program.methods
-    .createCampaign(
-      defaultParams.title,
-      defaultParams.description,
-      defaultParams.category,
-      defaultParams.organizationName,
-      new anchor.BN(defaultParams.targetAmount),
-      new anchor.BN(startDate),
-      new anchor.BN(endDate),
-      defaultParams.imageUrl,
-      defaultParams.isUrgent
-    )
+    .createCampaign({
+      title: defaultParams.title,
+      description: defaultParams.description,
+      category: defaultParams.category,
+      organizationName: defaultParams.organizationName,
+      targetAmount: new anchor.BN(defaultParams.targetAmount),
+      startDate: new anchor.BN(startDate),
+      endDate: new anchor.BN(endDate),
+      imageUrl: defaultParams.imageUrl,
+      isUrgent: defaultParams.isUrgent,
+      allowUnsafeMintExtensions: false,
+      usdTarget: null,
+      fundingMode: defaultParams.fundingMode,
+      expiryBounty: new anchor.BN(defaultParams.expiryBounty),
+      milestones: defaultParams.milestones,
+      voting: defaultParams.voting,
+      beneficiaries: defaultParams.beneficiaries,
+      approvals: defaultParams.approvals,
+      donationLimits: defaultParams.donationLimits,
+    })
     .accounts({
       authority: authority.publicKey,
       user: userPDA,
@@ -181,37 +430,47 @@ export const createTestCampaignWithVault = async (
     daysToRun: 30,
     imageUrl: "https://example.com/image.jpg",
     isUrgent: false,
+    fundingMode: { keepItAll: {} },
+    expiryBounty: 0,
+    milestones: [],
+    voting: null,
+    beneficiaries: [authority.publicKey],
+    approvals: null,
+    donationLimits: { hardCap: null, maxPerDonor: null },
     ...params,
   };
 
-  const startDate = getCurrentTimestamp();
-  const endDate = getFutureTimestamp(defaultParams.daysToRun);
+  // Backdated a little so the campaign is open even if the validator's clock lags
+  const startDate = defaultParams.startDate ?? getCurrentTimestamp() - 60;
+  const endDate =
+    defaultParams.endDate ?? getFutureTimestamp(defaultParams.daysToRun);
 
   const [userPDA] = await findUserPDA(authority.publicKey, program);
-  const [campaignPDA] = await findCampaignPDA(
-    defaultParams.title,
-    authority.publicKey,
-    program
-  );
+  const [campaignPDA] = await findNextCampaignPDA(authority.publicKey, program);
 
-  const [vaultPDA] = await findCampaignVaultPDA(
-    defaultParams.title,
-    authority.publicKey,
-    program
-  );
+  const [vaultPDA] = await findCampaignVaultPDA(campaignPDA, program);
 
   await program.methods
-    .createCampaign(
-      defaultParams.title,
-      defaultParams.description,
-      defaultParams.category,
-      defaultParams.organizationName,
-      new anchor.BN(defaultParams.targetAmount),
-      new anchor.BN(startDate),
-      new anchor.BN(endDate),
-      defaultParams.imageUrl,
-      defaultParams.isUrgent
-    )
+    .createCampaign({
+      title: defaultParams.title,
+      description: defaultParams.description,
+      category: defaultParams.category,
+      organizationName: defaultParams.organizationName,
+      targetAmount: new anchor.BN(defaultParams.targetAmount),
+      startDate: new anchor.BN(startDate),
+      endDate: new anchor.BN(endDate),
+      imageUrl: defaultParams.imageUrl,
+      isUrgent: defaultParams.isUrgent,
+      allowUnsafeMintExtensions: false,
+      usdTarget: null,
+      fundingMode: defaultParams.fundingMode,
+      expiryBounty: new anchor.BN(defaultParams.expiryBounty),
+      milestones: defaultParams.milestones,
+      voting: defaultParams.voting,
+      beneficiaries: defaultParams.beneficiaries,
+      approvals: defaultParams.approvals,
+      donationLimits: defaultParams.donationLimits,
+    })
     .accounts({
       authority: authority.publicKey,
       user: userPDA,
@@ -232,26 +491,22 @@ export const makeTestDonation = async (
   campaignVault: PublicKey
 ): Promise<PublicKey> => {
   const [userPDA] = await findUserPDA(donor.publicKey, program);
-  const campaignAccount = await program.account.campaign.fetch(campaign);
+  const [donationPDA] = await findNextDonationPDA(campaign, program);
 
-  const [donationPDA] = await findDonationPDA(
+  const [donorPositionPDA] = await findDonorPositionPDA(
     campaign,
     donor.publicKey,
-    campaignAccount.donorsCount.toString(),
     program
   );
 
   await program.methods
-    .donate(
-      new anchor.BN(amount),
-      { cryptoWallet: {} },
-      campaignAccount.donorsCount.toString()
-    )
+    .donate(new anchor.BN(amount), { cryptoWallet: {} }, null, null)
     .accounts({
       donor: donor.publicKey,
       user: userPDA,
       campaign: campaign,
       donation: donationPDA,
+      donorPosition: donorPositionPDA,
       campaignVault: campaignVault,
       systemProgram: SystemProgram.programId,
     })
@@ -281,3 +536,153 @@ export const verifyBadgeAward = async (
       JSON.stringify(badge.badgeType) === JSON.stringify(expectedBadgeType)
   );
 };
+
+// Token helpers
+export const createTestMint = async (
+  connection: anchor.web3.Connection,
+  payer: Keypair,
+  decimals: number = 6
+): Promise<PublicKey> => {
+  return await createMint(connection, payer, payer.publicKey, null, decimals);
+};
+
+export type Token2022MintOptions = {
+  decimals?: number;
+  transferFeeBasisPoints?: number;
+  permanentDelegate?: PublicKey;
+};
+
+export const createToken2022Mint = async (
+  connection: anchor.web3.Connection,
+  payer: Keypair,
+  options: Token2022MintOptions = {}
+): Promise<PublicKey> => {
+  const mint = Keypair.generate();
+  const extensions: ExtensionType[] = [];
+  if (options.transferFeeBasisPoints !== undefined) {
+    extensions.push(ExtensionType.TransferFeeConfig);
+  }
+  if (options.permanentDelegate) {
+    extensions.push(ExtensionType.PermanentDelegate);
+  }
+
+  const mintLen = getMintLen(extensions);
+  const lamports = await connection.getMinimumBalanceForRentExemption(mintLen);
+  const transaction = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: payer.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space: mintLen,
+      lamports,
+      programId: TOKEN_2022_PROGRAM_ID,
+    })
+  );
+
+  if (options.transferFeeBasisPoints !== undefined) {
+    transaction.add(
+      createInitializeTransferFeeConfigInstruction(
+        mint.publicKey,
+        payer.publicKey,
+        payer.publicKey,
+        options.transferFeeBasisPoints,
+        BigInt(Number.MAX_SAFE_INTEGER),
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+  }
+  if (options.permanentDelegate) {
+    transaction.add(
+      createInitializePermanentDelegateInstruction(
+        mint.publicKey,
+        options.permanentDelegate,
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+  }
+
+  transaction.add(
+    createInitializeMintInstruction(
+      mint.publicKey,
+      options.decimals ?? 6,
+      payer.publicKey,
+      null,
+      TOKEN_2022_PROGRAM_ID
+    )
+  );
+
+  await sendAndConfirmTransaction(connection, transaction, [payer, mint]);
+  return mint.publicKey;
+};
+
+export const fundTokenAccount = async (
+  connection: anchor.web3.Connection,
+  mintAuthority: Keypair,
+  mint: PublicKey,
+  owner: PublicKey,
+  amount: number,
+  tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<PublicKey> => {
+  const tokenAccount = await getOrCreateAssociatedTokenAccount(
+    connection,
+    mintAuthority,
+    mint,
+    owner,
+    false,
+    undefined,
+    undefined,
+    tokenProgram
+  );
+  if (amount > 0) {
+    await mintTo(
+      connection,
+      mintAuthority,
+      mint,
+      tokenAccount.address,
+      mintAuthority,
+      amount,
+      [],
+      undefined,
+      tokenProgram
+    );
+  }
+  return tokenAccount.address;
+};
+
+// Events are emitted through a self-CPI, so they are read back from the
+// transaction's inner instructions rather than its logs
+const EVENT_IX_TAG = Buffer.from([
+  0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d,
+]);
+
+export type ProgramEvent = { name: string; data: any };
+
+export const fetchCpiEvents = async (
+  program: Program<Ripple>,
+  signature: string
+): Promise<ProgramEvent[]> => {
+  const tx = await program.provider.connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  const accountKeys = tx.transaction.message.getAccountKeys().staticAccountKeys;
+
+  const events: ProgramEvent[] = [];
+  for (const inner of tx.meta.innerInstructions ?? []) {
+    for (const ix of inner.instructions) {
+      if (!accountKeys[ix.programIdIndex].equals(program.programId)) {
+        continue;
+      }
+      const data = Buffer.from(anchor.utils.bytes.bs58.decode(ix.data));
+      if (!data.subarray(0, 8).equals(EVENT_IX_TAG)) {
+        continue;
+      }
+      const event = program.coder.events.decode(
+        anchor.utils.bytes.base64.encode(data.subarray(8))
+      );
+      if (event) {
+        events.push(event);
+      }
+    }
+  }
+  return events;
+};
@@ -24,6 +24,10 @@ export const getCurrentTimestamp = (): number => {
   return Math.floor(Date.now() / 1000);
 };
 
+export const sleep = (ms: number): Promise<void> => {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+};
+
 export const getFutureTimestamp = (daysFromNow: number): number => {
   return getCurrentTimestamp() + daysFromNow * 24 * 60 * 60;
 };
@@ -33,6 +37,7 @@ export const TEST_CONSTANTS = {
   MAX_CAMPAIGN_DURATION: 90 * 24 * 60 * 60,
   MIN_CAMPAIGN_TARGET: 0.1 * LAMPORTS_PER_SOL,
   MIN_DONATION_AMOUNT: 0.001 * LAMPORTS_PER_SOL,
+  MAX_TITLE_LENGTH: 100,
   BADGE_THRESHOLDS: {
     BRONZE: 1 * LAMPORTS_PER_SOL,
     SILVER: 5 * LAMPORTS_PER_SOL,
@@ -43,7 +48,7 @@ export const TEST_CONSTANTS = {
 };
 
 export const ERROR_MESSAGES = {
-  TITLE_TOO_LONG: "Error: Max seed length exceeded",
+  TITLE_TOO_LONG: "Error Code: TitleTooLong",
   DESCRIPTION_TOO_LONG: "custom program error: 0x1772",
   CAMPAIGN_DURATION_TOO_SHORT: "Error Code: CampaignDurationTooShort",
   CAMPAIGN_DURATION_TOO_LONG: "Error Code: CampaignDurationTooLong",
@@ -57,4 +62,53 @@ export const ERROR_MESSAGES = {
   NAME_TOO_LONG:
     "Error Code: NameTooLong. Error Number: 6013. Error Message: Maximum name length exceeded",
   EMAIL_TOO_LONG: "custom program error: 0x177E",
+  MISSING_TOKEN_ACCOUNTS: "Error Code: MissingTokenAccounts",
+  UNSAFE_MINT_EXTENSION: "Error Code: UnsafeMintExtension",
+  STALE_PRICE: "Error Code: StalePrice",
+  PRICE_CONFIDENCE_TOO_WIDE: "Error Code: PriceConfidenceTooWide",
+  FUNDING_GOAL_NOT_REACHED: "Error Code: FundingGoalNotReached",
+  REFUND_NOT_AVAILABLE: "Error Code: RefundNotAvailable",
+  DONATION_NOT_REFUNDABLE: "Error Code: DonationNotRefundable",
+  CAMPAIGN_CANCELLED: "Error Code: CampaignCancelled",
+  CAMPAIGN_NOT_ENDED: "Error Code: CampaignNotEnded",
+  INVALID_MILESTONE: "Error Code: InvalidMilestone",
+  MILESTONE_ALREADY_COMPLETED: "Error Code: MilestoneAlreadyCompleted",
+  MILESTONE_NOT_REACHED: "Error Code: MilestoneNotReached",
+  INVALID_VOTE_WEIGHT: "Error Code: InvalidVoteWeight",
+  VOTING_STILL_OPEN: "Error Code: VotingStillOpen",
+  SUBSCRIPTION_NOT_ACTIVE: "Error Code: SubscriptionNotActive",
+  SUBSCRIPTION_NOT_DUE: "Error Code: SubscriptionNotDue",
+  INVALID_SUBSCRIPTION_TARGET: "Error Code: InvalidSubscriptionTarget",
+  MATCHING_POOL_NOT_ELIGIBLE: "Error Code: MatchingPoolNotEligible",
+  MATCHING_POOL_ACTIVE: "Error Code: MatchingPoolActive",
+  ROUND_NOT_ENDED: "Error Code: RoundNotEnded",
+  DONATION_ALREADY_TALLIED: "Error Code: DonationAlreadyTallied",
+  DEDICATION_TOO_LONG: "Error Code: DedicationTooLong",
+  INVALID_HONOREE: "Error Code: InvalidHonoree",
+  INVALID_COMMITMENT: "Error Code: InvalidCommitment",
+  CAMPAIGN_NOT_STARTED: "Error Code: CampaignNotStarted",
+  INVALID_START_DATE: "Error Code: InvalidStartDate",
+  CAMPAIGN_ALREADY_STARTED: "Error Code: CampaignAlreadyStarted",
+  CAMPAIGN_PAUSED: "Error Code: CampaignPaused",
+  CAMPAIGN_NOT_PAUSED: "Error Code: CampaignNotPaused",
+  NOT_PENDING_AUTHORITY: "Error Code: NotPendingAuthority",
+  INVALID_BENEFICIARIES: "Error Code: InvalidBeneficiaries",
+  RECIPIENT_NOT_BENEFICIARY: "Error Code: RecipientNotBeneficiary",
+  NO_PENDING_BENEFICIARY_CHANGE: "Error Code: NoPendingBeneficiaryChange",
+  BENEFICIARY_CHANGE_NOT_READY: "Error Code: BeneficiaryChangeNotReady",
+  INVALID_APPROVAL_CONFIG: "Error Code: InvalidApprovalConfig",
+  APPROVAL_REQUIRED: "Error Code: ApprovalRequired",
+  NOT_APPROVER: "Error Code: NotApprover",
+  ALREADY_APPROVED: "Error Code: AlreadyApproved",
+  APPROVAL_THRESHOLD_NOT_MET: "Error Code: ApprovalThresholdNotMet",
+  WITHDRAWAL_PROPOSAL_NOT_EXPIRED: "Error Code: WithdrawalProposalNotExpired",
+  INVALID_DONATION_LIMITS: "Error Code: InvalidDonationLimits",
+  HARD_CAP_REACHED: "Error Code: HardCapReached",
+  DONOR_LIMIT_EXCEEDED: "Error Code: DonorLimitExceeded",
+  ANONYMOUS_DONATION_NOT_ALLOWED: "Error Code: AnonymousDonationNotAllowed",
+  CAMPAIGN_ALREADY_MIGRATED: "Error Code: CampaignAlreadyMigrated",
+  USER_NOT_MIGRATED: "Error Code: UserNotMigrated",
+  USER_ALREADY_MIGRATED: "Error Code: UserAlreadyMigrated",
+  ROUND_MATCH_NOT_RETURNABLE: "Error Code: RoundMatchNotReturnable",
+  NO_ROUND_MATCH: "Error Code: NoRoundMatch",
 };
@@ -9,6 +9,7 @@ import {
   validateCampaignTarget,
 } from "../../utils/validation";
 import { SystemProgram } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
 import { useProgram } from "../useProgram";
 import { handleTransaction } from "@/utils/transaction";
 
@@ -50,23 +51,37 @@ export const useCreateCampaign = () => {
         throw new Error("Target amount too low");
       }
 
-      // Generate PDAs
+      // Generate PDAs. The campaign is derived from the authority's next
+      // campaign id, so the title can change later without moving it.
       const [userPDA] = await findUserPDA(authority);
-      const [campaignPDA] = await findCampaignPDA(params.title, authority);
+      const user = await program.account.user.fetch(userPDA);
+      const [campaignPDA] = await findCampaignPDA(
+        authority,
+        user.campaignsCreated
+      );
 
       // Prepare transaction
       const transaction = program.methods
-        .createCampaign(
-          params.title,
-          params.description,
-          params.category,
-          params.organizationName,
-          params.targetAmount,
-          params.startDate,
-          params.endDate,
-          params.imageUrl,
-          params.isUrgent
-        )
+        .createCampaign({
+          title: params.title,
+          description: params.description,
+          category: params.category,
+          organizationName: params.organizationName,
+          targetAmount: params.targetAmount,
+          startDate: params.startDate,
+          endDate: params.endDate,
+          imageUrl: params.imageUrl,
+          isUrgent: params.isUrgent,
+          allowUnsafeMintExtensions: false,
+          usdTarget: null,
+          fundingMode: { keepItAll: {} },
+          expiryBounty: new BN(0),
+          milestones: [],
+          voting: null,
+          beneficiaries: params.beneficiaries ?? [authority],
+          approvals: null,
+          donationLimits: { hardCap: null, maxPerDonor: null },
+        })
         .accounts({
           authority,
           user: userPDA,
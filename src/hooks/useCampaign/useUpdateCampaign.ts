@@ -1,13 +1,12 @@
 // hooks/useCampaign/useUpdateCampaign.ts
 import { useState } from "react";
 import { UpdateCampaignParams } from "../../types";
-import { findCampaignPDA } from "../../utils/pdas";
 import { useWallet } from "@solana/wallet-adapter-react";
 import { validateCampaignDuration } from "../../utils/validation";
-import { SystemProgram } from "@solana/web3.js";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
 import { useProgram } from "../useProgram";
 
-export const useUpdateCampaign = (campaignTitle: string) => {
+export const useUpdateCampaign = (campaignPDA: PublicKey) => {
   const { program } = useProgram();
   const { publicKey: authority } = useWallet();
   const [loading, setLoading] = useState(false);
@@ -22,8 +21,6 @@ export const useUpdateCampaign = (campaignTitle: string) => {
     setError(null);
 
     try {
-      const [campaignPDA] = await findCampaignPDA(campaignTitle, authority);
-
       // Validate updates if needed
       if (params.endDate) {
         const campaign = await program.account.campaign.fetch(campaignPDA);
@@ -39,11 +36,14 @@ export const useUpdateCampaign = (campaignTitle: string) => {
 
       // Ensure all optional fields are null rather than undefined
       const formattedParams = {
+        title: params.title ?? null,
         description: params.description ?? null,
         imageUrl: params.imageUrl ?? null,
+        startDate: params.startDate ?? null,
         endDate: params.endDate ?? null,
         status: params.status ?? null,
         isUrgent: params.isUrgent ?? null,
+        donationLimits: null,
       };
 
       const tx = await program.methods
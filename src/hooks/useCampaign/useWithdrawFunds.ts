@@ -1,6 +1,6 @@
 // File: /hooks/useCampaign/useWithdrawFunds.ts
 import { useState } from "react";
-import { findCampaignVaultPDA } from "../../utils/pdas";
+import { findCampaignVaultPDA, findWithdrawalPDA } from "../../utils/pdas";
 import { useWallet } from "@solana/wallet-adapter-react";
 import { useConnection } from "@solana/wallet-adapter-react";
 import { BN } from "@coral-xyz/anchor";
@@ -11,7 +11,9 @@ import { handleTransaction } from "@/utils/transaction";
 interface WithdrawParams {
   campaignPDA: PublicKey;
   amount: BN;
+  // Must be one of the campaign's beneficiaries
   recipient: PublicKey;
+  purpose?: string;
 }
 
 interface WithdrawResult {
@@ -31,6 +33,7 @@ export const useWithdrawFunds = () => {
     campaignPDA,
     amount,
     recipient,
+    purpose = "",
   }: WithdrawParams): Promise<WithdrawResult> => {
     if (!program || !authority) {
       throw new Error("Program or wallet not connected");
@@ -42,17 +45,20 @@ export const useWithdrawFunds = () => {
     try {
       // Fetch campaign data and derive PDAs
       const campaign = await program.account.campaign.fetch(campaignPDA);
-      const [campaignVaultPDA] = await findCampaignVaultPDA(
-        campaign.title,
-        campaign.authority
+      const [campaignVaultPDA] = await findCampaignVaultPDA(campaignPDA);
+      const [withdrawalPDA] = await findWithdrawalPDA(
+        campaignPDA,
+        campaign.withdrawalCount
       );
 
       // Prepare withdrawal transaction
       const transaction = program.methods
-        .withdrawFunds(amount)
+        .withdrawFunds(amount, purpose, null)
         .accounts({
           authority,
           campaign: campaignPDA,
+          campaignRole: null,
+          withdrawal: withdrawalPDA,
           campaignVault: campaignVaultPDA,
           recipient,
           systemProgram: SystemProgram.programId,
@@ -2,6 +2,7 @@
 import { useState } from "react";
 import {
   findDonationPDA,
+  findDonorPositionPDA,
   findCampaignVaultPDA,
   findUserPDA,
 } from "../../utils/pdas";
@@ -42,26 +43,23 @@ export const useDonate = () => {
     try {
       const campaign = await program.account.campaign.fetch(campaignPDA);
       const [userPDA] = await findUserPDA(donor);
-      const [campaignVaultPDA] = await findCampaignVaultPDA(
-        campaign.title,
-        campaign.authority
-      );
-      const donationCount = campaign.donorsCount.toString();
+      const [campaignVaultPDA] = await findCampaignVaultPDA(campaignPDA);
       const [donationPDA] = await findDonationPDA(
         campaignPDA,
-        donor,
-        donationCount
+        campaign.donationCount
       );
+      const [donorPositionPDA] = await findDonorPositionPDA(campaignPDA, donor);
 
       const toastId = toast.loading("Processing donation...");
 
       const signature = await program.methods
-        .donate(amount, paymentMethod, donationCount)
+        .donate(amount, paymentMethod, null, null)
         .accounts({
           donor,
           user: userPDA,
           campaign: campaignPDA,
           donation: donationPDA,
+          donorPosition: donorPositionPDA,
           campaignVault: campaignVaultPDA,
           systemProgram: SystemProgram.programId,
         })
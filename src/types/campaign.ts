@@ -16,7 +16,10 @@ export type CampaignStatus =
   | { active: Record<string, never> }
   | { inProgress: Record<string, never> }
   | { completed: Record<string, never> }
-  | { expired: Record<string, never> };
+  | { expired: Record<string, never> }
+  | { cancelled: Record<string, never> }
+  | { scheduled: Record<string, never> }
+  | { paused: Record<string, never> };
 
 // Helper function to get status key
 export function getStatusKey(status: CampaignStatus): string {
@@ -42,6 +45,9 @@ export function getDominantStatus(statuses: CampaignStatus[]): CampaignStatus {
     inProgress: { inProgress: {} },
     completed: { completed: {} },
     expired: { expired: {} },
+    cancelled: { cancelled: {} },
+    scheduled: { scheduled: {} },
+    paused: { paused: {} },
   };
 
   return statusMap[dominantStatusKey];
@@ -49,6 +55,8 @@ export function getDominantStatus(statuses: CampaignStatus[]): CampaignStatus {
 
 export interface Campaign {
   authority: PublicKey;
+  // Index among the authority's campaigns that the address is derived from
+  id: BN;
   title: string;
   description: string;
   category: CampaignCategory;
@@ -56,6 +64,7 @@ export interface Campaign {
   targetAmount: BN;
   raisedAmount: BN;
   donorsCount: number;
+  donationCount: BN;
   startDate: BN;
   endDate: BN;
   status: CampaignStatus;
@@ -69,8 +78,10 @@ export interface CampaignWithKey {
 }
 
 export interface UpdateCampaignParams {
+  title: string | null;
   description: string | null;
   imageUrl: string | null;
+  startDate: BN | null;
   endDate: BN | null;
   status: CampaignStatus | null;
   isUrgent: boolean | null;
@@ -86,4 +97,6 @@ export interface CreateCampaignParams {
   endDate: BN;
   imageUrl: string;
   isUrgent: boolean;
+  // Addresses withdrawals may be paid to; defaults to the authority
+  beneficiaries?: PublicKey[];
 }
@@ -14,233 +14,276 @@ export type Ripple = {
   };
   instructions: [
     {
-      name: "createCampaign";
-      discriminator: [111, 131, 187, 98, 160, 193, 114, 244];
+      name: "acceptCampaignAuthority";
+      discriminator: [12, 171, 49, 91, 203, 187, 201, 219];
       accounts: [
         {
-          name: "authority";
-          writable: true;
+          name: "newAuthority";
           signer: true;
         },
         {
-          name: "user";
+          name: "campaign";
           writable: true;
+        },
+        {
+          name: "eventAuthority";
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [117, 115, 101, 114];
-              },
-              {
-                kind: "account";
-                path: "authority";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
               }
             ];
           };
         },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "applyBeneficiaryChange";
+      discriminator: [229, 40, 243, 236, 193, 79, 10, 127];
+      accounts: [
+        {
+          name: "caller";
+          docs: ["Anyone can apply a change once its waiting period is over"];
+          signer: true;
+        },
         {
           name: "campaign";
           writable: true;
+        },
+        {
+          name: "eventAuthority";
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [99, 97, 109, 112, 97, 105, 103, 110];
-              },
-              {
-                kind: "arg";
-                path: "title";
-              },
-              {
-                kind: "account";
-                path: "authority";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
               }
             ];
           };
         },
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
+          name: "program";
         }
       ];
-      args: [
-        {
-          name: "title";
-          type: "string";
-        },
-        {
-          name: "description";
-          type: "string";
-        },
-        {
-          name: "category";
-          type: {
-            defined: {
-              name: "campaignCategory";
-            };
-          };
-        },
-        {
-          name: "organizationName";
-          type: "string";
-        },
+      args: [];
+    },
+    {
+      name: "approveWithdrawal";
+      discriminator: [75, 48, 146, 122, 201, 158, 210, 123];
+      accounts: [
         {
-          name: "targetAmount";
-          type: "u64";
+          name: "approver";
+          signer: true;
         },
         {
-          name: "startDate";
-          type: "i64";
+          name: "campaign";
+          relations: ["withdrawalProposal"];
         },
         {
-          name: "endDate";
-          type: "i64";
+          name: "withdrawalProposal";
+          writable: true;
         },
         {
-          name: "imageUrl";
-          type: "string";
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
         },
         {
-          name: "isUrgent";
-          type: "bool";
+          name: "program";
         }
       ];
+      args: [];
     },
     {
-      name: "donate";
-      discriminator: [121, 186, 218, 211, 73, 70, 196, 180];
+      name: "cancelCampaign";
+      discriminator: [66, 10, 32, 138, 122, 36, 134, 202];
       accounts: [
         {
-          name: "donor";
+          name: "authority";
           writable: true;
           signer: true;
         },
         {
-          name: "user";
+          name: "campaign";
           writable: true;
+        },
+        {
+          name: "campaignVault";
+          docs: [
+            "Its balance is snapshotted as the pool donors are refunded from."
+          ];
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [117, 115, 101, 114];
+                value: [118, 97, 117, 108, 116];
               },
               {
                 kind: "account";
-                path: "donor";
+                path: "campaign";
               }
             ];
           };
         },
         {
-          name: "campaign";
-          writable: true;
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "vaultTokenAccount";
+          optional: true;
           pda: {
             seeds: [
               {
-                kind: "const";
-                value: [99, 97, 109, 112, 97, 105, 103, 110];
+                kind: "account";
+                path: "campaignVault";
               },
               {
                 kind: "account";
-                path: "campaign.title";
-                account: "campaign";
+                path: "tokenProgram";
               },
               {
                 kind: "account";
-                path: "campaign.authority";
-                account: "campaign";
+                path: "mint";
               }
             ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
           };
         },
         {
-          name: "donation";
-          writable: true;
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "eventAuthority";
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [100, 111, 110, 97, 116, 105, 111, 110];
-              },
-              {
-                kind: "account";
-                path: "campaign";
-              },
-              {
-                kind: "account";
-                path: "donor";
-              },
-              {
-                kind: "arg";
-                path: "countInString";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
               }
             ];
           };
         },
         {
-          name: "campaignVault";
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "cancelSubscription";
+      discriminator: [60, 139, 189, 242, 191, 208, 143, 18];
+      accounts: [
+        {
+          name: "donor";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "subscription";
           docs: [
-            "The seeds and constraints ensure it is the correct vault for this campaign."
+            "Closing the account returns its rent and any unspent escrow to the donor"
           ];
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [99, 97, 109, 112, 97, 105, 103, 110];
+                value: [
+                  115, 117, 98, 115, 99, 114, 105, 112, 116, 105, 111, 110
+                ];
               },
               {
                 kind: "account";
-                path: "campaign.title";
-                account: "campaign";
+                path: "donor";
               },
               {
                 kind: "account";
-                path: "campaign.authority";
-                account: "campaign";
-              },
-              {
-                kind: "const";
-                value: [118, 97, 117, 108, 116];
+                path: "subscription.id";
+                account: "subscription";
               }
             ];
           };
         },
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
-        }
-      ];
-      args: [
+          name: "donorTokenAccount";
+          docs: [
+            "Token account the subscription was approved on. Pass it to revoke the approval."
+          ];
+          writable: true;
+          optional: true;
+        },
         {
-          name: "amount";
-          type: "u64";
+          name: "tokenProgram";
+          optional: true;
         },
         {
-          name: "paymentMethod";
-          type: {
-            defined: {
-              name: "paymentMethod";
-            };
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
           };
         },
         {
-          name: "countInString";
-          type: "string";
+          name: "program";
         }
       ];
+      args: [];
     },
     {
-      name: "initialize";
-      discriminator: [175, 175, 109, 31, 13, 152, 155, 237];
+      name: "claimRefund";
+      discriminator: [15, 16, 30, 161, 255, 228, 97, 60];
       accounts: [
         {
-          name: "authority";
+          name: "donor";
           writable: true;
           signer: true;
+          relations: ["donation"];
         },
         {
           name: "user";
+          docs: [
+            "The donor's profile, which stops counting the campaign as supported once",
+            "every gift to it is refunded"
+          ];
           writable: true;
           pda: {
             seeds: [
@@ -250,328 +293,6832 @@ export type Ripple = {
               },
               {
                 kind: "account";
-                path: "authority";
+                path: "donor";
               }
             ];
           };
         },
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
-        }
-      ];
-      args: [
-        {
-          name: "name";
-          type: "string";
-        }
-      ];
-    },
-    {
-      name: "updateCampaign";
-      discriminator: [235, 31, 39, 49, 121, 173, 19, 92];
-      accounts: [
+          name: "campaign";
+          writable: true;
+          relations: ["donation"];
+        },
         {
-          name: "authority";
+          name: "donation";
           writable: true;
-          signer: true;
         },
         {
-          name: "campaign";
+          name: "donorPosition";
+          docs: [
+            "Created here for donations made before positions were tracked"
+          ];
           writable: true;
           pda: {
             seeds: [
               {
                 kind: "const";
-                value: [99, 97, 109, 112, 97, 105, 103, 110];
+                value: [
+                  100, 111, 110, 111, 114, 95, 112, 111, 115, 105, 116, 105,
+                  111, 110
+                ];
               },
               {
                 kind: "account";
-                path: "campaign.title";
-                account: "campaign";
+                path: "campaign";
               },
               {
                 kind: "account";
-                path: "authority";
+                path: "donor";
               }
             ];
           };
         },
         {
-          name: "systemProgram";
-          address: "11111111111111111111111111111111";
-        }
-      ];
-      args: [
-        {
-          name: "params";
-          type: {
-            defined: {
-              name: "updateCampaignParams";
-            };
+          name: "campaignVault";
+          docs: ["The seeds ensure it is the correct vault for this campaign."];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              }
+            ];
           };
-        }
-      ];
-    },
-    {
-      name: "withdrawFunds";
-      discriminator: [241, 36, 29, 111, 208, 31, 104, 217];
-      accounts: [
+        },
         {
-          name: "authority";
-          writable: true;
-          signer: true;
+          name: "mint";
+          optional: true;
         },
         {
-          name: "campaign";
+          name: "vaultTokenAccount";
           writable: true;
+          optional: true;
           pda: {
             seeds: [
               {
-                kind: "const";
-                value: [99, 97, 109, 112, 97, 105, 103, 110];
+                kind: "account";
+                path: "campaignVault";
               },
               {
                 kind: "account";
-                path: "campaign.title";
-                account: "campaign";
+                path: "tokenProgram";
               },
               {
                 kind: "account";
-                path: "authority";
+                path: "mint";
               }
             ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
           };
         },
         {
-          name: "campaignVault";
+          name: "donorTokenAccount";
           docs: [
-            "The seeds and constraints ensure it is the correct vault for this campaign, and only the",
-            "campaign authority can withdraw from it when the campaign is completed."
+            "Token account of the donor that receives the refunded tokens"
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "matchingPool";
+          docs: [
+            "Pool that matched the donation. Required when it did, as the pool gets back",
+            "its share of the refund."
           ];
           writable: true;
+          optional: true;
+        },
+        {
+          name: "poolTokenAccount";
+          writable: true;
+          optional: true;
           pda: {
             seeds: [
-              {
-                kind: "const";
-                value: [99, 97, 109, 112, 97, 105, 103, 110];
-              },
               {
                 kind: "account";
-                path: "campaign.title";
-                account: "campaign";
+                path: "matchingPool";
               },
               {
                 kind: "account";
-                path: "campaign.authority";
-                account: "campaign";
+                path: "tokenProgram";
               },
               {
-                kind: "const";
-                value: [118, 97, 117, 108, 116];
+                kind: "account";
+                path: "mint";
               }
             ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
           };
         },
         {
-          name: "recipient";
-          docs: [
-            "It can be any valid system account as specified by the campaign authority."
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "completeMilestone";
+      discriminator: [137, 164, 160, 100, 33, 64, 178, 10];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u8";
+        },
+        {
+          name: "evidenceUri";
+          type: "string";
+        },
+        {
+          name: "evidenceHash";
+          type: {
+            array: [
+              "u8",
+              32
+            ];
+          };
+        }
+      ];
+    },
+    {
+      name: "createCampaign";
+      discriminator: [111, 131, 187, 98, 160, 193, 114, 244];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "user";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [117, 115, 101, 114];
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "campaign";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [99, 97, 109, 112, 97, 105, 103, 110];
+              },
+              {
+                kind: "account";
+                path: "authority";
+              },
+              {
+                kind: "account";
+                path: "user.campaigns_created";
+                account: "user";
+              }
+            ];
+          };
+        },
+        {
+          name: "campaignVault";
+          docs: [
+            "SOL campaigns and is the authority of the vault token account for SPL campaigns."
+          ];
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "acceptedMint";
+          docs: [
+            "Mint the campaign accepts donations in. Omit for a native SOL campaign."
+          ];
+          optional: true;
+        },
+        {
+          name: "vaultTokenAccount";
+          docs: [
+            "Anyone can create the vault's token account ahead of the campaign, since",
+            "its address is predictable, so an existing one is reused"
           ];
           writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "campaignVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "acceptedMint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "tokenProgram";
+          docs: [
+            "Token program that owns `accepted_mint`, either SPL Token or Token-2022"
+          ];
+          optional: true;
+        },
+        {
+          name: "associatedTokenProgram";
+          optional: true;
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        },
+        {
+          name: "priceFeed";
+          docs: ["campaigns with a USD target."];
+          optional: true;
         },
         {
           name: "systemProgram";
           address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
         }
       ];
       args: [
         {
-          name: "amount";
-          type: "u64";
+          name: "params";
+          type: {
+            defined: {
+              name: "createCampaignParams";
+            };
+          };
         }
       ];
-    }
-  ];
-  accounts: [
-    {
-      name: "campaign";
-      discriminator: [50, 40, 49, 11, 157, 220, 229, 192];
-    },
-    {
-      name: "donation";
-      discriminator: [189, 210, 54, 77, 216, 85, 7, 68];
-    },
-    {
-      name: "user";
-      discriminator: [159, 117, 95, 227, 239, 151, 58, 236];
-    }
-  ];
-  events: [
-    {
-      name: "badgeAwarded";
-      discriminator: [153, 87, 158, 115, 220, 200, 52, 1];
-    },
-    {
-      name: "campaignCreated";
-      discriminator: [9, 98, 69, 61, 53, 131, 64, 152];
-    },
-    {
-      name: "campaignUpdated";
-      discriminator: [110, 209, 206, 190, 205, 2, 234, 81];
-    },
-    {
-      name: "donationReceived";
-      discriminator: [160, 135, 32, 7, 241, 105, 91, 158];
-    },
-    {
-      name: "fundsWithdrawn";
-      discriminator: [56, 130, 230, 154, 35, 92, 11, 118];
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "titleTooLong";
-      msg: "The provided title is too long";
-    },
-    {
-      code: 6001;
-      name: "descriptionTooLong";
-      msg: "The provided description is too long";
     },
     {
-      code: 6002;
-      name: "organizationNameTooLong";
-      msg: "The provided organization name is too long";
+      name: "createFundingRound";
+      discriminator: [137, 140, 39, 150, 85, 67, 134, 110];
+      accounts: [
+        {
+          name: "admin";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "fundingRound";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  102, 117, 110, 100, 105, 110, 103, 95, 114, 111, 117, 110, 100
+                ];
+              },
+              {
+                kind: "account";
+                path: "admin";
+              },
+              {
+                kind: "arg";
+                path: "params.id";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: {
+              name: "createFundingRoundParams";
+            };
+          };
+        }
+      ];
     },
     {
-      code: 6003;
+      name: "createMatchingPool";
+      discriminator: [145, 150, 18, 245, 251, 94, 81, 0];
+      accounts: [
+        {
+          name: "sponsor";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "matchingPool";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109, 97, 116, 99, 104, 105, 110, 103, 95, 112, 111, 111, 108
+                ];
+              },
+              {
+                kind: "account";
+                path: "sponsor";
+              },
+              {
+                kind: "arg";
+                path: "params.id";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          docs: ["Mint the pool matches in. Omit for a SOL pool."];
+          optional: true;
+        },
+        {
+          name: "sponsorTokenAccount";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "poolTokenAccount";
+          docs: [
+            "The pool's address is predictable, so its token account may already",
+            "have been created by someone else and is reused"
+          ];
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "matchingPool";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "associatedTokenProgram";
+          optional: true;
+          address: "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: {
+              name: "createMatchingPoolParams";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "createSubscription";
+      discriminator: [65, 71, 10, 60, 249, 82, 197, 12];
+      accounts: [
+        {
+          name: "donor";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "user";
+          docs: [
+            "Executions credit the donor's profile, so it has to exist up front"
+          ];
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [117, 115, 101, 114];
+              },
+              {
+                kind: "account";
+                path: "donor";
+              }
+            ];
+          };
+        },
+        {
+          name: "subscription";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115, 117, 98, 115, 99, 114, 105, 112, 116, 105, 111, 110
+                ];
+              },
+              {
+                kind: "account";
+                path: "donor";
+              },
+              {
+                kind: "arg";
+                path: "params.id";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          docs: [
+            "Mint to donate in. Omit for a SOL subscription funded from escrow."
+          ];
+          optional: true;
+        },
+        {
+          name: "donorTokenAccount";
+          docs: [
+            "Token account the subscription is approved to draw donations from"
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: {
+              name: "createSubscriptionParams";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "donate";
+      discriminator: [121, 186, 218, 211, 73, 70, 196, 180];
+      accounts: [
+        {
+          name: "donor";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "user";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [117, 115, 101, 114];
+              },
+              {
+                kind: "account";
+                path: "donor";
+              }
+            ];
+          };
+        },
+        {
+          name: "honoreeUser";
+          docs: [
+            "Profile of a tribute's honoree. When passed, the donation counts towards the",
+            "honoree's stats and badges instead of the donor's."
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "donation";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [100, 111, 110, 97, 116, 105, 111, 110];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "campaign.donation_count";
+                account: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "donorPosition";
+          docs: [
+            "The donor's running totals for the campaign. Omitted for anonymous",
+            "donations, which it would link to the donor."
+          ];
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  100, 111, 110, 111, 114, 95, 112, 111, 115, 105, 116, 105,
+                  111, 110
+                ];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "donor";
+              }
+            ];
+          };
+        },
+        {
+          name: "campaignVault";
+          docs: [
+            "The seeds and constraints ensure it is the correct vault for this campaign."
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "donorTokenAccount";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "vaultTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "campaignVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "priceFeed";
+          docs: ["campaigns with a USD target."];
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "paymentMethod";
+          type: {
+            defined: {
+              name: "paymentMethod";
+            };
+          };
+        },
+        {
+          name: "tribute";
+          type: {
+            option: {
+              defined: {
+                name: "tributeParams";
+              };
+            };
+          };
+        },
+        {
+          name: "donorCommitment";
+          type: {
+            option: {
+              array: [
+                "u8",
+                32
+              ];
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "executeSubscription";
+      discriminator: [77, 121, 39, 37, 183, 213, 72, 134];
+      accounts: [
+        {
+          name: "keeper";
+          docs: [
+            "Anyone can execute a subscription once it is due; they pay the donation record's rent"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "subscription";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115, 117, 98, 115, 99, 114, 105, 112, 116, 105, 111, 110
+                ];
+              },
+              {
+                kind: "account";
+                path: "subscription.donor";
+                account: "subscription";
+              },
+              {
+                kind: "account";
+                path: "subscription.id";
+                account: "subscription";
+              }
+            ];
+          };
+        },
+        {
+          name: "user";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [117, 115, 101, 114];
+              },
+              {
+                kind: "account";
+                path: "subscription.donor";
+                account: "subscription";
+              }
+            ];
+          };
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "donation";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [100, 111, 110, 97, 116, 105, 111, 110];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "campaign.donation_count";
+                account: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "donorPosition";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  100, 111, 110, 111, 114, 95, 112, 111, 115, 105, 116, 105,
+                  111, 110
+                ];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "subscription.donor";
+                account: "subscription";
+              }
+            ];
+          };
+        },
+        {
+          name: "campaignVault";
+          docs: [
+            "The seeds and constraints ensure it is the correct vault for this campaign."
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "donorTokenAccount";
+          docs: ["Token account the subscription was approved to draw from"];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "vaultTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "campaignVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "priceFeed";
+          docs: ["campaigns with a USD target."];
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "executeWithdrawal";
+      discriminator: [113, 121, 203, 232, 137, 139, 248, 249];
+      accounts: [
+        {
+          name: "executor";
+          docs: ["One of the campaign's approval signers"];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+          relations: ["withdrawalProposal"];
+        },
+        {
+          name: "withdrawalProposal";
+          writable: true;
+        },
+        {
+          name: "proposer";
+          docs: ["Receives the proposal's rent back"];
+          writable: true;
+          relations: ["withdrawalProposal"];
+        },
+        {
+          name: "withdrawal";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "campaign.withdrawal_count";
+                account: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "campaignVault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "recipient";
+          docs: ["campaign's beneficiaries."];
+          writable: true;
+          relations: ["withdrawalProposal"];
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "vaultTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "campaignVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "recipientTokenAccount";
+          docs: [
+            "Token account of `recipient` that receives the withdrawn tokens"
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "expireCampaign";
+      discriminator: [229, 143, 4, 1, 143, 221, 0, 6];
+      accounts: [
+        {
+          name: "caller";
+          docs: [
+            "Anyone can settle a campaign once it has ended; they collect the expiry bounty"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "expireWithdrawalProposal";
+      discriminator: [252, 148, 242, 57, 108, 115, 170, 79];
+      accounts: [
+        {
+          name: "caller";
+          docs: ["Anyone can clear out a proposal once it has expired"];
+          signer: true;
+        },
+        {
+          name: "withdrawalProposal";
+          writable: true;
+        },
+        {
+          name: "proposer";
+          docs: ["Receives the proposal's rent back"];
+          writable: true;
+          relations: ["withdrawalProposal"];
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "finalizeMilestoneVote";
+      discriminator: [37, 91, 34, 107, 20, 211, 9, 1];
+      accounts: [
+        {
+          name: "caller";
+          docs: ["Anyone can settle a vote once its window has closed"];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "campaignVault";
+          docs: [
+            "Its balance becomes the refund pool if donors reject the milestone."
+          ];
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "vaultTokenAccount";
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "campaignVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "finalizeRound";
+      discriminator: [239, 160, 254, 11, 254, 144, 53, 148];
+      accounts: [
+        {
+          name: "caller";
+          docs: ["Anyone can finalize a round once it has ended"];
+          signer: true;
+        },
+        {
+          name: "fundingRound";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  102, 117, 110, 100, 105, 110, 103, 95, 114, 111, 117, 110, 100
+                ];
+              },
+              {
+                kind: "account";
+                path: "funding_round.admin";
+                account: "fundingRound";
+              },
+              {
+                kind: "account";
+                path: "funding_round.id";
+                account: "fundingRound";
+              }
+            ];
+          };
+        },
+        {
+          name: "admin";
+          writable: true;
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "fundSubscription";
+      discriminator: [224, 196, 55, 110, 8, 87, 188, 114];
+      accounts: [
+        {
+          name: "donor";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "subscription";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115, 117, 98, 115, 99, 114, 105, 112, 116, 105, 111, 110
+                ];
+              },
+              {
+                kind: "account";
+                path: "donor";
+              },
+              {
+                kind: "account";
+                path: "subscription.id";
+                account: "subscription";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "grantCampaignRole";
+      discriminator: [182, 2, 253, 99, 166, 230, 176, 20];
+      accounts: [
+        {
+          name: "authority";
+          docs: ["The campaign's authority, or one of its admins"];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "campaign";
+        },
+        {
+          name: "authorityRole";
+          docs: [
+            "The signer's own role, when they aren't the campaign's authority"
+          ];
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  99, 97, 109, 112, 97, 105, 103, 110, 95, 114, 111, 108, 101
+                ];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "campaignRole";
+          docs: [
+            "Granting a role to an existing member replaces their current one"
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  99, 97, 109, 112, 97, 105, 103, 110, 95, 114, 111, 108, 101
+                ];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "arg";
+                path: "member";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "member";
+          type: "pubkey";
+        },
+        {
+          name: "role";
+          type: {
+            defined: {
+              name: "campaignRoleKind";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "initialize";
+      discriminator: [175, 175, 109, 31, 13, 152, 155, 237];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "user";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [117, 115, 101, 114];
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "name";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "migrateCampaign";
+      discriminator: [38, 211, 205, 215, 172, 252, 62, 227];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "campaign";
+          docs: [
+            "`Campaign`. Decoded and checked against its title-derived address in the",
+            "handler."
+          ];
+          writable: true;
+        },
+        {
+          name: "legacyVault";
+          docs: ["address in the handler."];
+          writable: true;
+        },
+        {
+          name: "campaignVault";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateUser";
+      discriminator: [159, 64, 64, 6, 80, 228, 54, 66];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "user";
+          docs: ["as a `User`. Decoded by hand in the handler."];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [117, 115, 101, 114];
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "pauseCampaign";
+      discriminator: [62, 247, 54, 192, 240, 158, 8, 161];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "campaignRole";
+          docs: [
+            "The signer's role on the campaign, when they aren't its authority"
+          ];
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  99, 97, 109, 112, 97, 105, 103, 110, 95, 114, 111, 108, 101
+                ];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "reason";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "proposeBeneficiaryChange";
+      discriminator: [174, 196, 156, 132, 129, 174, 36, 56];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "beneficiaries";
+          type: {
+            option: {
+              vec: "pubkey";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "proposeCampaignAuthority";
+      discriminator: [109, 77, 84, 75, 177, 8, 254, 16];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "newAuthority";
+          type: {
+            option: "pubkey";
+          };
+        }
+      ];
+    },
+    {
+      name: "proposeWithdrawal";
+      discriminator: [24, 15, 80, 161, 146, 233, 1, 25];
+      accounts: [
+        {
+          name: "proposer";
+          docs: [
+            "One of the campaign's approval signers; their proposal counts as their approval"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "withdrawalProposal";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  119, 105, 116, 104, 100, 114, 97, 119, 97, 108, 95, 112, 114,
+                  111, 112, 111, 115, 97, 108
+                ];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "campaign.withdrawal_proposal_count";
+                account: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "recipient";
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "purpose";
+          type: "string";
+        },
+        {
+          name: "receiptHash";
+          type: {
+            option: {
+              array: [
+                "u8",
+                32
+              ];
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "reclaimMatchingPool";
+      discriminator: [216, 246, 203, 250, 179, 155, 58, 123];
+      accounts: [
+        {
+          name: "sponsor";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "matchingPool";
+          docs: [
+            "The pool is kept open after reclaiming, as refunds of matched donations",
+            "return funds to it that the sponsor can reclaim again"
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109, 97, 116, 99, 104, 105, 110, 103, 95, 112, 111, 111, 108
+                ];
+              },
+              {
+                kind: "account";
+                path: "sponsor";
+              },
+              {
+                kind: "account";
+                path: "matching_pool.id";
+                account: "matchingPool";
+              }
+            ];
+          };
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "poolTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "matchingPool";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "sponsorTokenAccount";
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "registerRoundCampaign";
+      discriminator: [61, 199, 41, 112, 248, 206, 243, 15];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "admin";
+          docs: [
+            "Campaigns join a round only with its admin's sign-off, so the limited",
+            "slots can't be filled by anyone else"
+          ];
+          signer: true;
+        },
+        {
+          name: "fundingRound";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  102, 117, 110, 100, 105, 110, 103, 95, 114, 111, 117, 110, 100
+                ];
+              },
+              {
+                kind: "account";
+                path: "funding_round.admin";
+                account: "fundingRound";
+              },
+              {
+                kind: "account";
+                path: "funding_round.id";
+                account: "fundingRound";
+              }
+            ];
+          };
+        },
+        {
+          name: "campaign";
+        },
+        {
+          name: "campaignVault";
+          docs: ["Recorded so the round can pay the campaign's match into it."];
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "roundParticipant";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  114, 111, 117, 110, 100, 95, 112, 97, 114, 116, 105, 99, 105,
+                  112, 97, 110, 116
+                ];
+              },
+              {
+                kind: "account";
+                path: "fundingRound";
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "removeRoundCampaign";
+      discriminator: [78, 48, 151, 94, 48, 85, 149, 195];
+      accounts: [
+        {
+          name: "admin";
+          signer: true;
+        },
+        {
+          name: "fundingRound";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  102, 117, 110, 100, 105, 110, 103, 95, 114, 111, 117, 110, 100
+                ];
+              },
+              {
+                kind: "account";
+                path: "funding_round.admin";
+                account: "fundingRound";
+              },
+              {
+                kind: "account";
+                path: "funding_round.id";
+                account: "fundingRound";
+              }
+            ];
+          };
+        },
+        {
+          name: "roundParticipant";
+          docs: [
+            "Kept rather than closed: its contribution records stay behind, so the",
+            "campaign can't register again and start its score over"
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  114, 111, 117, 110, 100, 95, 112, 97, 114, 116, 105, 99, 105,
+                  112, 97, 110, 116
+                ];
+              },
+              {
+                kind: "account";
+                path: "fundingRound";
+              },
+              {
+                kind: "account";
+                path: "round_participant.campaign";
+                account: "roundParticipant";
+              }
+            ];
+          };
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "resumeCampaign";
+      discriminator: [84, 91, 163, 83, 28, 24, 54, 2];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "campaignRole";
+          docs: [
+            "The signer's role on the campaign, when they aren't its authority"
+          ];
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  99, 97, 109, 112, 97, 105, 103, 110, 95, 114, 111, 108, 101
+                ];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "extendEndDate";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "returnRoundMatch";
+      discriminator: [237, 26, 193, 5, 102, 251, 244, 35];
+      accounts: [
+        {
+          name: "caller";
+          docs: ["Anyone can return a match once the campaign is refunding"];
+          signer: true;
+        },
+        {
+          name: "fundingRound";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  102, 117, 110, 100, 105, 110, 103, 95, 114, 111, 117, 110, 100
+                ];
+              },
+              {
+                kind: "account";
+                path: "funding_round.admin";
+                account: "fundingRound";
+              },
+              {
+                kind: "account";
+                path: "funding_round.id";
+                account: "fundingRound";
+              }
+            ];
+          };
+        },
+        {
+          name: "admin";
+          writable: true;
+        },
+        {
+          name: "roundParticipant";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  114, 111, 117, 110, 100, 95, 112, 97, 114, 116, 105, 99, 105,
+                  112, 97, 110, 116
+                ];
+              },
+              {
+                kind: "account";
+                path: "fundingRound";
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "campaignVault";
+          docs: ["The seeds ensure it is the correct vault for this campaign."];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "revealDonation";
+      discriminator: [17, 167, 143, 212, 193, 121, 201, 136];
+      accounts: [
+        {
+          name: "donor";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "user";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [117, 115, 101, 114];
+              },
+              {
+                kind: "account";
+                path: "donor";
+              }
+            ];
+          };
+        },
+        {
+          name: "donation";
+          writable: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+          relations: ["donation"];
+        },
+        {
+          name: "donorPosition";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  100, 111, 110, 111, 114, 95, 112, 111, 115, 105, 116, 105,
+                  111, 110
+                ];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "donor";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "salt";
+          type: {
+            array: [
+              "u8",
+              32
+            ];
+          };
+        }
+      ];
+    },
+    {
+      name: "revokeCampaignRole";
+      discriminator: [94, 131, 181, 12, 244, 244, 90, 174];
+      accounts: [
+        {
+          name: "authority";
+          docs: [
+            "The campaign's authority, or one of its admins. Receives the role",
+            "account's rent."
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "campaign";
+          relations: ["campaignRole"];
+        },
+        {
+          name: "authorityRole";
+          docs: [
+            "The signer's own role, when they aren't the campaign's authority"
+          ];
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  99, 97, 109, 112, 97, 105, 103, 110, 95, 114, 111, 108, 101
+                ];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "campaignRole";
+          writable: true;
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "setMockPrice";
+      discriminator: [161, 22, 71, 90, 159, 254, 26, 48];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "priceFeed";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  109, 111, 99, 107, 95, 112, 114, 105, 99, 101, 95, 102, 101,
+                  101, 100
+                ];
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        }
+      ];
+      args: [
+        {
+          name: "price";
+          type: "i64";
+        },
+        {
+          name: "confidence";
+          type: "u64";
+        },
+        {
+          name: "exponent";
+          type: "i32";
+        },
+        {
+          name: "publishTime";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "setSubscriptionStatus";
+      discriminator: [124, 142, 62, 117, 59, 119, 116, 119];
+      accounts: [
+        {
+          name: "donor";
+          signer: true;
+        },
+        {
+          name: "subscription";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  115, 117, 98, 115, 99, 114, 105, 112, 116, 105, 111, 110
+                ];
+              },
+              {
+                kind: "account";
+                path: "donor";
+              },
+              {
+                kind: "account";
+                path: "subscription.id";
+                account: "subscription";
+              }
+            ];
+          };
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "status";
+          type: {
+            defined: {
+              name: "subscriptionStatus";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "tallyRoundContribution";
+      discriminator: [180, 153, 126, 23, 189, 52, 26, 46];
+      accounts: [
+        {
+          name: "payer";
+          docs: [
+            "Anyone can tally a donation into a round; they pay for the contribution record"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "fundingRound";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  102, 117, 110, 100, 105, 110, 103, 95, 114, 111, 117, 110, 100
+                ];
+              },
+              {
+                kind: "account";
+                path: "funding_round.admin";
+                account: "fundingRound";
+              },
+              {
+                kind: "account";
+                path: "funding_round.id";
+                account: "fundingRound";
+              }
+            ];
+          };
+        },
+        {
+          name: "roundParticipant";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  114, 111, 117, 110, 100, 95, 112, 97, 114, 116, 105, 99, 105,
+                  112, 97, 110, 116
+                ];
+              },
+              {
+                kind: "account";
+                path: "fundingRound";
+              },
+              {
+                kind: "account";
+                path: "round_participant.campaign";
+                account: "roundParticipant";
+              }
+            ];
+          };
+        },
+        {
+          name: "donation";
+          writable: true;
+        },
+        {
+          name: "roundContribution";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  114, 111, 117, 110, 100, 95, 99, 111, 110, 116, 114, 105, 98,
+                  117, 116, 105, 111, 110
+                ];
+              },
+              {
+                kind: "account";
+                path: "fundingRound";
+              },
+              {
+                kind: "account";
+                path: "round_participant.campaign";
+                account: "roundParticipant";
+              },
+              {
+                kind: "account";
+                path: "donation.donor";
+                account: "donation";
+              }
+            ];
+          };
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "updateCampaign";
+      discriminator: [235, 31, 39, 49, 121, 173, 19, 92];
+      accounts: [
+        {
+          name: "authority";
+          docs: [
+            "The campaign's authority, or a member with a role covering the update"
+          ];
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "campaignRole";
+          docs: [
+            "The signer's role on the campaign, when they aren't its authority"
+          ];
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  99, 97, 109, 112, 97, 105, 103, 110, 95, 114, 111, 108, 101
+                ];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "campaignAuthority";
+          docs: [
+            "Receives the expiry bounty when a member closes the campaign"
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: {
+              name: "updateCampaignParams";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "voteMilestone";
+      discriminator: [43, 27, 71, 239, 231, 20, 102, 156];
+      accounts: [
+        {
+          name: "voter";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "vote";
+          docs: ["One vote per donor per milestone"];
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u8";
+        },
+        {
+          name: "approve";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "withdrawFunds";
+      discriminator: [241, 36, 29, 111, 208, 31, 104, 217];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "campaign";
+          writable: true;
+        },
+        {
+          name: "campaignRole";
+          docs: [
+            "The signer's role on the campaign, when they aren't its authority"
+          ];
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  99, 97, 109, 112, 97, 105, 103, 110, 95, 114, 111, 108, 101
+                ];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "authority";
+              }
+            ];
+          };
+        },
+        {
+          name: "withdrawal";
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [119, 105, 116, 104, 100, 114, 97, 119, 97, 108];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              },
+              {
+                kind: "account";
+                path: "campaign.withdrawal_count";
+                account: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "campaignVault";
+          docs: [
+            "The seeds and constraints ensure it is the correct vault for this campaign, and only the",
+            "campaign authority can withdraw from it once the campaign is completed or expired."
+          ];
+          writable: true;
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [118, 97, 117, 108, 116];
+              },
+              {
+                kind: "account";
+                path: "campaign";
+              }
+            ];
+          };
+        },
+        {
+          name: "recipient";
+          docs: ["It must be one of the beneficiaries the campaign declared."];
+          writable: true;
+        },
+        {
+          name: "mint";
+          optional: true;
+        },
+        {
+          name: "vaultTokenAccount";
+          writable: true;
+          optional: true;
+          pda: {
+            seeds: [
+              {
+                kind: "account";
+                path: "campaignVault";
+              },
+              {
+                kind: "account";
+                path: "tokenProgram";
+              },
+              {
+                kind: "account";
+                path: "mint";
+              }
+            ];
+            program: {
+              kind: "const";
+              value: [
+                140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142,
+                13, 131, 11, 90, 19, 153, 218, 255, 16, 132, 4, 142, 123, 216,
+                219, 233, 248, 89
+              ];
+            };
+          };
+        },
+        {
+          name: "recipientTokenAccount";
+          docs: [
+            "Token account of `recipient` that receives the withdrawn tokens"
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "tokenProgram";
+          optional: true;
+        },
+        {
+          name: "systemProgram";
+          address: "11111111111111111111111111111111";
+        },
+        {
+          name: "eventAuthority";
+          pda: {
+            seeds: [
+              {
+                kind: "const";
+                value: [
+                  95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111,
+                  114, 105, 116, 121
+                ];
+              }
+            ];
+          };
+        },
+        {
+          name: "program";
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "purpose";
+          type: "string";
+        },
+        {
+          name: "receiptHash";
+          type: {
+            option: {
+              array: [
+                "u8",
+                32
+              ];
+            };
+          };
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: "campaign";
+      discriminator: [50, 40, 49, 11, 157, 220, 229, 192];
+    },
+    {
+      name: "campaignRole";
+      discriminator: [164, 202, 242, 166, 184, 197, 42, 96];
+    },
+    {
+      name: "donation";
+      discriminator: [189, 210, 54, 77, 216, 85, 7, 68];
+    },
+    {
+      name: "donorPosition";
+      discriminator: [219, 73, 133, 110, 179, 183, 165, 126];
+    },
+    {
+      name: "fundingRound";
+      discriminator: [137, 235, 160, 80, 252, 185, 180, 4];
+    },
+    {
+      name: "matchingPool";
+      discriminator: [193, 46, 246, 112, 41, 79, 78, 195];
+    },
+    {
+      name: "milestoneVote";
+      discriminator: [5, 129, 119, 11, 182, 73, 115, 227];
+    },
+    {
+      name: "mockPriceFeed";
+      discriminator: [73, 0, 218, 41, 7, 202, 200, 152];
+    },
+    {
+      name: "roundContribution";
+      discriminator: [188, 178, 240, 220, 38, 20, 214, 194];
+    },
+    {
+      name: "roundParticipant";
+      discriminator: [147, 204, 209, 217, 33, 43, 166, 99];
+    },
+    {
+      name: "subscription";
+      discriminator: [64, 7, 26, 135, 102, 132, 98, 33];
+    },
+    {
+      name: "user";
+      discriminator: [159, 117, 95, 227, 239, 151, 58, 236];
+    },
+    {
+      name: "withdrawal";
+      discriminator: [10, 45, 211, 182, 129, 235, 90, 82];
+    },
+    {
+      name: "withdrawalProposal";
+      discriminator: [133, 217, 76, 47, 128, 98, 53, 3];
+    }
+  ];
+  events: [
+    {
+      name: "badgeAwarded";
+      discriminator: [153, 87, 158, 115, 220, 200, 52, 1];
+    },
+    {
+      name: "beneficiaryChangeApplied";
+      discriminator: [224, 189, 234, 6, 21, 219, 48, 50];
+    },
+    {
+      name: "beneficiaryChangeProposed";
+      discriminator: [76, 180, 78, 144, 49, 49, 82, 84];
+    },
+    {
+      name: "campaignAuthorityProposed";
+      discriminator: [218, 144, 233, 122, 187, 216, 160, 91];
+    },
+    {
+      name: "campaignAuthorityTransferred";
+      discriminator: [190, 115, 76, 243, 12, 146, 102, 220];
+    },
+    {
+      name: "campaignCancelled";
+      discriminator: [121, 171, 21, 55, 56, 234, 121, 44];
+    },
+    {
+      name: "campaignCreated";
+      discriminator: [9, 98, 69, 61, 53, 131, 64, 152];
+    },
+    {
+      name: "campaignMigrated";
+      discriminator: [104, 2, 28, 27, 48, 56, 165, 203];
+    },
+    {
+      name: "campaignPaused";
+      discriminator: [175, 201, 65, 236, 104, 165, 240, 42];
+    },
+    {
+      name: "campaignResumed";
+      discriminator: [28, 180, 44, 11, 139, 21, 180, 117];
+    },
+    {
+      name: "campaignRoleGranted";
+      discriminator: [46, 64, 110, 34, 177, 176, 1, 247];
+    },
+    {
+      name: "campaignRoleRevoked";
+      discriminator: [71, 207, 3, 197, 213, 172, 237, 157];
+    },
+    {
+      name: "campaignSettled";
+      discriminator: [117, 145, 212, 210, 201, 63, 135, 24];
+    },
+    {
+      name: "campaignUpdated";
+      discriminator: [110, 209, 206, 190, 205, 2, 234, 81];
+    },
+    {
+      name: "donationMatched";
+      discriminator: [143, 129, 80, 25, 184, 131, 32, 53];
+    },
+    {
+      name: "donationReceived";
+      discriminator: [160, 135, 32, 7, 241, 105, 91, 158];
+    },
+    {
+      name: "donationRevealed";
+      discriminator: [100, 42, 22, 58, 78, 9, 30, 28];
+    },
+    {
+      name: "fundingRoundCreated";
+      discriminator: [118, 70, 3, 99, 58, 196, 5, 45];
+    },
+    {
+      name: "fundingRoundFinalized";
+      discriminator: [145, 130, 204, 121, 98, 40, 230, 53];
+    },
+    {
+      name: "fundsWithdrawn";
+      discriminator: [56, 130, 230, 154, 35, 92, 11, 118];
+    },
+    {
+      name: "matchingPoolCreated";
+      discriminator: [10, 6, 252, 132, 185, 83, 203, 99];
+    },
+    {
+      name: "matchingPoolReclaimed";
+      discriminator: [147, 44, 80, 129, 21, 134, 109, 59];
+    },
+    {
+      name: "milestoneCompleted";
+      discriminator: [44, 25, 3, 4, 74, 141, 142, 66];
+    },
+    {
+      name: "milestoneVoteCast";
+      discriminator: [251, 202, 241, 169, 201, 116, 60, 46];
+    },
+    {
+      name: "milestoneVoteFinalized";
+      discriminator: [203, 13, 125, 134, 67, 120, 28, 192];
+    },
+    {
+      name: "refundClaimed";
+      discriminator: [136, 64, 242, 99, 4, 244, 208, 130];
+    },
+    {
+      name: "roundCampaignRegistered";
+      discriminator: [187, 96, 122, 5, 152, 57, 55, 135];
+    },
+    {
+      name: "roundCampaignRemoved";
+      discriminator: [189, 101, 163, 135, 236, 43, 88, 28];
+    },
+    {
+      name: "roundContributionTallied";
+      discriminator: [245, 12, 220, 188, 241, 237, 9, 139];
+    },
+    {
+      name: "roundMatchDistributed";
+      discriminator: [233, 95, 19, 124, 167, 57, 38, 59];
+    },
+    {
+      name: "roundMatchReturned";
+      discriminator: [91, 130, 107, 129, 173, 225, 238, 128];
+    },
+    {
+      name: "subscriptionCancelled";
+      discriminator: [158, 216, 233, 205, 138, 62, 176, 239];
+    },
+    {
+      name: "subscriptionCreated";
+      discriminator: [215, 63, 169, 25, 179, 200, 180, 105];
+    },
+    {
+      name: "subscriptionExecuted";
+      discriminator: [237, 101, 134, 61, 131, 50, 211, 8];
+    },
+    {
+      name: "subscriptionUpdated";
+      discriminator: [51, 157, 221, 95, 183, 199, 243, 218];
+    },
+    {
+      name: "tributeDonation";
+      discriminator: [131, 174, 203, 34, 201, 239, 63, 1];
+    },
+    {
+      name: "userInitialized";
+      discriminator: [66, 195, 5, 223, 42, 84, 135, 60];
+    },
+    {
+      name: "userMigrated";
+      discriminator: [87, 188, 236, 188, 129, 230, 244, 219];
+    },
+    {
+      name: "withdrawalApproved";
+      discriminator: [118, 139, 166, 230, 81, 241, 215, 154];
+    },
+    {
+      name: "withdrawalProposalExpired";
+      discriminator: [72, 135, 229, 53, 126, 143, 12, 19];
+    },
+    {
+      name: "withdrawalProposed";
+      discriminator: [162, 31, 212, 4, 156, 25, 2, 157];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "titleTooLong";
+      msg: "The provided title is too long";
+    },
+    {
+      code: 6001;
+      name: "descriptionTooLong";
+      msg: "The provided description is too long";
+    },
+    {
+      code: 6002;
+      name: "organizationNameTooLong";
+      msg: "The provided organization name is too long";
+    },
+    {
+      code: 6003;
       name: "imageUrlTooLong";
       msg: "The provided image URL is too long";
     },
     {
-      code: 6004;
-      name: "campaignDurationTooShort";
-      msg: "Campaign duration is too short";
+      code: 6004;
+      name: "campaignDurationTooShort";
+      msg: "Campaign duration is too short";
+    },
+    {
+      code: 6005;
+      name: "campaignDurationTooLong";
+      msg: "Campaign duration is too long";
+    },
+    {
+      code: 6006;
+      name: "targetAmountTooLow";
+      msg: "Campaign target amount is too low";
+    },
+    {
+      code: 6007;
+      name: "campaignEnded";
+      msg: "Campaign has already ended";
+    },
+    {
+      code: 6008;
+      name: "campaignNotActive";
+      msg: "Campaign is not active";
+    },
+    {
+      code: 6009;
+      name: "donationTooLow";
+      msg: "Donation amount is too low";
+    },
+    {
+      code: 6010;
+      name: "invalidAuthority";
+      msg: "Invalid authority";
+    },
+    {
+      code: 6011;
+      name: "invalidStatusTransition";
+      msg: "Invalid campaign status transition";
+    },
+    {
+      code: 6012;
+      name: "insufficientFunds";
+      msg: "Insufficient funds";
+    },
+    {
+      code: 6013;
+      name: "nameTooLong";
+      msg: "Maximum name length exceeded";
+    },
+    {
+      code: 6014;
+      name: "emailTooLong";
+      msg: "Maximum email length exceeded";
+    },
+    {
+      code: 6015;
+      name: "invalidEmailFormat";
+      msg: "Invalid email format";
+    },
+    {
+      code: 6016;
+      name: "transactionHashTooLong";
+      msg: "Maximum transaction hash length exceeded";
+    },
+    {
+      code: 6017;
+      name: "impactDescriptionTooLong";
+      msg: "Maximum impact description length exceeded";
+    },
+    {
+      code: 6018;
+      name: "maxBadgesReached";
+      msg: "Maximum number of badges reached";
+    },
+    {
+      code: 6019;
+      name: "invalidMint";
+      msg: "Token mint does not match the campaign's accepted mint";
+    },
+    {
+      code: 6020;
+      name: "missingTokenAccounts";
+      msg: "Token accounts are required for campaigns that accept an SPL mint";
+    },
+    {
+      code: 6021;
+      name: "invalidTokenAccount";
+      msg: "Invalid token account";
+    },
+    {
+      code: 6022;
+      name: "unsupportedMintExtension";
+      msg: "Token mint uses an extension that is not supported";
+    },
+    {
+      code: 6023;
+      name: "unsafeMintExtension";
+      msg: "Token mint uses an extension that makes vault custody unsafe";
+    },
+    {
+      code: 6024;
+      name: "invalidPriceFeed";
+      msg: "Price feed account does not match the campaign's price feed";
+    },
+    {
+      code: 6025;
+      name: "missingPriceFeed";
+      msg: "A price feed account is required for campaigns with a USD target";
+    },
+    {
+      code: 6026;
+      name: "invalidPrice";
+      msg: "Price is invalid";
+    },
+    {
+      code: 6027;
+      name: "stalePrice";
+      msg: "Price is too old";
+    },
+    {
+      code: 6028;
+      name: "priceConfidenceTooWide";
+      msg: "Price confidence interval is too wide";
+    },
+    {
+      code: 6029;
+      name: "fundingGoalNotReached";
+      msg: "Campaign has not reached its funding goal";
+    },
+    {
+      code: 6030;
+      name: "refundNotAvailable";
+      msg: "Refunds are not available for this campaign";
+    },
+    {
+      code: 6031;
+      name: "donationNotRefundable";
+      msg: "Donation cannot be refunded";
+    },
+    {
+      code: 6032;
+      name: "campaignCancelled";
+      msg: "Campaign has been cancelled";
+    },
+    {
+      code: 6033;
+      name: "campaignNotEnded";
+      msg: "Campaign has not ended yet";
+    },
+    {
+      code: 6034;
+      name: "expiryBountyTooHigh";
+      msg: "Expiry bounty exceeds the maximum allowed";
+    },
+    {
+      code: 6035;
+      name: "tooManyMilestones";
+      msg: "Too many milestones";
+    },
+    {
+      code: 6036;
+      name: "invalidMilestone";
+      msg: "Milestone is invalid";
+    },
+    {
+      code: 6037;
+      name: "milestoneNotFound";
+      msg: "Milestone not found";
+    },
+    {
+      code: 6038;
+      name: "milestoneAlreadyCompleted";
+      msg: "Milestone has already been completed";
+    },
+    {
+      code: 6039;
+      name: "milestoneDeadlinePassed";
+      msg: "Milestone deadline has passed";
+    },
+    {
+      code: 6040;
+      name: "evidenceUriTooLong";
+      msg: "Evidence URI is too long";
+    },
+    {
+      code: 6041;
+      name: "milestoneNotReached";
+      msg: "Withdrawal exceeds the funds released by completed milestones";
+    },
+    {
+      code: 6042;
+      name: "invalidVotingConfig";
+      msg: "Voting configuration is invalid";
+    },
+    {
+      code: 6043;
+      name: "milestoneNotVoting";
+      msg: "Milestone is not open for voting";
+    },
+    {
+      code: 6044;
+      name: "votingClosed";
+      msg: "Voting on this milestone has closed";
+    },
+    {
+      code: 6045;
+      name: "votingStillOpen";
+      msg: "Voting on this milestone is still open";
+    },
+    {
+      code: 6046;
+      name: "invalidVoteWeight";
+      msg: "Donation accounts do not give the voter any weight";
+    },
+    {
+      code: 6047;
+      name: "purposeTooLong";
+      msg: "Withdrawal purpose is too long";
+    },
+    {
+      code: 6048;
+      name: "invalidSubscription";
+      msg: "Subscription configuration is invalid";
+    },
+    {
+      code: 6049;
+      name: "subscriptionNotActive";
+      msg: "Subscription is not active";
+    },
+    {
+      code: 6050;
+      name: "subscriptionNotDue";
+      msg: "Subscription is not due yet";
+    },
+    {
+      code: 6051;
+      name: "subscriptionExhausted";
+      msg: "Subscription has reached its maximum number of donations";
+    },
+    {
+      code: 6052;
+      name: "invalidSubscriptionTarget";
+      msg: "Campaign is not a target of this subscription";
+    },
+    {
+      code: 6053;
+      name: "insufficientEscrow";
+      msg: "Subscription escrow cannot cover the donation";
+    },
+    {
+      code: 6054;
+      name: "invalidMatchingPool";
+      msg: "Matching pool configuration is invalid";
+    },
+    {
+      code: 6055;
+      name: "matchingPoolNotEligible";
+      msg: "Campaign is not eligible for this matching pool";
+    },
+    {
+      code: 6056;
+      name: "matchingPoolActive";
+      msg: "Matching pool window has not ended";
+    },
+    {
+      code: 6057;
+      name: "missingMatchingPool";
+      msg: "Matching pool account is required to return the matched funds";
+    },
+    {
+      code: 6058;
+      name: "invalidFundingRound";
+      msg: "Funding round configuration is invalid";
+    },
+    {
+      code: 6059;
+      name: "roundNotOpen";
+      msg: "Funding round is not open";
+    },
+    {
+      code: 6060;
+      name: "roundNotEnded";
+      msg: "Funding round has not ended";
+    },
+    {
+      code: 6061;
+      name: "roundFinalized";
+      msg: "Funding round has already been finalized";
+    },
+    {
+      code: 6062;
+      name: "roundFull";
+      msg: "Funding round has no room for more campaigns";
+    },
+    {
+      code: 6063;
+      name: "donationAlreadyTallied";
+      msg: "Donation has already been tallied in a funding round";
+    },
+    {
+      code: 6064;
+      name: "invalidRoundParticipants";
+      msg: "Round participants do not match the funding round";
+    },
+    {
+      code: 6065;
+      name: "donationNotCompleted";
+      msg: "Donation is not completed";
+    },
+    {
+      code: 6066;
+      name: "dedicationTooLong";
+      msg: "Dedication message is too long";
+    },
+    {
+      code: 6067;
+      name: "invalidHonoree";
+      msg: "Honoree is invalid";
+    },
+    {
+      code: 6068;
+      name: "anonymousDonation";
+      msg: "Donation is anonymous";
+    },
+    {
+      code: 6069;
+      name: "invalidCommitment";
+      msg: "Salt does not match the donation's commitment";
+    },
+    {
+      code: 6070;
+      name: "campaignAlreadyMigrated";
+      msg: "Campaign has already been migrated";
+    },
+    {
+      code: 6071;
+      name: "invalidDonorPosition";
+      msg: "Donor position is invalid";
+    },
+    {
+      code: 6072;
+      name: "campaignNotStarted";
+      msg: "Campaign has not started yet";
+    },
+    {
+      code: 6073;
+      name: "invalidStartDate";
+      msg: "Start date is too far in the past or future";
+    },
+    {
+      code: 6074;
+      name: "campaignAlreadyStarted";
+      msg: "Campaign has already started";
+    },
+    {
+      code: 6075;
+      name: "campaignPaused";
+      msg: "Campaign is paused";
+    },
+    {
+      code: 6076;
+      name: "campaignNotPaused";
+      msg: "Campaign is not paused";
+    },
+    {
+      code: 6077;
+      name: "pauseReasonTooLong";
+      msg: "Pause reason exceeds maximum length";
+    },
+    {
+      code: 6078;
+      name: "notPendingAuthority";
+      msg: "Signer is not the pending campaign authority";
+    },
+    {
+      code: 6079;
+      name: "invalidBeneficiaries";
+      msg: "Beneficiaries must be between one and three distinct addresses";
+    },
+    {
+      code: 6080;
+      name: "recipientNotBeneficiary";
+      msg: "Recipient is not one of the campaign's beneficiaries";
+    },
+    {
+      code: 6081;
+      name: "noPendingBeneficiaryChange";
+      msg: "No beneficiary change is pending";
+    },
+    {
+      code: 6082;
+      name: "beneficiaryChangeNotReady";
+      msg: "Beneficiary change is still in its waiting period";
+    },
+    {
+      code: 6083;
+      name: "invalidApprovalConfig";
+      msg: "Withdrawal approval signers or threshold are invalid";
+    },
+    {
+      code: 6084;
+      name: "approvalRequired";
+      msg: "Withdrawals from this campaign need approval from its signers";
+    },
+    {
+      code: 6085;
+      name: "approvalsNotEnabled";
+      msg: "Campaign does not use withdrawal approvals";
+    },
+    {
+      code: 6086;
+      name: "notApprover";
+      msg: "Signer is not one of the campaign's withdrawal approvers";
+    },
+    {
+      code: 6087;
+      name: "alreadyApproved";
+      msg: "Withdrawal has already been approved by this signer";
+    },
+    {
+      code: 6088;
+      name: "approvalThresholdNotMet";
+      msg: "Withdrawal proposal does not have enough approvals";
+    },
+    {
+      code: 6089;
+      name: "withdrawalProposalExpired";
+      msg: "Withdrawal proposal has expired";
+    },
+    {
+      code: 6090;
+      name: "withdrawalProposalNotExpired";
+      msg: "Withdrawal proposal has not expired yet";
+    },
+    {
+      code: 6091;
+      name: "invalidWithdrawalProposal";
+      msg: "Withdrawal proposal does not match the accounts provided";
+    },
+    {
+      code: 6092;
+      name: "invalidDonationLimits";
+      msg: "Hard cap must cover the target and the per-donor limit, and per-donor limits must be positive";
+    },
+    {
+      code: 6093;
+      name: "hardCapReached";
+      msg: "Campaign has reached its hard cap";
+    },
+    {
+      code: 6094;
+      name: "hardCapExceeded";
+      msg: "Donation would exceed the campaign's hard cap";
+    },
+    {
+      code: 6095;
+      name: "donorLimitExceeded";
+      msg: "Donation would exceed the campaign's per-donor limit";
+    },
+    {
+      code: 6096;
+      name: "anonymousDonationNotAllowed";
+      msg: "Campaigns with a per-donor limit do not accept anonymous donations";
+    },
+    {
+      code: 6097;
+      name: "mockOracleDisabled";
+      msg: "Mock price feeds are only available in builds with the mock-oracle feature";
+    },
+    {
+      code: 6098;
+      name: "invalidLegacyCampaign";
+      msg: "Account is not a campaign in its original title-derived layout";
+    },
+    {
+      code: 6099;
+      name: "userNotMigrated";
+      msg: "User profile must be migrated to the current layout first";
+    },
+    {
+      code: 6100;
+      name: "userAlreadyMigrated";
+      msg: "User profile has already been migrated";
+    },
+    {
+      code: 6101;
+      name: "roundMatchNotReturnable";
+      msg: "Round match can only be returned while the campaign is refunding donors";
+    },
+    {
+      code: 6102;
+      name: "noRoundMatch";
+      msg: "Campaign has no round match to return";
+    },
+    {
+      code: 6103;
+      name: "roundCampaignRemoved";
+      msg: "Campaign has been removed from the funding round";
+    }
+  ];
+  types: [
+    {
+      name: "approvalConfig";
+      docs: [
+        "Signers who must approve a campaign's withdrawals, for organizations that",
+        "require several people to sign off on disbursements"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "signers";
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "threshold";
+            docs: [
+              "Number of signers that must approve a withdrawal before it can be executed"
+            ];
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "badge";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "badgeType";
+            type: {
+              defined: {
+                name: "badgeType";
+              };
+            };
+          },
+          {
+            name: "description";
+            type: "string";
+          },
+          {
+            name: "imageUrl";
+            type: "string";
+          },
+          {
+            name: "dateEarned";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "badgeAwarded";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "user";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "badgeType";
+            type: {
+              defined: {
+                name: "badgeType";
+              };
+            };
+          },
+          {
+            name: "totalDonations";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "badgeType";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "gold";
+          },
+          {
+            name: "silver";
+          },
+          {
+            name: "bronze";
+          },
+          {
+            name: "championOfChange";
+          },
+          {
+            name: "sustainedSupporter";
+          }
+        ];
+      };
+    },
+    {
+      name: "beneficiaryChange";
+      docs: [
+        "A new set of beneficiaries proposed by the authority. Donors get until",
+        "`effective_at` to see the change before withdrawals can go to it."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "beneficiaries";
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "effectiveAt";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "beneficiaryChangeApplied";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "previousBeneficiaries";
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "beneficiaries";
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "beneficiaryChangeProposed";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "beneficiaries";
+            docs: ["`None` when a previous proposal is withdrawn"];
+            type: {
+              option: {
+                vec: "pubkey";
+              };
+            };
+          },
+          {
+            name: "effectiveAt";
+            docs: ["When the change can be applied"];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaign";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "id";
+            docs: [
+              "Index among the authority's campaigns that the campaign's address is derived",
+              "from. Zero for migrated campaigns, which keep their title-derived address."
+            ];
+            type: "u64";
+          },
+          {
+            name: "title";
+            type: "string";
+          },
+          {
+            name: "description";
+            type: "string";
+          },
+          {
+            name: "category";
+            type: {
+              defined: {
+                name: "campaignCategory";
+              };
+            };
+          },
+          {
+            name: "organizationName";
+            type: "string";
+          },
+          {
+            name: "targetAmount";
+            type: "u64";
+          },
+          {
+            name: "raisedAmount";
+            type: "u64";
+          },
+          {
+            name: "donorsCount";
+            docs: [
+              "Number of distinct donors with an unrefunded donation. Each anonymous",
+              "donation counts as its own donor, since it can't be linked to others."
+            ];
+            type: "u32";
+          },
+          {
+            name: "donationCount";
+            docs: [
+              "Number of donations received, including repeat gifts from the same donor.",
+              "Also the index the next donation is assigned."
+            ];
+            type: "u64";
+          },
+          {
+            name: "startDate";
+            type: "i64";
+          },
+          {
+            name: "endDate";
+            type: "i64";
+          },
+          {
+            name: "status";
+            type: {
+              defined: {
+                name: "campaignStatus";
+              };
+            };
+          },
+          {
+            name: "imageUrl";
+            type: "string";
+          },
+          {
+            name: "isUrgent";
+            type: "bool";
+          },
+          {
+            name: "acceptedMint";
+            docs: [
+              "SPL mint donations are accepted in, or `None` for native SOL.",
+              "`target_amount` and `raised_amount` are in this mint's base units."
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "allowUnsafeMintExtensions";
+            docs: [
+              "Opts in to mint extensions that let a third party move vault funds,",
+              "such as a Token-2022 permanent delegate."
+            ];
+            type: "bool";
+          },
+          {
+            name: "usdTarget";
+            docs: [
+              "Fiat target and price feed, for campaigns that raise towards a USD amount"
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "usdTarget";
+                };
+              };
+            };
+          },
+          {
+            name: "raisedUsd";
+            docs: [
+              "Sum of each donation's USD value at the time it was made, in micro-USD"
+            ];
+            type: "u64";
+          },
+          {
+            name: "fundingMode";
+            type: {
+              defined: {
+                name: "fundingMode";
+              };
+            };
+          },
+          {
+            name: "refundPool";
+            docs: [
+              "Vault funds still owed to donors of a cancelled campaign. Set to the vault",
+              "balance on cancellation and paid out pro-rata as refunds are claimed."
+            ];
+            type: "u64";
+          },
+          {
+            name: "expiryBounty";
+            docs: [
+              "Lamports held on the campaign account for whoever calls `expire_campaign`"
+            ];
+            type: "u64";
+          },
+          {
+            name: "milestones";
+            docs: [
+              "Deliverables that gate withdrawals. Empty for campaigns that release",
+              "everything once completed."
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: "milestone";
+                };
+              };
+            };
+          },
+          {
+            name: "voting";
+            docs: [
+              "Donor sign-off on milestones, or `None` when the authority's report is enough"
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "votingConfig";
+                };
+              };
+            };
+          },
+          {
+            name: "voteableAmount";
+            docs: [
+              "Part of `raised_amount` that donors can vote on milestones with: their own",
+              "gifts, excluding matches and donations that are still anonymous. Milestone",
+              "quorums are measured against it."
+            ];
+            type: "u64";
+          },
+          {
+            name: "withdrawnAmount";
+            docs: ["Total paid out to the authority through `withdraw_funds`"];
+            type: "u64";
+          },
+          {
+            name: "withdrawalCount";
+            docs: [
+              "Number of `Withdrawal` records, and the sequence number of the next one"
+            ];
+            type: "u64";
+          },
+          {
+            name: "pausedAt";
+            docs: [
+              "When the current pause started, while the campaign is paused"
+            ];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "pauseCount";
+            docs: ["Number of times the campaign has been paused"];
+            type: "u32";
+          },
+          {
+            name: "totalPausedDuration";
+            docs: [
+              "Seconds the campaign has spent paused, not counting a pause in progress"
+            ];
+            type: "i64";
+          },
+          {
+            name: "pendingAuthority";
+            docs: ["Wallet offered control of the campaign, until it accepts"];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "roleEpoch";
+            docs: [
+              "Bumped each time control changes hands. Roles granted before the",
+              "current authority took over no longer authorize anything."
+            ];
+            type: "u32";
+          },
+          {
+            name: "beneficiaries";
+            docs: ["Addresses `withdraw_funds` may pay out to"];
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "pendingBeneficiaries";
+            docs: [
+              "Replacement beneficiaries waiting out `BENEFICIARY_CHANGE_DELAY`"
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "beneficiaryChange";
+                };
+              };
+            };
+          },
+          {
+            name: "approvals";
+            docs: [
+              "Signers who approve withdrawals, or `None` when the authority withdraws alone"
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "approvalConfig";
+                };
+              };
+            };
+          },
+          {
+            name: "withdrawalProposalCount";
+            docs: [
+              "Number of withdrawal proposals made, and the index of the next one"
+            ];
+            type: "u64";
+          },
+          {
+            name: "donationLimits";
+            docs: ["Hard cap and per-donor limit on what the campaign accepts"];
+            type: {
+              defined: {
+                name: "donationLimits";
+              };
+            };
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignAuthorityProposed";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "pendingAuthority";
+            docs: ["`None` when a previous proposal is withdrawn"];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignAuthorityTransferred";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "previousAuthority";
+            type: "pubkey";
+          },
+          {
+            name: "newAuthority";
+            type: "pubkey";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignCancelled";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "refundPool";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignCategory";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "healthcare";
+          },
+          {
+            name: "education";
+          },
+          {
+            name: "foodSupply";
+          },
+          {
+            name: "emergencyRelief";
+          },
+          {
+            name: "infrastructure";
+          },
+          {
+            name: "waterSanitation";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignCreated";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "title";
+            type: "string";
+          },
+          {
+            name: "category";
+            type: {
+              defined: {
+                name: "campaignCategory";
+              };
+            };
+          },
+          {
+            name: "targetAmount";
+            type: "u64";
+          },
+          {
+            name: "acceptedMint";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "usdTarget";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "fundingMode";
+            type: {
+              defined: {
+                name: "fundingMode";
+              };
+            };
+          },
+          {
+            name: "status";
+            docs: ["`Scheduled` when the campaign starts in the future"];
+            type: {
+              defined: {
+                name: "campaignStatus";
+              };
+            };
+          },
+          {
+            name: "startDate";
+            type: "i64";
+          },
+          {
+            name: "endDate";
+            type: "i64";
+          },
+          {
+            name: "beneficiaries";
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "donationLimits";
+            type: {
+              defined: {
+                name: "donationLimits";
+              };
+            };
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignMigrated";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "legacyVault";
+            type: "pubkey";
+          },
+          {
+            name: "vault";
+            type: "pubkey";
+          },
+          {
+            name: "lamports";
+            docs: ["Lamports moved out of the legacy vault"];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignPaused";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "reason";
+            type: "string";
+          },
+          {
+            name: "pauseCount";
+            type: "u32";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignResumed";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "pausedDuration";
+            type: "i64";
+          },
+          {
+            name: "totalPausedDuration";
+            type: "i64";
+          },
+          {
+            name: "endDate";
+            type: "i64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignRole";
+      docs: [
+        "Scoped permission over a campaign held by someone other than its authority.",
+        "Cancelling the campaign, reporting milestones, changing its beneficiaries",
+        "and handing it over are left to the authority alone."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "member";
+            type: "pubkey";
+          },
+          {
+            name: "role";
+            type: {
+              defined: {
+                name: "campaignRoleKind";
+              };
+            };
+          },
+          {
+            name: "grantedBy";
+            type: "pubkey";
+          },
+          {
+            name: "grantedAt";
+            type: "i64";
+          },
+          {
+            name: "epoch";
+            docs: [
+              "The campaign's `role_epoch` when the role was granted. The role lapses",
+              "once the campaign changes hands."
+            ];
+            type: "u32";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignRoleGranted";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "member";
+            type: "pubkey";
+          },
+          {
+            name: "role";
+            type: {
+              defined: {
+                name: "campaignRoleKind";
+              };
+            };
+          },
+          {
+            name: "grantedBy";
+            type: "pubkey";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignRoleKind";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "editor";
+          },
+          {
+            name: "statusManager";
+          },
+          {
+            name: "treasurer";
+          },
+          {
+            name: "admin";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignRoleRevoked";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "member";
+            type: "pubkey";
+          },
+          {
+            name: "role";
+            type: {
+              defined: {
+                name: "campaignRoleKind";
+              };
+            };
+          },
+          {
+            name: "revokedBy";
+            type: "pubkey";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignSettled";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "caller";
+            type: "pubkey";
+          },
+          {
+            name: "status";
+            type: {
+              defined: {
+                name: "campaignStatus";
+              };
+            };
+          },
+          {
+            name: "raisedAmount";
+            type: "u64";
+          },
+          {
+            name: "bountyPaid";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignStatus";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "active";
+          },
+          {
+            name: "inProgress";
+          },
+          {
+            name: "completed";
+          },
+          {
+            name: "expired";
+          },
+          {
+            name: "cancelled";
+          },
+          {
+            name: "scheduled";
+          },
+          {
+            name: "paused";
+          }
+        ];
+      };
+    },
+    {
+      name: "campaignUpdated";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "title";
+            type: "string";
+          },
+          {
+            name: "newStatus";
+            type: {
+              option: {
+                defined: {
+                  name: "campaignStatus";
+                };
+              };
+            };
+          },
+          {
+            name: "startDate";
+            type: "i64";
+          },
+          {
+            name: "endDate";
+            type: "i64";
+          },
+          {
+            name: "isUrgent";
+            type: "bool";
+          },
+          {
+            name: "donationLimits";
+            type: {
+              defined: {
+                name: "donationLimits";
+              };
+            };
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "createCampaignParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "title";
+            type: "string";
+          },
+          {
+            name: "description";
+            type: "string";
+          },
+          {
+            name: "category";
+            type: {
+              defined: {
+                name: "campaignCategory";
+              };
+            };
+          },
+          {
+            name: "organizationName";
+            type: "string";
+          },
+          {
+            name: "targetAmount";
+            type: "u64";
+          },
+          {
+            name: "startDate";
+            type: "i64";
+          },
+          {
+            name: "endDate";
+            type: "i64";
+          },
+          {
+            name: "imageUrl";
+            type: "string";
+          },
+          {
+            name: "isUrgent";
+            type: "bool";
+          },
+          {
+            name: "allowUnsafeMintExtensions";
+            type: "bool";
+          },
+          {
+            name: "usdTarget";
+            type: {
+              option: {
+                defined: {
+                  name: "usdTarget";
+                };
+              };
+            };
+          },
+          {
+            name: "fundingMode";
+            type: {
+              defined: {
+                name: "fundingMode";
+              };
+            };
+          },
+          {
+            name: "expiryBounty";
+            docs: [
+              "Lamports paid to whoever settles the campaign with `expire_campaign`"
+            ];
+            type: "u64";
+          },
+          {
+            name: "milestones";
+            docs: [
+              "Milestones that gate withdrawals; leave empty to release funds on completion"
+            ];
+            type: {
+              vec: {
+                defined: {
+                  name: "milestoneParams";
+                };
+              };
+            };
+          },
+          {
+            name: "voting";
+            docs: [
+              "Requires donors to approve each milestone before its funds are released"
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "votingConfig";
+                };
+              };
+            };
+          },
+          {
+            name: "beneficiaries";
+            docs: ["Addresses withdrawals may be paid to"];
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "approvals";
+            docs: [
+              "Requires several signers to approve each withdrawal instead of the authority alone"
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "approvalConfig";
+                };
+              };
+            };
+          },
+          {
+            name: "donationLimits";
+            type: {
+              defined: {
+                name: "donationLimits";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "createFundingRoundParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "id";
+            type: "u64";
+          },
+          {
+            name: "pot";
+            docs: [
+              "Lamports to split between campaigns, transferred into the round up front"
+            ];
+            type: "u64";
+          },
+          {
+            name: "startTime";
+            type: "i64";
+          },
+          {
+            name: "endTime";
+            type: "i64";
+          },
+          {
+            name: "maxContributionPerDonor";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "createMatchingPoolParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "id";
+            type: "u64";
+          },
+          {
+            name: "categories";
+            docs: ["Campaign categories whose donations are matched"];
+            type: {
+              vec: {
+                defined: {
+                  name: "campaignCategory";
+                };
+              };
+            };
+          },
+          {
+            name: "campaigns";
+            docs: ["Individual campaigns whose donations are matched"];
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "matchRatioBps";
+            docs: [
+              "Amount matched per unit donated, in basis points (10_000 = 1:1)"
+            ];
+            type: "u16";
+          },
+          {
+            name: "perDonationCap";
+            type: "u64";
+          },
+          {
+            name: "totalCap";
+            docs: ["Total to match, transferred into the pool up front"];
+            type: "u64";
+          },
+          {
+            name: "startTime";
+            type: "i64";
+          },
+          {
+            name: "endTime";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "createSubscriptionParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "id";
+            type: "u64";
+          },
+          {
+            name: "target";
+            type: {
+              defined: {
+                name: "subscriptionTarget";
+              };
+            };
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "interval";
+            docs: ["Time between donations, in seconds"];
+            type: "i64";
+          },
+          {
+            name: "startAt";
+            docs: [
+              "When the first donation falls due; defaults to immediately"
+            ];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "maxCount";
+            docs: [
+              "Number of donations to make; required for token subscriptions"
+            ];
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "escrowDeposit";
+            docs: ["Lamports to prepay into escrow for a SOL subscription"];
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "donation";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "donor";
+            docs: [
+              "Donor's key, or `Pubkey::default()` while the donation is anonymous"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "donorCommitment";
+            docs: [
+              "`sha256(donor || salt)` for anonymous donations. The donor proves the",
+              "donation is theirs by revealing the salt."
+            ];
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32
+                ];
+              };
+            };
+          },
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "index";
+            docs: [
+              "Position of the donation in the campaign's sequence, which its address",
+              "is derived from"
+            ];
+            type: "u64";
+          },
+          {
+            name: "mint";
+            docs: ["Mint the donation was made in, or `None` for native SOL."];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "usdValue";
+            docs: [
+              "Value of `amount` plus any match in micro-USD when donated, if the campaign has a USD target"
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "matchingPool";
+            docs: ["Matching pool that topped up the donation, if any"];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "matchedAmount";
+            docs: ["Amount the matching pool added on top of `amount`"];
+            type: "u64";
+          },
+          {
+            name: "fundingRound";
+            docs: ["Funding round the donation was tallied in, if any"];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "honoree";
+            docs: [
+              "Person the donation was made in honor of or on behalf of, if any"
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "dedication";
+            type: "string";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          },
+          {
+            name: "status";
+            type: {
+              defined: {
+                name: "donationStatus";
+              };
+            };
+          },
+          {
+            name: "paymentMethod";
+            type: {
+              defined: {
+                name: "paymentMethod";
+              };
+            };
+          },
+          {
+            name: "transactionHash";
+            type: "string";
+          },
+          {
+            name: "impactDescription";
+            type: "string";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "donationLimits";
+      docs: [
+        "Limits on what a campaign accepts, in the campaign's unit. Unlike",
+        "`target_amount`, these are enforced on every donation."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "hardCap";
+            docs: [
+              "Most the campaign will raise, matches included. The donation that reaches",
+              "it is cut down to fit, and later ones are rejected."
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "maxPerDonor";
+            docs: ["Most a single donor can give in total, net of refunds"];
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "donationMatched";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "matchingPool";
+            type: "pubkey";
+          },
+          {
+            name: "donation";
+            type: "pubkey";
+          },
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "matchedTotal";
+            docs: ["Pool total matched after this donation"];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "donationReceived";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "donation";
+            type: "pubkey";
+          },
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "donor";
+            docs: ["`Pubkey::default()` for anonymous donations"];
+            type: "pubkey";
+          },
+          {
+            name: "mint";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "usdValue";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "paymentMethod";
+            type: {
+              defined: {
+                name: "paymentMethod";
+              };
+            };
+          },
+          {
+            name: "raisedAmount";
+            docs: ["Campaign totals after the donation"];
+            type: "u64";
+          },
+          {
+            name: "raisedUsd";
+            type: "u64";
+          },
+          {
+            name: "donorsCount";
+            type: "u32";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "donationRevealed";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "donation";
+            type: "pubkey";
+          },
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "donor";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "donationStatus";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "pending";
+          },
+          {
+            name: "completed";
+          },
+          {
+            name: "allocated";
+          },
+          {
+            name: "spent";
+          },
+          {
+            name: "refunded";
+          }
+        ];
+      };
+    },
+    {
+      name: "donorPosition";
+      docs: ["Everything one donor has given to one campaign"];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "donor";
+            type: "pubkey";
+          },
+          {
+            name: "totalAmount";
+            docs: [
+              "Total given, in the campaign's mint, net of refunds and excluding matches"
+            ];
+            type: "u64";
+          },
+          {
+            name: "donationCount";
+            docs: ["Donations that haven't been refunded"];
+            type: "u32";
+          },
+          {
+            name: "firstDonationAt";
+            type: "i64";
+          },
+          {
+            name: "lastDonationAt";
+            type: "i64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "fundingMode";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "keepItAll";
+          },
+          {
+            name: "allOrNothing";
+          }
+        ];
+      };
+    },
+    {
+      name: "fundingRound";
+      docs: [
+        "A quadratic funding grant round. Donations made to registered campaigns during",
+        "the round are tallied, and `finalize_round` splits the matching pot between",
+        "campaigns in proportion to the square of the sum of the square roots of their",
+        "donors' contributions. Rounds are denominated in SOL."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "admin";
+            type: "pubkey";
+          },
+          {
+            name: "id";
+            docs: [
+              "Admin-chosen identifier, so one admin can run several rounds"
+            ];
+            type: "u64";
+          },
+          {
+            name: "pot";
+            docs: [
+              "Lamports committed to matching, held on top of the account's rent"
+            ];
+            type: "u64";
+          },
+          {
+            name: "startTime";
+            type: "i64";
+          },
+          {
+            name: "endTime";
+            type: "i64";
+          },
+          {
+            name: "maxContributionPerDonor";
+            docs: [
+              "Most a single donor's contributions to one campaign count towards its match"
+            ];
+            type: "u64";
+          },
+          {
+            name: "campaignCount";
+            type: "u32";
+          },
+          {
+            name: "totalScore";
+            docs: ["Sum of every registered campaign's score"];
+            type: "u128";
+          },
+          {
+            name: "finalized";
+            type: "bool";
+          },
+          {
+            name: "distributed";
+            docs: ["Total paid into campaign vaults at finalization"];
+            type: "u64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "fundingRoundCreated";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "fundingRound";
+            type: "pubkey";
+          },
+          {
+            name: "admin";
+            type: "pubkey";
+          },
+          {
+            name: "pot";
+            type: "u64";
+          },
+          {
+            name: "startTime";
+            type: "i64";
+          },
+          {
+            name: "endTime";
+            type: "i64";
+          },
+          {
+            name: "maxContributionPerDonor";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "fundingRoundFinalized";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "fundingRound";
+            type: "pubkey";
+          },
+          {
+            name: "totalScore";
+            type: "u128";
+          },
+          {
+            name: "distributed";
+            type: "u64";
+          },
+          {
+            name: "returned";
+            docs: ["Unallocated pot returned to the admin"];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "fundsWithdrawn";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "withdrawal";
+            type: "pubkey";
+          },
+          {
+            name: "sequence";
+            type: "u64";
+          },
+          {
+            name: "recipient";
+            type: "pubkey";
+          },
+          {
+            name: "mint";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "withdrawnAmount";
+            docs: ["Campaign total withdrawn after this withdrawal"];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "impactMetrics";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mealsProvided";
+            type: "u32";
+          },
+          {
+            name: "childrenEducated";
+            type: "u32";
+          },
+          {
+            name: "familiesHoused";
+            type: "u32";
+          },
+          {
+            name: "treesPlanted";
+            type: "u32";
+          }
+        ];
+      };
+    },
+    {
+      name: "matchingPool";
+      docs: [
+        "Sponsor funds that top up eligible donations passed through `donate`"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "sponsor";
+            type: "pubkey";
+          },
+          {
+            name: "id";
+            docs: [
+              "Sponsor-chosen identifier, so one sponsor can run several pools"
+            ];
+            type: "u64";
+          },
+          {
+            name: "mint";
+            docs: [
+              "Mint the pool matches in, or `None` for native SOL. Only campaigns",
+              "accepting the same mint can be matched."
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "categories";
+            docs: ["Campaign categories whose donations are matched"];
+            type: {
+              vec: {
+                defined: {
+                  name: "campaignCategory";
+                };
+              };
+            };
+          },
+          {
+            name: "campaigns";
+            docs: [
+              "Individual campaigns whose donations are matched, whatever their category"
+            ];
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "matchRatioBps";
+            docs: [
+              "Amount matched per unit donated, in basis points (10_000 = 1:1)"
+            ];
+            type: "u16";
+          },
+          {
+            name: "perDonationCap";
+            type: "u64";
+          },
+          {
+            name: "totalCap";
+            docs: ["Total the sponsor has committed to the pool"];
+            type: "u64";
+          },
+          {
+            name: "matchedTotal";
+            docs: ["Matched so far, net of matches returned by refunds"];
+            type: "u64";
+          },
+          {
+            name: "matchedCount";
+            type: "u32";
+          },
+          {
+            name: "startTime";
+            type: "i64";
+          },
+          {
+            name: "endTime";
+            type: "i64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "matchingPoolCreated";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "matchingPool";
+            type: "pubkey";
+          },
+          {
+            name: "sponsor";
+            type: "pubkey";
+          },
+          {
+            name: "mint";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "categories";
+            type: {
+              vec: {
+                defined: {
+                  name: "campaignCategory";
+                };
+              };
+            };
+          },
+          {
+            name: "campaigns";
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "matchRatioBps";
+            type: "u16";
+          },
+          {
+            name: "perDonationCap";
+            type: "u64";
+          },
+          {
+            name: "totalCap";
+            type: "u64";
+          },
+          {
+            name: "startTime";
+            type: "i64";
+          },
+          {
+            name: "endTime";
+            type: "i64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "matchingPoolReclaimed";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "matchingPool";
+            type: "pubkey";
+          },
+          {
+            name: "sponsor";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "milestone";
+      docs: [
+        "A deliverable that unlocks part of a campaign's funds once the organizer",
+        "shows it has been reached"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "description";
+            type: "string";
+          },
+          {
+            name: "amount";
+            type: {
+              defined: {
+                name: "milestoneAmount";
+              };
+            };
+          },
+          {
+            name: "deadline";
+            type: "i64";
+          },
+          {
+            name: "status";
+            type: {
+              defined: {
+                name: "milestoneStatus";
+              };
+            };
+          },
+          {
+            name: "completedAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "evidenceUri";
+            docs: [
+              "Where the evidence of completion is published, e.g. an IPFS or Arweave URI"
+            ];
+            type: "string";
+          },
+          {
+            name: "evidenceHash";
+            docs: [
+              "SHA-256 of the evidence content, so it can't be swapped after the fact"
+            ];
+            type: {
+              array: [
+                "u8",
+                32
+              ];
+            };
+          },
+          {
+            name: "votingEndsAt";
+            docs: [
+              "End of the donor vote, for campaigns that put milestones to a vote"
+            ];
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "approveWeight";
+            type: "u64";
+          },
+          {
+            name: "rejectWeight";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "milestoneAmount";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "fixed";
+            fields: ["u64"];
+          },
+          {
+            name: "percentage";
+            fields: ["u16"];
+          }
+        ];
+      };
+    },
+    {
+      name: "milestoneCompleted";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "index";
+            type: "u8";
+          },
+          {
+            name: "status";
+            type: {
+              defined: {
+                name: "milestoneStatus";
+              };
+            };
+          },
+          {
+            name: "evidenceUri";
+            type: "string";
+          },
+          {
+            name: "evidenceHash";
+            type: {
+              array: [
+                "u8",
+                32
+              ];
+            };
+          },
+          {
+            name: "votingEndsAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "milestoneParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "description";
+            type: "string";
+          },
+          {
+            name: "amount";
+            type: {
+              defined: {
+                name: "milestoneAmount";
+              };
+            };
+          },
+          {
+            name: "deadline";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "milestoneStatus";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "pending";
+          },
+          {
+            name: "voting";
+          },
+          {
+            name: "approved";
+          },
+          {
+            name: "rejected";
+          }
+        ];
+      };
+    },
+    {
+      name: "milestoneVote";
+      docs: [
+        "A donor's vote on one milestone, weighted by their donations to the campaign"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "milestoneIndex";
+            type: "u8";
+          },
+          {
+            name: "voter";
+            type: "pubkey";
+          },
+          {
+            name: "approve";
+            type: "bool";
+          },
+          {
+            name: "weight";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "milestoneVoteCast";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "index";
+            type: "u8";
+          },
+          {
+            name: "voter";
+            type: "pubkey";
+          },
+          {
+            name: "approve";
+            type: "bool";
+          },
+          {
+            name: "weight";
+            type: "u64";
+          },
+          {
+            name: "approveWeight";
+            docs: ["Milestone tallies after the vote"];
+            type: "u64";
+          },
+          {
+            name: "rejectWeight";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "milestoneVoteFinalized";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "index";
+            type: "u8";
+          },
+          {
+            name: "status";
+            type: {
+              defined: {
+                name: "milestoneStatus";
+              };
+            };
+          },
+          {
+            name: "approveWeight";
+            type: "u64";
+          },
+          {
+            name: "rejectWeight";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6005;
-      name: "campaignDurationTooLong";
-      msg: "Campaign duration is too long";
+      name: "mockPriceFeed";
+      docs: [
+        "A price account with a fixed layout that stands in for a real oracle on",
+        "localnet and in tests. Anyone can publish their own mock feed, so they are",
+        "only accepted by builds with the `mock-oracle` feature."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "price";
+            type: "i64";
+          },
+          {
+            name: "confidence";
+            type: "u64";
+          },
+          {
+            name: "exponent";
+            type: "i32";
+          },
+          {
+            name: "publishTime";
+            type: "i64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
     },
     {
-      code: 6006;
-      name: "targetAmountTooLow";
-      msg: "Campaign target amount is too low";
+      name: "paymentMethod";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "cryptoWallet";
+          },
+          {
+            name: "card";
+          }
+        ];
+      };
     },
     {
-      code: 6007;
-      name: "campaignEnded";
-      msg: "Campaign has already ended";
+      name: "priceFeedKind";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "mock";
+          },
+          {
+            name: "pyth";
+          }
+        ];
+      };
     },
     {
-      code: 6008;
-      name: "campaignNotActive";
-      msg: "Campaign is not active";
+      name: "refundClaimed";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "donation";
+            type: "pubkey";
+          },
+          {
+            name: "donor";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "matchedRefund";
+            docs: [
+              "Share of the refund returned to the donation's matching pool"
+            ];
+            type: "u64";
+          },
+          {
+            name: "raisedAmount";
+            docs: ["Campaign total after the refund"];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6009;
-      name: "donationTooLow";
-      msg: "Donation amount is too low";
+      name: "roundCampaignRegistered";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "fundingRound";
+            type: "pubkey";
+          },
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "campaignCount";
+            type: "u32";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6010;
-      name: "invalidAuthority";
-      msg: "Invalid authority";
+      name: "roundCampaignRemoved";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "fundingRound";
+            type: "pubkey";
+          },
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "campaignCount";
+            type: "u32";
+          },
+          {
+            name: "totalScore";
+            type: "u128";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "roundContribution";
+      docs: [
+        "A donor's counted contributions to one campaign in a funding round"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "round";
+            type: "pubkey";
+          },
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "donor";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "roundContributionTallied";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "fundingRound";
+            type: "pubkey";
+          },
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "donor";
+            type: "pubkey";
+          },
+          {
+            name: "donation";
+            type: "pubkey";
+          },
+          {
+            name: "countedAmount";
+            docs: [
+              "Donor's counted contributions to the campaign after the per-donor cap"
+            ];
+            type: "u64";
+          },
+          {
+            name: "score";
+            docs: ["Campaign score after the contribution"];
+            type: "u128";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "roundMatchDistributed";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "fundingRound";
+            type: "pubkey";
+          },
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "score";
+            type: "u128";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6011;
-      name: "invalidStatusTransition";
-      msg: "Invalid campaign status transition";
+      name: "roundMatchReturned";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "fundingRound";
+            type: "pubkey";
+          },
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "admin";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            docs: [
+              "Lamports paid back, the match's share of the vault for cancelled campaigns"
+            ];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6012;
-      name: "insufficientFunds";
-      msg: "Insufficient funds";
+      name: "roundParticipant";
+      docs: ["A campaign registered in a funding round"];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "round";
+            type: "pubkey";
+          },
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "vault";
+            docs: ["Vault the campaign's match is paid into"];
+            type: "pubkey";
+          },
+          {
+            name: "sumSqrt";
+            docs: [
+              "Sum of the square roots of each donor's counted contributions"
+            ];
+            type: "u128";
+          },
+          {
+            name: "contributions";
+            docs: ["Total counted contributions, after the per-donor cap"];
+            type: "u64";
+          },
+          {
+            name: "contributors";
+            type: "u32";
+          },
+          {
+            name: "matchAmount";
+            docs: [
+              "Match paid to the campaign when the round was finalized. Cleared when",
+              "`return_round_match` gives it back to the admin."
+            ];
+            type: "u64";
+          },
+          {
+            name: "matchUsdValue";
+            docs: [
+              "Value of `match_amount` in micro-USD when it was paid, if the campaign",
+              "has a USD target"
+            ];
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "removed";
+            docs: [
+              "Set when the admin takes the campaign out of the round. Removed campaigns",
+              "aren't tallied or finalized, and can't register in the round again."
+            ];
+            type: "bool";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
     },
     {
-      code: 6013;
-      name: "nameTooLong";
-      msg: "Maximum name length exceeded";
+      name: "subscription";
+      docs: [
+        "A recurring donation that any keeper can execute through `execute_subscription`",
+        "once it falls due"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "donor";
+            type: "pubkey";
+          },
+          {
+            name: "id";
+            docs: [
+              "Donor-chosen identifier, so one donor can hold several subscriptions"
+            ];
+            type: "u64";
+          },
+          {
+            name: "target";
+            type: {
+              defined: {
+                name: "subscriptionTarget";
+              };
+            };
+          },
+          {
+            name: "mint";
+            docs: [
+              "Mint the subscription donates in, or `None` for native SOL"
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "interval";
+            docs: ["Time between donations, in seconds"];
+            type: "i64";
+          },
+          {
+            name: "nextDue";
+            type: "i64";
+          },
+          {
+            name: "maxCount";
+            docs: [
+              "Number of donations after which the subscription ends, or `None` for no limit"
+            ];
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "executedCount";
+            type: "u32";
+          },
+          {
+            name: "funding";
+            type: {
+              defined: {
+                name: "subscriptionFunding";
+              };
+            };
+          },
+          {
+            name: "escrowBalance";
+            docs: [
+              "Lamports prepaid for SOL subscriptions, held on top of the account's rent"
+            ];
+            type: "u64";
+          },
+          {
+            name: "status";
+            type: {
+              defined: {
+                name: "subscriptionStatus";
+              };
+            };
+          },
+          {
+            name: "createdAt";
+            type: "i64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
     },
     {
-      code: 6014;
-      name: "emailTooLong";
-      msg: "Maximum email length exceeded";
+      name: "subscriptionCancelled";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "subscription";
+            type: "pubkey";
+          },
+          {
+            name: "donor";
+            type: "pubkey";
+          },
+          {
+            name: "executedCount";
+            type: "u32";
+          },
+          {
+            name: "refundedEscrow";
+            docs: ["Unspent escrow returned to the donor"];
+            type: "u64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6015;
-      name: "invalidEmailFormat";
-      msg: "Invalid email format";
+      name: "subscriptionCreated";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "subscription";
+            type: "pubkey";
+          },
+          {
+            name: "donor";
+            type: "pubkey";
+          },
+          {
+            name: "target";
+            type: {
+              defined: {
+                name: "subscriptionTarget";
+              };
+            };
+          },
+          {
+            name: "mint";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "interval";
+            type: "i64";
+          },
+          {
+            name: "nextDue";
+            type: "i64";
+          },
+          {
+            name: "maxCount";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "funding";
+            type: {
+              defined: {
+                name: "subscriptionFunding";
+              };
+            };
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6016;
-      name: "transactionHashTooLong";
-      msg: "Maximum transaction hash length exceeded";
+      name: "subscriptionExecuted";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "subscription";
+            type: "pubkey";
+          },
+          {
+            name: "donation";
+            type: "pubkey";
+          },
+          {
+            name: "campaign";
+            type: "pubkey";
+          },
+          {
+            name: "keeper";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "executedCount";
+            type: "u32";
+          },
+          {
+            name: "nextDue";
+            type: "i64";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          }
+        ];
+      };
     },
     {
-      code: 6017;
-      name: "impactDescriptionTooLong";
-      msg: "Maximum impact description length exceeded";
+      name: "subscriptionFunding";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "escrow";
+          },
+          {
+            name: "delegate";
+          }
+        ];
+      };
     },
     {
-      code: 6018;
-      name: "maxBadgesReached";
-      msg: "Maximum number of badges reached";
-    }
-  ];
-  types: [
-    {
-      name: "badge";
+      name: "subscriptionStatus";
       type: {
-        kind: "struct";
-        fields: [
+        kind: "enum";
+        variants: [
           {
-            name: "badgeType";
-            type: {
-              defined: {
-                name: "badgeType";
-              };
-            };
+            name: "active";
           },
           {
-            name: "description";
-            type: "string";
-          },
+            name: "paused";
+          }
+        ];
+      };
+    },
+    {
+      name: "subscriptionTarget";
+      type: {
+        kind: "enum";
+        variants: [
           {
-            name: "imageUrl";
-            type: "string";
+            name: "campaign";
+            fields: ["pubkey"];
           },
           {
-            name: "dateEarned";
-            type: "i64";
+            name: "organization";
+            fields: ["pubkey"];
           }
         ];
       };
     },
     {
-      name: "badgeAwarded";
+      name: "subscriptionUpdated";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "user";
+            name: "subscription";
             type: "pubkey";
           },
           {
-            name: "badgeType";
+            name: "donor";
+            type: "pubkey";
+          },
+          {
+            name: "status";
             type: {
               defined: {
-                name: "badgeType";
+                name: "subscriptionStatus";
               };
             };
           },
+          {
+            name: "escrowBalance";
+            type: "u64";
+          },
+          {
+            name: "nextDue";
+            type: "i64";
+          },
           {
             name: "timestamp";
             type: "i64";
@@ -580,133 +7127,259 @@ export type Ripple = {
       };
     },
     {
-      name: "badgeType";
+      name: "tributeDonation";
       type: {
-        kind: "enum";
-        variants: [
+        kind: "struct";
+        fields: [
           {
-            name: "gold";
+            name: "donation";
+            type: "pubkey";
           },
           {
-            name: "silver";
+            name: "campaign";
+            type: "pubkey";
           },
           {
-            name: "bronze";
+            name: "donor";
+            docs: ["`Pubkey::default()` for anonymous donations"];
+            type: "pubkey";
           },
           {
-            name: "championOfChange";
+            name: "honoree";
+            type: "pubkey";
           },
           {
-            name: "sustainedSupporter";
+            name: "dedication";
+            type: "string";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "creditedUser";
+            docs: [
+              "User profile the donation was credited to, or `Pubkey::default()` for an",
+              "anonymous donation that credited nobody"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "timestamp";
+            type: "i64";
           }
         ];
       };
     },
     {
-      name: "campaign";
+      name: "tributeParams";
+      docs: [
+        "Dedicates a donation to someone else, such as a gift in their honor or a",
+        "company donating on behalf of an employee"
+      ];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "authority";
+            name: "honoree";
             type: "pubkey";
           },
           {
-            name: "title";
+            name: "dedication";
             type: "string";
+          }
+        ];
+      };
+    },
+    {
+      name: "updateCampaignParams";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "title";
+            type: {
+              option: "string";
+            };
           },
           {
             name: "description";
-            type: "string";
+            type: {
+              option: "string";
+            };
           },
           {
-            name: "category";
+            name: "imageUrl";
             type: {
-              defined: {
-                name: "campaignCategory";
-              };
+              option: "string";
             };
           },
           {
-            name: "organizationName";
-            type: "string";
+            name: "startDate";
+            docs: ["Only while the campaign is still scheduled"];
+            type: {
+              option: "i64";
+            };
           },
           {
-            name: "targetAmount";
-            type: "u64";
+            name: "endDate";
+            type: {
+              option: "i64";
+            };
           },
           {
-            name: "raisedAmount";
-            type: "u64";
+            name: "status";
+            type: {
+              option: {
+                defined: {
+                  name: "campaignStatus";
+                };
+              };
+            };
           },
           {
-            name: "donorsCount";
-            type: "u32";
+            name: "isUrgent";
+            type: {
+              option: "bool";
+            };
           },
           {
-            name: "startDate";
-            type: "i64";
+            name: "donationLimits";
+            docs: [
+              "Replaces both limits; the hard cap can't drop below what has been raised"
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "donationLimits";
+                };
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "usdTarget";
+      docs: [
+        "Fiat target of a campaign and the price account used to value donations against it"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "targetUsd";
+            docs: ["Target in micro-USD (6 decimals)"];
+            type: "u64";
           },
           {
-            name: "endDate";
-            type: "i64";
+            name: "priceFeed";
+            type: "pubkey";
           },
           {
-            name: "status";
+            name: "feedKind";
             type: {
               defined: {
-                name: "campaignStatus";
+                name: "priceFeedKind";
               };
             };
           },
           {
-            name: "imageUrl";
-            type: "string";
-          },
-          {
-            name: "isUrgent";
-            type: "bool";
-          },
-          {
-            name: "bump";
-            type: "u8";
+            name: "feedId";
+            docs: [
+              "Pyth price feed id the price account must carry; unused for mock feeds"
+            ];
+            type: {
+              array: [
+                "u8",
+                32
+              ];
+            };
           }
         ];
       };
     },
     {
-      name: "campaignCategory";
+      name: "user";
       type: {
-        kind: "enum";
-        variants: [
+        kind: "struct";
+        fields: [
           {
-            name: "healthcare";
+            name: "authority";
+            type: "pubkey";
           },
           {
-            name: "education";
+            name: "name";
+            type: "string";
           },
           {
-            name: "foodSupply";
+            name: "walletAddress";
+            type: "pubkey";
           },
           {
-            name: "emergencyRelief";
+            name: "email";
+            type: "string";
           },
           {
-            name: "infrastructure";
+            name: "avatarUrl";
+            type: "string";
           },
           {
-            name: "waterSanitation";
+            name: "totalDonations";
+            type: "u64";
+          },
+          {
+            name: "campaignsSupported";
+            docs: [
+              "Number of distinct campaigns the user has given to and not been fully",
+              "refunded by. Tributes count here for the donor, not the honoree."
+            ];
+            type: "u32";
+          },
+          {
+            name: "impactMetrics";
+            type: {
+              defined: {
+                name: "impactMetrics";
+              };
+            };
+          },
+          {
+            name: "badges";
+            type: {
+              vec: {
+                defined: {
+                  name: "badge";
+                };
+              };
+            };
+          },
+          {
+            name: "rank";
+            type: "u32";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "campaignsCreated";
+            docs: [
+              "Number of campaigns the user has created, and the id of the next one.",
+              "Profiles created before this field existed get it from `migrate_user`."
+            ];
+            type: "u64";
           }
         ];
       };
     },
     {
-      name: "campaignCreated";
+      name: "userInitialized";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "campaign";
+            name: "user";
             type: "pubkey";
           },
           {
@@ -714,83 +7387,103 @@ export type Ripple = {
             type: "pubkey";
           },
           {
-            name: "title";
+            name: "name";
             type: "string";
           },
           {
-            name: "category";
-            type: {
-              defined: {
-                name: "campaignCategory";
-              };
-            };
-          },
-          {
-            name: "targetAmount";
-            type: "u64";
+            name: "timestamp";
+            type: "i64";
           }
         ];
       };
     },
     {
-      name: "campaignStatus";
+      name: "userMigrated";
       type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "active";
-          },
+        kind: "struct";
+        fields: [
           {
-            name: "inProgress";
+            name: "user";
+            type: "pubkey";
           },
           {
-            name: "completed";
+            name: "authority";
+            type: "pubkey";
           },
           {
-            name: "expired";
+            name: "timestamp";
+            type: "i64";
           }
         ];
       };
     },
     {
-      name: "campaignUpdated";
+      name: "votingConfig";
+      docs: [
+        "How donors sign off on milestones. Quorum is measured against the campaign's",
+        "`voteable_amount`, since each donor votes with the amount they donated."
+      ];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "campaign";
-            type: "pubkey";
+            name: "votingPeriod";
+            docs: [
+              "How long donors have to vote once a milestone is reported, in seconds"
+            ];
+            type: "i64";
           },
           {
-            name: "authority";
-            type: "pubkey";
+            name: "quorumBps";
+            docs: [
+              "Share of the voteable amount that must vote, in basis points"
+            ];
+            type: "u16";
           },
           {
-            name: "newStatus";
-            type: {
-              option: {
-                defined: {
-                  name: "campaignStatus";
-                };
-              };
-            };
+            name: "approvalThresholdBps";
+            docs: [
+              "Share of the votes cast that must approve, in basis points"
+            ];
+            type: "u16";
           }
         ];
       };
     },
     {
-      name: "donation";
+      name: "withdrawal";
+      docs: [
+        "A permanent record of funds leaving a campaign vault through `withdraw_funds`"
+      ];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "donor";
+            name: "campaign";
             type: "pubkey";
           },
           {
-            name: "campaign";
+            name: "sequence";
+            docs: [
+              "Position of this withdrawal in the campaign's ledger, starting at 0"
+            ];
+            type: "u64";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "recipient";
             type: "pubkey";
           },
+          {
+            name: "mint";
+            docs: ["Mint the withdrawal was paid in, or `None` for native SOL"];
+            type: {
+              option: "pubkey";
+            };
+          },
           {
             name: "amount";
             type: "u64";
@@ -800,29 +7493,23 @@ export type Ripple = {
             type: "i64";
           },
           {
-            name: "status";
-            type: {
-              defined: {
-                name: "donationStatus";
-              };
-            };
+            name: "purpose";
+            type: "string";
           },
           {
-            name: "paymentMethod";
+            name: "receiptHash";
+            docs: [
+              "Hash of an off-chain receipt or invoice backing the withdrawal"
+            ];
             type: {
-              defined: {
-                name: "paymentMethod";
+              option: {
+                array: [
+                  "u8",
+                  32
+                ];
               };
             };
           },
-          {
-            name: "transactionHash";
-            type: "string";
-          },
-          {
-            name: "impactDescription";
-            type: "string";
-          },
           {
             name: "bump";
             type: "u8";
@@ -831,64 +7518,56 @@ export type Ripple = {
       };
     },
     {
-      name: "donationReceived";
+      name: "withdrawalApproved";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "donation";
+            name: "campaign";
             type: "pubkey";
           },
           {
-            name: "campaign";
+            name: "withdrawalProposal";
             type: "pubkey";
           },
           {
-            name: "donor";
+            name: "approver";
             type: "pubkey";
           },
           {
-            name: "amount";
-            type: "u64";
+            name: "approvalCount";
+            docs: ["Approvals the proposal has after this one"];
+            type: "u8";
           },
           {
-            name: "paymentMethod";
-            type: {
-              defined: {
-                name: "paymentMethod";
-              };
-            };
+            name: "timestamp";
+            type: "i64";
           }
         ];
       };
     },
     {
-      name: "donationStatus";
+      name: "withdrawalProposal";
+      docs: [
+        "A withdrawal waiting on approval from the campaign's signers. Closed once it",
+        "is executed or expires; the `Withdrawal` record is what remains."
+      ];
       type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "pending";
-          },
+        kind: "struct";
+        fields: [
           {
-            name: "completed";
+            name: "campaign";
+            type: "pubkey";
           },
           {
-            name: "allocated";
+            name: "index";
+            docs: [
+              "Position among the campaign's proposals, which the address is derived from"
+            ];
+            type: "u64";
           },
           {
-            name: "spent";
-          }
-        ];
-      };
-    },
-    {
-      name: "fundsWithdrawn";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "campaign";
+            name: "proposer";
             type: "pubkey";
           },
           {
@@ -898,148 +7577,99 @@ export type Ripple = {
           {
             name: "amount";
             type: "u64";
-          }
-        ];
-      };
-    },
-    {
-      name: "impactMetrics";
-      type: {
-        kind: "struct";
-        fields: [
+          },
           {
-            name: "mealsProvided";
-            type: "u32";
+            name: "purpose";
+            type: "string";
           },
           {
-            name: "childrenEducated";
-            type: "u32";
+            name: "receiptHash";
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32
+                ];
+              };
+            };
           },
           {
-            name: "familiesHoused";
-            type: "u32";
+            name: "approvals";
+            docs: ["Signers who have approved, including the proposer"];
+            type: {
+              vec: "pubkey";
+            };
           },
           {
-            name: "treesPlanted";
-            type: "u32";
-          }
-        ];
-      };
-    },
-    {
-      name: "paymentMethod";
-      type: {
-        kind: "enum";
-        variants: [
+            name: "createdAt";
+            type: "i64";
+          },
           {
-            name: "cryptoWallet";
+            name: "expiresAt";
+            docs: [
+              "After this the proposal can no longer be approved or executed"
+            ];
+            type: "i64";
           },
           {
-            name: "card";
+            name: "bump";
+            type: "u8";
           }
         ];
       };
     },
     {
-      name: "updateCampaignParams";
+      name: "withdrawalProposalExpired";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "description";
-            type: {
-              option: "string";
-            };
-          },
-          {
-            name: "imageUrl";
-            type: {
-              option: "string";
-            };
-          },
-          {
-            name: "endDate";
-            type: {
-              option: "i64";
-            };
+            name: "campaign";
+            type: "pubkey";
           },
           {
-            name: "status";
-            type: {
-              option: {
-                defined: {
-                  name: "campaignStatus";
-                };
-              };
-            };
+            name: "withdrawalProposal";
+            type: "pubkey";
           },
           {
-            name: "isUrgent";
-            type: {
-              option: "bool";
-            };
+            name: "timestamp";
+            type: "i64";
           }
         ];
       };
     },
     {
-      name: "user";
+      name: "withdrawalProposed";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "authority";
+            name: "campaign";
             type: "pubkey";
           },
           {
-            name: "name";
-            type: "string";
-          },
-          {
-            name: "walletAddress";
+            name: "withdrawalProposal";
             type: "pubkey";
           },
           {
-            name: "email";
-            type: "string";
+            name: "proposer";
+            type: "pubkey";
           },
           {
-            name: "avatarUrl";
-            type: "string";
+            name: "recipient";
+            type: "pubkey";
           },
           {
-            name: "totalDonations";
+            name: "amount";
             type: "u64";
           },
           {
-            name: "campaignsSupported";
-            type: "u32";
-          },
-          {
-            name: "impactMetrics";
-            type: {
-              defined: {
-                name: "impactMetrics";
-              };
-            };
-          },
-          {
-            name: "badges";
-            type: {
-              vec: {
-                defined: {
-                  name: "badge";
-                };
-              };
-            };
-          },
-          {
-            name: "rank";
-            type: "u32";
+            name: "expiresAt";
+            type: "i64";
           },
           {
-            name: "bump";
-            type: "u8";
+            name: "timestamp";
+            type: "i64";
           }
         ];
       };
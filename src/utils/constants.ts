@@ -8,7 +8,9 @@ export const SEEDS = {
   USER: "user",
   CAMPAIGN: "campaign",
   DONATION: "donation",
+  DONOR_POSITION: "donor_position",
   VAULT: "vault",
+  WITHDRAWAL: "withdrawal",
 } as const;
 
 export const MIN_CAMPAIGN_DURATION = 24 * 60 * 60; // 24 hours in seconds
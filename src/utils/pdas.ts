@@ -1,7 +1,12 @@
 // src/utils/pdas.ts
+import { BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 import { PROGRAM_ID, SEEDS } from "./constants";
 
+// Counters are encoded as 8-byte little-endian seeds
+const counterSeed = (count: BN | number): Buffer =>
+  new BN(count).toArrayLike(Buffer, "le", 8);
+
 export const findUserPDA = async (
   authority: PublicKey
 ): Promise<[PublicKey, number]> => {
@@ -11,42 +16,68 @@ export const findUserPDA = async (
   );
 };
 
+// Campaigns are numbered per authority; the next id is the authority's
+// `user.campaignsCreated`
 export const findCampaignPDA = async (
-  title: string,
-  authority: PublicKey
+  authority: PublicKey,
+  campaignId: BN | number
 ): Promise<[PublicKey, number]> => {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from(SEEDS.CAMPAIGN), Buffer.from(title), authority.toBuffer()],
+    [
+      Buffer.from(SEEDS.CAMPAIGN),
+      authority.toBuffer(),
+      counterSeed(campaignId),
+    ],
     PROGRAM_ID
   );
 };
 
 export const findCampaignVaultPDA = async (
-  title: string,
-  authority: PublicKey
+  campaign: PublicKey
 ): Promise<[PublicKey, number]> => {
   return PublicKey.findProgramAddressSync(
-    [
-      Buffer.from(SEEDS.CAMPAIGN),
-      Buffer.from(title),
-      authority.toBuffer(),
-      Buffer.from(SEEDS.VAULT),
-    ],
+    [Buffer.from(SEEDS.VAULT), campaign.toBuffer()],
     PROGRAM_ID
   );
 };
 
+// Donations are numbered per campaign; the next index is the campaign's
+// `donationCount`
 export const findDonationPDA = async (
   campaign: PublicKey,
-  donor: PublicKey,
-  donationCount: string
+  donationIndex: BN | number
 ): Promise<[PublicKey, number]> => {
   return PublicKey.findProgramAddressSync(
     [
       Buffer.from(SEEDS.DONATION),
       campaign.toBuffer(),
-      donor.toBuffer(),
-      Buffer.from(donationCount),
+      counterSeed(donationIndex),
+    ],
+    PROGRAM_ID
+  );
+};
+
+export const findDonorPositionPDA = async (
+  campaign: PublicKey,
+  donor: PublicKey
+): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(SEEDS.DONOR_POSITION), campaign.toBuffer(), donor.toBuffer()],
+    PROGRAM_ID
+  );
+};
+
+// Withdrawals are numbered per campaign; the next index is the campaign's
+// `withdrawalCount`
+export const findWithdrawalPDA = async (
+  campaign: PublicKey,
+  withdrawalIndex: BN | number
+): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(SEEDS.WITHDRAWAL),
+      campaign.toBuffer(),
+      counterSeed(withdrawalIndex),
     ],
     PROGRAM_ID
   );